@@ -0,0 +1,136 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{constants::ANNOUNCEMENT_READ_STATE_FILE_NAME, App};
+use crate::{
+    error::{AppError, Result},
+    model::{Announcement, AnnouncementFeed},
+    utils,
+};
+
+lazy_static! {
+    static ref ANNOUNCEMENT_READ_STATE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AnnouncementReadState {
+    #[serde(default)]
+    read_ids: HashSet<i64>,
+}
+
+fn build_announcement_feed(
+    mut announcements: Vec<Announcement>,
+    read_ids: &HashSet<i64>,
+) -> AnnouncementFeed {
+    let mut unread_counts = HashMap::new();
+    for announcement in &mut announcements {
+        announcement.read = read_ids.contains(&announcement.id);
+        if !announcement.read {
+            *unread_counts
+                .entry(announcement.context_code.clone())
+                .or_default() += 1;
+        }
+    }
+    AnnouncementFeed {
+        unread_count: unread_counts.values().sum(),
+        unread_counts,
+        announcements,
+    }
+}
+
+impl App {
+    fn announcement_read_state_path() -> Result<PathBuf> {
+        Ok(Path::new(&App::config_dir()?).join(ANNOUNCEMENT_READ_STATE_FILE_NAME))
+    }
+
+    fn read_announcement_read_state() -> Result<AnnouncementReadState> {
+        let path = App::announcement_read_state_path()?;
+        if fs::metadata(&path).is_err() {
+            return Ok(Default::default());
+        }
+        let content = fs::read(&path)?;
+        utils::json::parse_json(&content)
+    }
+
+    pub async fn list_announcements(
+        &self,
+        start_date: Option<&str>,
+        end_date: Option<&str>,
+    ) -> Result<AnnouncementFeed> {
+        let token = self.config.read().await.token.clone();
+        let announcements = self
+            .client
+            .list_current_term_announcements(&token, start_date, end_date)
+            .await?;
+        let read_state = {
+            let _guard = ANNOUNCEMENT_READ_STATE_LOCK
+                .lock()
+                .map_err(|_| AppError::MutexError)?;
+            App::read_announcement_read_state()?
+        };
+        Ok(build_announcement_feed(announcements, &read_state.read_ids))
+    }
+
+    pub fn mark_announcements_read(&self, announcement_ids: &[i64], read: bool) -> Result<()> {
+        let _guard = ANNOUNCEMENT_READ_STATE_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        let mut read_state = App::read_announcement_read_state()?;
+        for id in announcement_ids {
+            if read {
+                read_state.read_ids.insert(*id);
+            } else {
+                read_state.read_ids.remove(id);
+            }
+        }
+        fs::write(
+            App::announcement_read_state_path()?,
+            serde_json::to_vec(&read_state)?,
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn announcement(id: i64, context_code: &str) -> Announcement {
+        Announcement {
+            id,
+            context_code: context_code.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_announcement_feed() {
+        let announcements = vec![
+            announcement(1, "course_10"),
+            announcement(2, "course_10"),
+            announcement(3, "course_20"),
+            announcement(4, "course_30"),
+        ];
+        let read_ids = HashSet::from([2, 4]);
+        let feed = build_announcement_feed(announcements, &read_ids);
+
+        assert_eq!(
+            feed.announcements
+                .iter()
+                .map(|announcement| announcement.read)
+                .collect::<Vec<_>>(),
+            vec![false, true, false, true]
+        );
+        assert_eq!(feed.unread_count, 2);
+        assert_eq!(
+            feed.unread_counts,
+            HashMap::from([("course_10".to_owned(), 1), ("course_20".to_owned(), 1)])
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::App;
+use crate::{
+    error::Result,
+    model::{Assignment, AssignmentGroup, AssignmentInput},
+};
+
+// Clones start unpublished so that students do not see them before they are reviewed.
+// The assignment group only exists in the source course and is left to the default one.
+fn clone_input(assignment: &Assignment) -> AssignmentInput {
+    AssignmentInput {
+        name: assignment.name.clone(),
+        description: assignment.description.clone(),
+        points_possible: assignment.points_possible,
+        grading_type: Some(assignment.grading_type.clone()),
+        submission_types: assignment.submission_types.clone(),
+        allowed_extensions: assignment.allowed_extensions.clone(),
+        due_at: assignment.due_at.clone(),
+        unlock_at: assignment.unlock_at.clone(),
+        lock_at: assignment.lock_at.clone(),
+        assignment_group_id: None,
+        published: Some(false),
+    }
+}
+
+impl App {
+    pub async fn list_assignment_groups(&self, course_id: i64) -> Result<Vec<AssignmentGroup>> {
+        self.client
+            .list_assignment_groups(course_id, &self.config.read().await.token)
+            .await
+    }
+
+    pub async fn create_assignment(
+        &self,
+        course_id: i64,
+        input: &AssignmentInput,
+    ) -> Result<Assignment> {
+        self.client
+            .create_assignment(course_id, input, &self.config.read().await.token)
+            .await
+    }
+
+    pub async fn update_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        input: &AssignmentInput,
+    ) -> Result<Assignment> {
+        self.client
+            .update_assignment(
+                course_id,
+                assignment_id,
+                input,
+                &self.config.read().await.token,
+            )
+            .await
+    }
+
+    pub async fn set_assignment_published(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        published: bool,
+    ) -> Result<Assignment> {
+        self.client
+            .set_assignment_published(
+                course_id,
+                assignment_id,
+                published,
+                &self.config.read().await.token,
+            )
+            .await
+    }
+
+    pub async fn delete_assignment(&self, course_id: i64, assignment_id: i64) -> Result<()> {
+        self.client
+            .delete_assignment(course_id, assignment_id, &self.config.read().await.token)
+            .await
+    }
+
+    // Within a course canvas duplicates the assignment itself, across courses it is
+    // recreated from its fields. Files linked in the description still point to the
+    // source course.
+    pub async fn clone_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        target_course_id: Option<i64>,
+    ) -> Result<Assignment> {
+        let token = self.config.read().await.token.clone();
+        let target_course_id = target_course_id.unwrap_or(course_id);
+        if target_course_id == course_id {
+            return self
+                .client
+                .duplicate_assignment(course_id, assignment_id, &token)
+                .await;
+        }
+        let assignment = self
+            .client
+            .get_assignment(course_id, assignment_id, &token)
+            .await?;
+        let cloned = self
+            .client
+            .create_assignment(target_course_id, &clone_input(&assignment), &token)
+            .await?;
+        tracing::info!(
+            "Cloned assignment {} of course {} into course {} as {}",
+            assignment_id,
+            course_id,
+            target_course_id,
+            cloned.id
+        );
+        Ok(cloned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_input() {
+        let assignment = Assignment {
+            id: 1,
+            name: "Homework 1".to_owned(),
+            points_possible: Some(10.0),
+            allowed_extensions: vec!["pdf".to_owned()],
+            assignment_group_id: Some(3),
+            published: true,
+            ..Default::default()
+        };
+        let input = clone_input(&assignment);
+
+        assert_eq!(input.name, "Homework 1");
+        assert_eq!(input.points_possible, Some(10.0));
+        assert_eq!(input.allowed_extensions, vec!["pdf".to_owned()]);
+        assert_eq!(input.assignment_group_id, None);
+        assert_eq!(input.published, Some(false));
+    }
+}
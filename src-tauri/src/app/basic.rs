@@ -24,7 +24,10 @@ use warp::{hyper::Response, Filter};
 
 use crate::{
     client::{
+        archive::get_submission_entries,
         constants::{BASE_URL, JI_BASE_URL},
+        pagination::ListOptions,
+        quiz::get_quiz_calendar_events,
         Client,
     },
     error,
@@ -220,6 +223,8 @@ impl App {
             handle: Default::default(),
             mcp_handle: Default::default(),
             cache: Default::default(),
+            download_manager: Arc::new(App::new_download_manager(&config_dir)),
+            search_index: Default::default(),
         }
     }
 
@@ -353,6 +358,10 @@ impl App {
         *handle = None;
     }
 
+    pub async fn mcp_allow_write(&self) -> bool {
+        self.config.read().await.mcp_allow_write
+    }
+
     pub async fn restart_mcp(&self) -> Result<bool> {
         self.stop_mcp().await;
         self.start_mcp().await
@@ -466,12 +475,23 @@ impl App {
         student_id: i64,
         grade: &str,
         comment: Option<&str>,
+        group_id: Option<i64>,
     ) -> Result<()> {
+        // Grading a group gives every member the same grade
+        let student_ids = match group_id {
+            Some(group_id) => self
+                .list_group_users(group_id)
+                .await?
+                .iter()
+                .map(|user| user.id)
+                .collect(),
+            None => vec![student_id],
+        };
         self.client
             .update_grade(
                 course_id,
                 assignment_id,
-                student_id,
+                &student_ids,
                 grade,
                 comment,
                 &self.config.read().await.token,
@@ -597,11 +617,98 @@ impl App {
             .await
     }
 
+    pub async fn list_course_pages(&self, course_id: i64) -> Result<Vec<Page>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_pages(course_id, &token).await
+    }
+
+    pub async fn get_course_page(&self, course_id: i64, page_url: &str) -> Result<Page> {
+        let token = self.config.read().await.token.clone();
+        self.client.get_page(course_id, page_url, &token).await
+    }
+
+    // Pages are exported to `<save path>/<course>/pages/<page url>.md`
+    pub async fn export_page_markdown(&self, course: &Course, page_url: &str) -> Result<String> {
+        let guard = self.config.read().await;
+        let token = guard.token.clone();
+        let save_dir = Path::new(&guard.save_path)
+            .join(self.get_course_identifier(course))
+            .join("pages");
+        drop(guard);
+        let page = self.client.get_page(course.id, page_url, &token).await?;
+        let path = self
+            .client
+            .export_page_markdown(&page, &save_dir, &token)
+            .await?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    pub async fn list_course_quizzes(&self, course_id: i64) -> Result<Vec<Quiz>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_quizzes(course_id, &token).await
+    }
+
+    pub async fn get_quiz(&self, course_id: i64, quiz_id: i64) -> Result<Quiz> {
+        let token = self.config.read().await.token.clone();
+        self.client.get_quiz(course_id, quiz_id, &token).await
+    }
+
+    pub async fn get_my_quiz_submissions(
+        &self,
+        course_id: i64,
+        quiz_id: i64,
+    ) -> Result<QuizSubmissionHistory> {
+        let token = self.config.read().await.token.clone();
+        let quiz = self.client.get_quiz(course_id, quiz_id, &token).await?;
+        let quiz_submissions = self
+            .client
+            .list_my_quiz_submissions(course_id, quiz_id, &token)
+            .await?;
+        let attempts = match quiz.assignment_id {
+            Some(assignment_id) => {
+                self.client
+                    .list_quiz_attempts(course_id, assignment_id, &token)
+                    .await?
+            }
+            None => vec![],
+        };
+        Ok(QuizSubmissionHistory {
+            quiz_submissions,
+            attempts,
+        })
+    }
+
+    pub async fn list_quiz_questions(
+        &self,
+        course_id: i64,
+        quiz_id: i64,
+    ) -> Result<Vec<QuizQuestion>> {
+        let token = self.config.read().await.token.clone();
+        let quiz_submissions = self
+            .client
+            .list_my_quiz_submissions(course_id, quiz_id, &token)
+            .await?;
+        self.client
+            .list_quiz_questions(course_id, quiz_id, quiz_submissions.first(), &token)
+            .await
+    }
+
     pub async fn list_course_files(&self, course_id: i64) -> Result<Vec<File>> {
         let token = self.config.read().await.token.clone();
         self.client.list_course_files(course_id, &token).await
     }
 
+    pub async fn list_course_files_with_options(
+        &self,
+        course_id: i64,
+        options: &ListOptions,
+    ) -> Result<Vec<File>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .list_course_files_with_options(course_id, options, &token)
+            .await
+    }
+
     pub async fn list_course_images(&self, course_id: i64) -> Result<Vec<File>> {
         let token = self.config.read().await.token.clone();
         self.client.list_course_images(course_id, &token).await
@@ -703,6 +810,17 @@ impl App {
             .await
     }
 
+    pub async fn list_folder_files_by_page<F: FnMut(&[File])>(
+        &self,
+        folder_id: i64,
+        on_page: F,
+    ) -> Result<Vec<File>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .list_folder_files_by_page(folder_id, &token, on_page)
+            .await
+    }
+
     pub async fn list_course_folders(&self, course_id: i64) -> Result<Vec<Folder>> {
         self.client
             .list_course_folders(course_id, &self.config.read().await.token)
@@ -733,75 +851,131 @@ impl App {
         Ok(())
     }
 
-    pub async fn download_file<F: Fn(ProgressPayload) + Send>(
+    pub async fn download_file(&self, file: &File, priority: i64) -> Result<DownloadState> {
+        let save_dir = self.config.read().await.save_path.clone();
+        let target = DownloadTarget::File {
+            file: file.clone(),
+            save_dir,
+            course_sync: None,
+        };
+        self.enqueue_download(&file.uuid, target, priority, file.size)
+            .await
+    }
+
+    pub async fn download_course_file(
         &self,
         file: &File,
-        progress_handler: F,
-    ) -> Result<()> {
-        let guard = self.config.read().await;
-        let token = &guard.token.clone();
-        let save_path = &guard.save_path.clone();
-        self.client
-            .download_file(file, token, save_path, progress_handler)
-            .await?;
-        Ok(())
+        course: &Course,
+        folder_path: &str,
+        priority: i64,
+    ) -> Result<DownloadState> {
+        let course_dir =
+            Path::new(&self.config.read().await.save_path).join(self.get_course_identifier(course));
+        let save_dir = course_dir.join(folder_path);
+        tracing::info!("Download file at path: {:?}", save_dir);
+        let target = DownloadTarget::File {
+            file: file.clone(),
+            save_dir: save_dir.to_string_lossy().into_owned(),
+            course_sync: Some(CourseSyncTarget {
+                course_id: course.id,
+                course_dir: course_dir.to_string_lossy().into_owned(),
+                folder_path: folder_path.to_owned(),
+            }),
+        };
+        self.enqueue_download(&file.uuid, target, priority, file.size)
+            .await
     }
 
-    pub async fn download_course_file<F: Fn(ProgressPayload) + Send>(
+    pub async fn download_my_file(
         &self,
         file: &File,
-        course: &Course,
         folder_path: &str,
+        priority: i64,
+    ) -> Result<DownloadState> {
+        let save_dir = Path::new(&self.config.read().await.save_path)
+            .join(MY_CANVAS_FILES_FOLDER_NAME)
+            .join(folder_path);
+        tracing::info!("Download file at path: {:?}", save_dir);
+        let target = DownloadTarget::File {
+            file: file.clone(),
+            save_dir: save_dir.to_string_lossy().into_owned(),
+            course_sync: None,
+        };
+        self.enqueue_download(&file.uuid, target, priority, file.size)
+            .await
+    }
+
+    pub async fn download_folder_as_zip<F: Fn(ProgressPayload) + Send>(
+        &self,
+        folder_id: i64,
+        save_name: &str,
         progress_handler: F,
     ) -> Result<()> {
         let guard = self.config.read().await;
-        let token = &guard.token.clone();
-        let course_identifier = self.get_course_identifier(course);
-        let save_path = Path::new(&guard.save_path)
-            .join(course_identifier)
-            .join(folder_path);
-        let save_path = save_path.to_str().unwrap_or_default();
-        App::ensure_directory(save_path);
-        tracing::info!("Download file at path: {:?}", save_path);
+        let token = guard.token.clone();
+        let save_path = Path::new(&guard.save_path).join(save_name);
+        drop(guard);
         self.client
-            .download_file(file, token, save_path, progress_handler)
-            .await?;
-        Ok(())
+            .download_folder_as_zip(
+                folder_id,
+                &token,
+                save_path.to_str().unwrap_or_default(),
+                progress_handler,
+            )
+            .await
     }
 
-    pub async fn download_my_file<F: Fn(ProgressPayload) + Send>(
+    // Returns the path of the created directory, or of the zip file with `options.zip`
+    pub async fn download_submission_attachments<F: Fn(ProgressPayload) + Send>(
         &self,
-        file: &File,
-        folder_path: &str,
+        course_id: i64,
+        assignment_id: i64,
+        save_name: &str,
+        options: &SubmissionDownloadOptions,
         progress_handler: F,
-    ) -> Result<()> {
+    ) -> Result<String> {
         let guard = self.config.read().await;
-        let token = &guard.token.clone();
-        let save_path = Path::new(&guard.save_path)
-            .join(MY_CANVAS_FILES_FOLDER_NAME)
-            .join(folder_path);
-        let save_path = save_path.to_str().unwrap_or_default();
-        App::ensure_directory(save_path);
-        tracing::info!("Download file at path: {:?}", save_path);
+        let token = guard.token.clone();
+        let template = guard.submission_folder_template.clone();
+        let mut save_path = Path::new(&guard.save_path).join(save_name);
+        drop(guard);
+        if options.zip
+            && save_path
+                .extension()
+                .is_none_or(|extension| extension != "zip")
+        {
+            save_path.as_mut_os_string().push(".zip");
+        }
+
+        let submissions = if options.all_attempts {
+            self.client
+                .list_submissions_with_history(course_id, assignment_id, &token)
+                .await?
+        } else {
+            self.client
+                .list_course_assignment_submissions(course_id, assignment_id, &token)
+                .await?
+        };
+        let students = self.list_course_students(course_id).await?;
+        let entries =
+            get_submission_entries(&submissions, &students, &template, options.all_attempts);
         self.client
-            .download_file(file, token, save_path, progress_handler)
+            .download_submission_attachments(
+                &entries,
+                &token,
+                &save_path,
+                options.zip,
+                &format!("submissions_{assignment_id}"),
+                progress_handler,
+            )
             .await?;
-        Ok(())
+        Ok(save_path.to_string_lossy().into_owned())
     }
 
     fn get_course_identifier(&self, course: &Course) -> String {
         self.client.get_course_identifier(course)
     }
 
-    pub async fn sync_course_files(&self, course: &Course) -> Result<Vec<File>> {
-        let guard = self.config.read().await;
-        let save_dir = guard.save_path.clone();
-        let token = guard.token.clone();
-        self.client
-            .sync_course_files(course, &save_dir, &token)
-            .await
-    }
-
     pub async fn open_file(&self, name: &str) -> Result<()> {
         let save_path = &self.config.read().await.save_path;
         let path = Path::new(save_path).join(name);
@@ -911,14 +1085,56 @@ impl App {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<CalendarEvent>> {
-        self.client
-            .list_calendar_events(
-                &self.config.read().await.token,
-                context_codes,
+        let token = self.config.read().await.token.clone();
+        let mut events = self
+            .client
+            .list_calendar_events(&token, context_codes, start_date, end_date)
+            .await?;
+        // Course names come from the events or the cached courses, so that loading the
+        // calendar costs one quiz listing per course and nothing more
+        let cached_courses: Vec<Course> = self.cache.get(COURSES_CACHE_KEY)?.unwrap_or_default();
+        let mut tasks = JoinSet::new();
+        for course_id in context_codes
+            .iter()
+            .filter_map(|code| code.strip_prefix("course_")?.parse::<i64>().ok())
+        {
+            let context_code = format!("course_{course_id}");
+            let course_name = events
+                .iter()
+                .find(|event| event.context_code == context_code)
+                .map(|event| event.context_name.clone())
+                .or_else(|| {
+                    cached_courses
+                        .iter()
+                        .find(|course| course.id == course_id)
+                        .map(|course| course.name.clone())
+                })
+                .unwrap_or_default();
+            let client = self.client.clone();
+            let token = token.clone();
+            tasks.spawn(async move {
+                let quizzes = client.list_quizzes(course_id, &token).await;
+                (course_id, course_name, quizzes)
+            });
+        }
+        while let Some(res) = tasks.join_next().await {
+            let (course_id, course_name, quizzes) = res?;
+            let quizzes = match quizzes {
+                Ok(quizzes) => quizzes,
+                Err(e) => {
+                    tracing::warn!("Failed to list quizzes of course {}: {:?}", course_id, e);
+                    continue;
+                }
+            };
+            events.extend(get_quiz_calendar_events(
+                &quizzes,
+                course_id,
+                &course_name,
                 start_date,
                 end_date,
-            )
-            .await
+            ));
+        }
+        Ok(events)
     }
 
     fn resolve_active_key_entry(config: &AppConfig) -> Option<&LlmApiKeyEntry> {
@@ -1035,20 +1251,6 @@ impl App {
         false
     }
 
-    pub async fn submit_assignment(
-        &self,
-        course_id: i64,
-        assignment_id: i64,
-        file_paths: &[String],
-        comment: Option<&str>,
-    ) -> Result<()> {
-        let token = self.config.read().await.token.clone();
-        self.client
-            .submit_assignment(course_id, assignment_id, file_paths, comment, &token)
-            .await?;
-        Ok(())
-    }
-
     pub async fn upload_submission_file(
         &self,
         course_id: i64,
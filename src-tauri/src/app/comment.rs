@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use super::App;
+use crate::{
+    error::Result,
+    model::{
+        DownloadState, FeedbackFileMatch, FeedbackUploadPlan, NewSubmissionComment,
+        ProgressPayload, Submission, User,
+    },
+};
+
+const LOGIN_ID_SEPARATORS: [char; 4] = ['_', '-', ' ', '.'];
+
+// A file belongs to a student when its stem is the login_id, optionally followed by a
+// separator and anything else, e.g. "520030910001_annotated.pdf". The longest matching
+// login_id wins so that ids sharing a prefix are not confused.
+fn find_feedback_student<'a>(students: &'a [User], file_path: &str) -> Option<&'a User> {
+    let stem = Path::new(file_path)
+        .file_stem()?
+        .to_string_lossy()
+        .to_lowercase();
+    students
+        .iter()
+        .filter(|student| !student.login_id.is_empty())
+        .filter(|student| {
+            let login_id = student.login_id.to_lowercase();
+            stem.strip_prefix(&login_id)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(LOGIN_ID_SEPARATORS))
+        })
+        .max_by_key(|student| student.login_id.len())
+}
+
+fn match_feedback_files(students: &[User], file_paths: &[String]) -> FeedbackUploadPlan {
+    let mut matches = BTreeMap::new();
+    let mut unmatched_files = vec![];
+    for file_path in file_paths {
+        let Some(student) = find_feedback_student(students, file_path) else {
+            unmatched_files.push(file_path.clone());
+            continue;
+        };
+        matches
+            .entry(student.login_id.clone())
+            .or_insert_with(|| FeedbackFileMatch {
+                student_id: student.id,
+                login_id: student.login_id.clone(),
+                name: student.name.clone(),
+                ..Default::default()
+            })
+            .file_paths
+            .push(file_path.clone());
+    }
+    FeedbackUploadPlan {
+        matches: matches.into_values().collect(),
+        unmatched_files,
+    }
+}
+
+fn list_feedback_files(dir: &str) -> Result<Vec<String>> {
+    let mut file_paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_file() && !hidden {
+            file_paths.push(path.to_string_lossy().into_owned());
+        }
+    }
+    file_paths.sort();
+    Ok(file_paths)
+}
+
+impl App {
+    pub async fn add_submission_comment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        student_id: &str,
+        comment: &NewSubmissionComment,
+    ) -> Result<Submission> {
+        let token = self.config.read().await.token.clone();
+        let mut file_ids = vec![];
+        for file_path in &comment.file_paths {
+            let file = self
+                .client
+                .upload_submission_comment_file(
+                    course_id,
+                    assignment_id,
+                    student_id,
+                    file_path,
+                    &token,
+                )
+                .await?;
+            file_ids.push(file.id);
+        }
+        self.client
+            .add_submission_comment(
+                course_id,
+                assignment_id,
+                student_id,
+                comment,
+                &file_ids,
+                &token,
+            )
+            .await
+    }
+
+    pub async fn plan_feedback_upload(
+        &self,
+        course_id: i64,
+        dir: &str,
+    ) -> Result<FeedbackUploadPlan> {
+        let file_paths = list_feedback_files(dir)?;
+        let students = self.list_course_students(course_id).await?;
+        Ok(match_feedback_files(&students, &file_paths))
+    }
+
+    // Every previewed match gets one comment with all of its files attached. Failures are
+    // recorded on the match and do not stop the remaining students, and matches already
+    // uploaded are skipped so that passing the result back only retries the failed ones.
+    pub async fn upload_feedback_files<F: Fn(ProgressPayload) + Send>(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        mut matches: Vec<FeedbackFileMatch>,
+        text: Option<&str>,
+        progress_handler: F,
+    ) -> Result<Vec<FeedbackFileMatch>> {
+        let uuid = format!("feedback_{assignment_id}");
+        let total = matches.len() as u64;
+        for (index, feedback) in matches.iter_mut().enumerate() {
+            if feedback.uploaded {
+                continue;
+            }
+            let comment = NewSubmissionComment {
+                text: text.unwrap_or_default().to_owned(),
+                file_paths: feedback.file_paths.clone(),
+                ..Default::default()
+            };
+            let student_id = feedback.student_id.to_string();
+            match self
+                .add_submission_comment(course_id, assignment_id, &student_id, &comment)
+                .await
+            {
+                Ok(_) => {
+                    feedback.uploaded = true;
+                    feedback.error = None;
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to upload feedback for {}: {:?}",
+                        feedback.login_id,
+                        e
+                    );
+                    feedback.error = Some(e.to_string());
+                }
+            }
+            progress_handler(ProgressPayload {
+                uuid: uuid.clone(),
+                processed: index as u64 + 1,
+                total,
+                state: DownloadState::Running,
+            });
+        }
+        progress_handler(ProgressPayload {
+            uuid,
+            processed: total,
+            total,
+            state: DownloadState::Completed,
+        });
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(id: i64, login_id: &str) -> User {
+        User {
+            id,
+            name: format!("student {id}"),
+            login_id: login_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_match_feedback_files() {
+        let students = vec![student(1, "5200"), student(2, "52001"), student(3, "abc")];
+        let file_paths = vec![
+            "/tmp/5200.pdf".to_owned(),
+            "/tmp/52001_annotated.pdf".to_owned(),
+            "/tmp/52001-rubric.xlsx".to_owned(),
+            "/tmp/ABC.docx".to_owned(),
+            "/tmp/520099.pdf".to_owned(),
+        ];
+        let plan = match_feedback_files(&students, &file_paths);
+
+        assert_eq!(plan.matches.len(), 3);
+        assert_eq!(plan.matches[0].student_id, 1);
+        assert_eq!(plan.matches[0].file_paths, vec!["/tmp/5200.pdf".to_owned()]);
+        assert_eq!(plan.matches[1].student_id, 2);
+        assert_eq!(plan.matches[1].file_paths.len(), 2);
+        assert_eq!(plan.matches[2].student_id, 3);
+        assert_eq!(plan.unmatched_files, vec!["/tmp/520099.pdf".to_owned()]);
+    }
+}
@@ -1,2 +1,10 @@
 pub const COURSES_CACHE_KEY: &str = "courses_cache_key";
 pub const RELATIONSHIP_CACHE_KEY: &str = "relationship_cache_key";
+pub const SYNC_MANIFEST_FILE_NAME: &str = ".sync_manifest.json";
+pub const HISTORY_DIR_NAME: &str = ".history";
+pub const DOWNLOAD_QUEUE_FILE_NAME: &str = "download_queue.json";
+pub const SEARCH_INDEX_FILE_NAME: &str = "search_index.json";
+pub const ANNOUNCEMENT_READ_STATE_FILE_NAME: &str = "announcement_read_state.json";
+pub const DEADLINE_HISTORY_FILE_NAME: &str = "deadline_history.json";
+// submission files above this size are flagged before uploading
+pub const LARGE_SUBMISSION_FILE_SIZE: u64 = 100 * 1024 * 1024;
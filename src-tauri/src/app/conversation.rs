@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use super::App;
+use crate::{
+    error::Result,
+    model::{Conversation, NewConversation},
+};
+
+// Canvas switches bulk messages with more recipients to asynchronous delivery, which
+// does not return the created conversations
+const MAX_RECIPIENTS_PER_MESSAGE: usize = 100;
+const DISABLED_POLL_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+impl App {
+    pub async fn list_conversations(&self, scope: Option<&str>) -> Result<Vec<Conversation>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_conversations(scope, &token).await
+    }
+
+    pub async fn get_conversation(&self, conversation_id: i64) -> Result<Conversation> {
+        let token = self.config.read().await.token.clone();
+        self.client.get_conversation(conversation_id, &token).await
+    }
+
+    pub async fn get_unread_conversation_count(&self) -> Result<i64> {
+        let token = self.config.read().await.token.clone();
+        self.client.get_unread_conversation_count(&token).await
+    }
+
+    pub async fn create_conversation(
+        &self,
+        conversation: &NewConversation,
+    ) -> Result<Vec<Conversation>> {
+        let token = self.config.read().await.token.clone();
+        let attachment_ids = self
+            .client
+            .upload_conversation_attachments(&conversation.attachment_paths, &token)
+            .await?;
+        self.client
+            .create_conversation(conversation, &attachment_ids, &token)
+            .await
+    }
+
+    pub async fn reply_conversation(
+        &self,
+        conversation_id: i64,
+        body: &str,
+        attachment_paths: &[String],
+    ) -> Result<Conversation> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .reply_conversation(conversation_id, body, attachment_paths, &token)
+            .await
+    }
+
+    // Every student gets a private copy, so replies are not visible to the whole class
+    pub async fn message_course_students(
+        &self,
+        course_id: i64,
+        subject: &str,
+        body: &str,
+        attachment_paths: &[String],
+    ) -> Result<Vec<Conversation>> {
+        let token = self.config.read().await.token.clone();
+        let students = self.list_course_students(course_id).await?;
+        let attachment_ids = self
+            .client
+            .upload_conversation_attachments(attachment_paths, &token)
+            .await?;
+        let mut conversations = vec![];
+        for students_batch in students.chunks(MAX_RECIPIENTS_PER_MESSAGE) {
+            let conversation = NewConversation {
+                recipients: students_batch
+                    .iter()
+                    .map(|student| student.id.to_string())
+                    .collect(),
+                subject: subject.to_owned(),
+                body: body.to_owned(),
+                attachment_paths: vec![],
+                context_code: Some(format!("course_{course_id}")),
+                bulk_message: true,
+            };
+            let created = self
+                .client
+                .create_conversation(&conversation, &attachment_ids, &token)
+                .await?;
+            conversations.extend(created);
+        }
+        tracing::info!(
+            "Messaged {} students of course {}",
+            students.len(),
+            course_id
+        );
+        Ok(conversations)
+    }
+
+    // Runs forever, calling `handler` whenever the unread count changes. The interval is
+    // re-read from the config on every round so that settings apply without a restart.
+    pub async fn poll_unread_conversations<F: Fn(i64)>(&self, handler: F) {
+        let mut last_count = None;
+        loop {
+            let (token, interval) = {
+                let config = self.config.read().await;
+                (config.token.clone(), config.conversation_poll_interval)
+            };
+            if interval == 0 || token.is_empty() {
+                tokio::time::sleep(DISABLED_POLL_CHECK_INTERVAL).await;
+                continue;
+            }
+            match self.client.get_unread_conversation_count(&token).await {
+                Ok(count) if last_count != Some(count) => {
+                    last_count = Some(count);
+                    handler(count);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Failed to poll unread conversations: {:?}", e),
+            }
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    }
+}
@@ -0,0 +1,612 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use super::{constants::DEADLINE_HISTORY_FILE_NAME, App};
+use crate::{
+    error::{AppError, Result},
+    model::{
+        Assignment, AssignmentOverride, DeadlineBatch, DeadlineChange, DeadlineChangeKind,
+        DeadlineDates, DeadlineOperation,
+    },
+    utils,
+};
+
+// Older batches are dropped from the history
+const MAX_DEADLINE_BATCHES: usize = 50;
+
+lazy_static! {
+    static ref DEADLINE_HISTORY_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn shift_date(date: &Option<String>, days: i64) -> Result<Option<String>> {
+    let Some(date) = date else {
+        return Ok(None);
+    };
+    let parsed = DateTime::parse_from_rfc3339(date)
+        .map_err(|_| AppError::InvalidDeadlineOperation(format!("invalid date {date}")))?;
+    let shifted = parsed.with_timezone(&Utc) + Duration::days(days);
+    Ok(Some(shifted.to_rfc3339_opts(SecondsFormat::Secs, true)))
+}
+
+fn shift_dates(dates: &DeadlineDates, days: i64) -> Result<DeadlineDates> {
+    Ok(DeadlineDates {
+        due_at: shift_date(&dates.due_at, days)?,
+        unlock_at: shift_date(&dates.unlock_at, days)?,
+        lock_at: shift_date(&dates.lock_at, days)?,
+    })
+}
+
+fn assignment_dates(assignment: &Assignment) -> DeadlineDates {
+    DeadlineDates {
+        due_at: assignment.due_at.clone(),
+        unlock_at: assignment.unlock_at.clone(),
+        lock_at: assignment.lock_at.clone(),
+    }
+}
+
+fn override_dates(assignment_override: &AssignmentOverride) -> DeadlineDates {
+    DeadlineDates {
+        due_at: assignment_override.due_at.clone(),
+        unlock_at: assignment_override.unlock_at.clone(),
+        lock_at: assignment_override.lock_at.clone(),
+    }
+}
+
+// Overrides listing students directly, as opposed to group or section overrides.
+// A student can be in at most one of them per assignment.
+fn is_student_override(assignment_override: &AssignmentOverride) -> bool {
+    assignment_override.group_id == 0 && assignment_override.course_section_id == 0
+}
+
+fn update_assignment_change(assignment: &Assignment, after: DeadlineDates) -> DeadlineChange {
+    DeadlineChange {
+        kind: DeadlineChangeKind::UpdateAssignment,
+        assignment_id: assignment.id,
+        assignment_name: assignment.name.clone(),
+        before: assignment_dates(assignment),
+        after,
+        ..Default::default()
+    }
+}
+
+fn update_override_change(
+    assignment: &Assignment,
+    assignment_override: &AssignmentOverride,
+    after: DeadlineDates,
+) -> DeadlineChange {
+    DeadlineChange {
+        kind: DeadlineChangeKind::UpdateOverride,
+        assignment_id: assignment.id,
+        assignment_name: assignment.name.clone(),
+        override_id: Some(assignment_override.id),
+        title: assignment_override.title.clone(),
+        student_ids: assignment_override.student_ids.clone(),
+        group_id: assignment_override.group_id,
+        course_section_id: assignment_override.course_section_id,
+        before: override_dates(assignment_override),
+        after,
+        ..Default::default()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct DeadlinePlan {
+    changes: Vec<DeadlineChange>,
+    warnings: Vec<String>,
+}
+
+fn plan_shift(assignments: &[&Assignment], days: i64) -> Result<DeadlinePlan> {
+    let mut plan = DeadlinePlan::default();
+    for assignment in assignments {
+        let dates = assignment_dates(assignment);
+        if dates != DeadlineDates::default() {
+            plan.changes.push(update_assignment_change(
+                assignment,
+                shift_dates(&dates, days)?,
+            ));
+        }
+        for assignment_override in &assignment.overrides {
+            let after = shift_dates(&override_dates(assignment_override), days)?;
+            plan.changes.push(update_override_change(
+                assignment,
+                assignment_override,
+                after,
+            ));
+        }
+    }
+    Ok(plan)
+}
+
+fn plan_extend(
+    assignments: &[&Assignment],
+    student_id: i64,
+    student_name: &str,
+    days: i64,
+) -> Result<DeadlinePlan> {
+    let mut plan = DeadlinePlan::default();
+    for assignment in assignments {
+        let student_overrides: Vec<_> = assignment
+            .overrides
+            .iter()
+            .filter(|assignment_override| assignment_override.student_ids.contains(&student_id))
+            .collect();
+        let own_override = student_overrides
+            .iter()
+            .find(|assignment_override| is_student_override(assignment_override));
+        match own_override {
+            Some(assignment_override) if assignment_override.student_ids.len() == 1 => {
+                let after = shift_dates(&override_dates(assignment_override), days)?;
+                plan.changes.push(update_override_change(
+                    assignment,
+                    assignment_override,
+                    after,
+                ));
+            }
+            Some(assignment_override) => plan.warnings.push(format!(
+                "{}: {} is already in override \"{}\" with other students",
+                assignment.name, student_name, assignment_override.title
+            )),
+            None => {
+                // extends from the group or section dates when the student has any
+                let before = student_overrides
+                    .first()
+                    .map(|assignment_override| override_dates(assignment_override))
+                    .unwrap_or_else(|| assignment_dates(assignment));
+                if before.due_at.is_none() {
+                    plan.warnings
+                        .push(format!("{}: no due date to extend", assignment.name));
+                    continue;
+                }
+                let after = DeadlineDates {
+                    due_at: shift_date(&before.due_at, days)?,
+                    unlock_at: before.unlock_at.clone(),
+                    lock_at: shift_date(&before.lock_at, days)?,
+                };
+                plan.changes.push(DeadlineChange {
+                    kind: DeadlineChangeKind::CreateOverride,
+                    assignment_id: assignment.id,
+                    assignment_name: assignment.name.clone(),
+                    title: student_name.to_owned(),
+                    student_ids: vec![student_id],
+                    before,
+                    after,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    Ok(plan)
+}
+
+fn plan_copy_overrides(source: &Assignment, targets: &[&Assignment]) -> DeadlinePlan {
+    let mut plan = DeadlinePlan::default();
+    for target in targets.iter().filter(|target| target.id != source.id) {
+        for assignment_override in &source.overrides {
+            let conflict = if assignment_override.group_id != 0 {
+                if target.group_category_id != source.group_category_id {
+                    Some("the group set differs")
+                } else if target
+                    .overrides
+                    .iter()
+                    .any(|existing| existing.group_id == assignment_override.group_id)
+                {
+                    Some("the group already has an override")
+                } else {
+                    None
+                }
+            } else if assignment_override.course_section_id != 0 {
+                target
+                    .overrides
+                    .iter()
+                    .any(|existing| {
+                        existing.course_section_id == assignment_override.course_section_id
+                    })
+                    .then_some("the section already has an override")
+            } else {
+                target
+                    .overrides
+                    .iter()
+                    .filter(|existing| is_student_override(existing))
+                    .any(|existing| {
+                        existing
+                            .student_ids
+                            .iter()
+                            .any(|id| assignment_override.student_ids.contains(id))
+                    })
+                    .then_some("some students already have an override")
+            };
+            if let Some(conflict) = conflict {
+                plan.warnings.push(format!(
+                    "{}: skipped override \"{}\", {}",
+                    target.name, assignment_override.title, conflict
+                ));
+                continue;
+            }
+            plan.changes.push(DeadlineChange {
+                kind: DeadlineChangeKind::CreateOverride,
+                assignment_id: target.id,
+                assignment_name: target.name.clone(),
+                title: assignment_override.title.clone(),
+                // group overrides carry the expanded members, which canvas does not accept
+                student_ids: if is_student_override(assignment_override) {
+                    assignment_override.student_ids.clone()
+                } else {
+                    vec![]
+                },
+                group_id: assignment_override.group_id,
+                course_section_id: assignment_override.course_section_id,
+                before: assignment_dates(target),
+                after: override_dates(assignment_override),
+                ..Default::default()
+            });
+        }
+    }
+    plan
+}
+
+fn select_assignments<'a>(assignments: &'a [Assignment], ids: &[i64]) -> Vec<&'a Assignment> {
+    assignments
+        .iter()
+        .filter(|assignment| ids.is_empty() || ids.contains(&assignment.id))
+        .collect()
+}
+
+impl App {
+    fn deadline_history_path() -> Result<PathBuf> {
+        Ok(Path::new(&App::config_dir()?).join(DEADLINE_HISTORY_FILE_NAME))
+    }
+
+    fn read_deadline_history() -> Result<Vec<DeadlineBatch>> {
+        let path = App::deadline_history_path()?;
+        if fs::metadata(&path).is_err() {
+            return Ok(vec![]);
+        }
+        let content = fs::read(&path)?;
+        utils::json::parse_json(&content)
+    }
+
+    // Replaces the batch with the same id, or adds it as the newest one
+    fn save_deadline_batch(batch: &DeadlineBatch) -> Result<()> {
+        let _guard = DEADLINE_HISTORY_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        let mut history = App::read_deadline_history()?;
+        match history.iter_mut().find(|saved| saved.id == batch.id) {
+            Some(saved) => *saved = batch.clone(),
+            None => history.insert(0, batch.clone()),
+        }
+        history.truncate(MAX_DEADLINE_BATCHES);
+        fs::write(App::deadline_history_path()?, serde_json::to_vec(&history)?)?;
+        Ok(())
+    }
+
+    pub fn list_deadline_batches(&self, course_id: i64) -> Result<Vec<DeadlineBatch>> {
+        let _guard = DEADLINE_HISTORY_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        Ok(App::read_deadline_history()?
+            .into_iter()
+            .filter(|batch| batch.course_id == course_id)
+            .collect())
+    }
+
+    pub async fn preview_deadline_operation(
+        &self,
+        course_id: i64,
+        operation: &DeadlineOperation,
+    ) -> Result<DeadlineBatch> {
+        let assignments = self
+            .list_course_assignments_with_group_members(course_id)
+            .await?;
+        let plan = match operation {
+            DeadlineOperation::Shift {
+                assignment_ids,
+                days,
+            } => plan_shift(&select_assignments(&assignments, assignment_ids), *days)?,
+            DeadlineOperation::Extend {
+                student_id,
+                assignment_ids,
+                days,
+            } => {
+                if assignment_ids.is_empty() {
+                    return Err(AppError::InvalidDeadlineOperation(
+                        "no assignment selected".to_owned(),
+                    ));
+                }
+                let students = self.list_course_students(course_id).await?;
+                let student_name = students
+                    .iter()
+                    .find(|student| student.id == *student_id)
+                    .map(|student| student.name.clone())
+                    .unwrap_or_else(|| student_id.to_string());
+                plan_extend(
+                    &select_assignments(&assignments, assignment_ids),
+                    *student_id,
+                    &student_name,
+                    *days,
+                )?
+            }
+            DeadlineOperation::CopyOverrides {
+                source_assignment_id,
+                target_assignment_ids,
+            } => {
+                let Some(source) = assignments
+                    .iter()
+                    .find(|assignment| assignment.id == *source_assignment_id)
+                else {
+                    return Err(AppError::InvalidDeadlineOperation(format!(
+                        "assignment {source_assignment_id} not found"
+                    )));
+                };
+                if target_assignment_ids.is_empty() {
+                    return Err(AppError::InvalidDeadlineOperation(
+                        "no target assignment selected".to_owned(),
+                    ));
+                }
+                plan_copy_overrides(
+                    source,
+                    &select_assignments(&assignments, target_assignment_ids),
+                )
+            }
+        };
+        Ok(DeadlineBatch {
+            id: Uuid::new_v4().to_string(),
+            course_id,
+            operation: operation.clone(),
+            changes: plan.changes,
+            warnings: plan.warnings,
+            applied_at: None,
+            reverted_at: None,
+        })
+    }
+
+    async fn apply_deadline_change(
+        &self,
+        course_id: i64,
+        change: &mut DeadlineChange,
+        token: &str,
+    ) -> Result<()> {
+        match change.kind {
+            DeadlineChangeKind::UpdateAssignment => {
+                self.client
+                    .update_assignment_dates(course_id, change.assignment_id, &change.after, token)
+                    .await
+            }
+            DeadlineChangeKind::UpdateOverride => {
+                let override_id = change.override_id.unwrap_or_default();
+                self.client
+                    .update_assignment_override_dates(
+                        course_id,
+                        change.assignment_id,
+                        override_id,
+                        &change.after,
+                        token,
+                    )
+                    .await
+            }
+            DeadlineChangeKind::CreateOverride => {
+                let created = self
+                    .client
+                    .create_assignment_override(course_id, change, token)
+                    .await?;
+                change.override_id = Some(created.id);
+                Ok(())
+            }
+        }
+    }
+
+    async fn revert_deadline_change(
+        &self,
+        course_id: i64,
+        change: &DeadlineChange,
+        token: &str,
+    ) -> Result<()> {
+        let override_id = change.override_id.unwrap_or_default();
+        match change.kind {
+            DeadlineChangeKind::UpdateAssignment => {
+                self.client
+                    .update_assignment_dates(course_id, change.assignment_id, &change.before, token)
+                    .await
+            }
+            DeadlineChangeKind::UpdateOverride => {
+                self.client
+                    .update_assignment_override_dates(
+                        course_id,
+                        change.assignment_id,
+                        override_id,
+                        &change.before,
+                        token,
+                    )
+                    .await
+            }
+            // an override that is gone was deleted by an earlier, interrupted revert
+            DeadlineChangeKind::CreateOverride => match self
+                .client
+                .delete_assignment_ddl_override(course_id, change.assignment_id, override_id, token)
+                .await
+            {
+                Err(AppError::Network(e)) if e.status() == Some(StatusCode::NOT_FOUND) => Ok(()),
+                result => result,
+            },
+        }
+    }
+
+    // Applies the previewed batch exactly as the user approved it. When a change fails,
+    // the ones already applied are still saved so that they can be reverted.
+    pub async fn apply_deadline_batch(&self, mut batch: DeadlineBatch) -> Result<DeadlineBatch> {
+        let already_applied = {
+            let _guard = DEADLINE_HISTORY_LOCK
+                .lock()
+                .map_err(|_| AppError::MutexError)?;
+            App::read_deadline_history()?
+                .iter()
+                .any(|saved| saved.id == batch.id)
+        };
+        if already_applied || batch.applied_at.is_some() {
+            return Err(AppError::InvalidDeadlineOperation(format!(
+                "batch {} is already applied",
+                batch.id
+            )));
+        }
+        let course_id = batch.course_id;
+        let token = self.config.read().await.token.clone();
+        let mut result = Ok(());
+        let mut applied = 0;
+        for change in batch.changes.iter_mut() {
+            if let Err(e) = self.apply_deadline_change(course_id, change, &token).await {
+                tracing::error!(
+                    "Failed to apply deadline change of assignment {}: {:?}",
+                    change.assignment_id,
+                    e
+                );
+                result = Err(e);
+                break;
+            }
+            applied += 1;
+        }
+        batch.changes.truncate(applied);
+        batch.applied_at = Some(Utc::now().to_rfc3339());
+        if applied > 0 {
+            App::save_deadline_batch(&batch)?;
+        }
+        tracing::info!(
+            "Applied {} deadline changes of course {}",
+            applied,
+            course_id
+        );
+        result.map(|_| batch)
+    }
+
+    pub async fn revert_deadline_batch(&self, batch_id: &str) -> Result<DeadlineBatch> {
+        let mut batch = {
+            let _guard = DEADLINE_HISTORY_LOCK
+                .lock()
+                .map_err(|_| AppError::MutexError)?;
+            App::read_deadline_history()?
+                .into_iter()
+                .find(|batch| batch.id == batch_id)
+                .ok_or_else(|| AppError::DeadlineBatchNotFound(batch_id.to_owned()))?
+        };
+        if batch.reverted_at.is_some() {
+            return Err(AppError::InvalidDeadlineOperation(format!(
+                "batch {batch_id} is already reverted"
+            )));
+        }
+        // Progress is saved after every change, so a retry resumes where the last attempt stopped
+        let token = self.config.read().await.token.clone();
+        for index in (0..batch.changes.len()).rev() {
+            if batch.changes[index].reverted {
+                continue;
+            }
+            self.revert_deadline_change(batch.course_id, &batch.changes[index], &token)
+                .await?;
+            batch.changes[index].reverted = true;
+            App::save_deadline_batch(&batch)?;
+        }
+        batch.reverted_at = Some(Utc::now().to_rfc3339());
+        App::save_deadline_batch(&batch)?;
+        Ok(batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> Option<String> {
+        Some(format!("2024-03-{day:02}T15:59:59Z"))
+    }
+
+    fn student_override(
+        id: i64,
+        student_ids: Vec<i64>,
+        due_at: Option<String>,
+    ) -> AssignmentOverride {
+        AssignmentOverride {
+            id,
+            student_ids,
+            title: format!("Override {id}"),
+            due_at,
+            ..Default::default()
+        }
+    }
+
+    fn assignment(id: i64, overrides: Vec<AssignmentOverride>) -> Assignment {
+        Assignment {
+            id,
+            name: format!("Assignment {id}"),
+            due_at: date(1),
+            lock_at: date(2),
+            overrides,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_shift_date() {
+        assert_eq!(
+            shift_date(&Some("2024-02-28T23:59:59+08:00".to_owned()), 2).unwrap(),
+            Some("2024-03-01T15:59:59Z".to_owned())
+        );
+        assert_eq!(shift_date(&None, 2).unwrap(), None);
+        assert!(shift_date(&Some("tomorrow".to_owned()), 1).is_err());
+    }
+
+    #[test]
+    fn test_plan_shift() {
+        let assignments = vec![assignment(1, vec![student_override(5, vec![10], date(3))])];
+        let plan = plan_shift(&select_assignments(&assignments, &[]), 7).unwrap();
+
+        assert_eq!(plan.changes.len(), 2);
+        assert_eq!(plan.changes[0].kind, DeadlineChangeKind::UpdateAssignment);
+        assert_eq!(plan.changes[0].after.due_at, date(8));
+        assert_eq!(plan.changes[0].after.lock_at, date(9));
+        assert_eq!(plan.changes[1].override_id, Some(5));
+        assert_eq!(plan.changes[1].before.due_at, date(3));
+        assert_eq!(plan.changes[1].after.due_at, date(10));
+    }
+
+    #[test]
+    fn test_plan_extend() {
+        let assignments = vec![
+            assignment(1, vec![]),
+            assignment(2, vec![student_override(5, vec![10], date(3))]),
+            assignment(3, vec![student_override(6, vec![10, 11], date(3))]),
+        ];
+        let plan = plan_extend(&select_assignments(&assignments, &[]), 10, "Alice", 2).unwrap();
+
+        assert_eq!(plan.changes.len(), 2);
+        assert_eq!(plan.changes[0].kind, DeadlineChangeKind::CreateOverride);
+        assert_eq!(plan.changes[0].student_ids, vec![10]);
+        assert_eq!(plan.changes[0].after.due_at, date(3));
+        assert_eq!(plan.changes[1].kind, DeadlineChangeKind::UpdateOverride);
+        assert_eq!(plan.changes[1].after.due_at, date(5));
+        assert_eq!(plan.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_plan_copy_overrides() {
+        let source = assignment(
+            1,
+            vec![
+                student_override(5, vec![10], date(3)),
+                student_override(6, vec![11], date(4)),
+            ],
+        );
+        let target = assignment(2, vec![student_override(7, vec![11], date(5))]);
+        let plan = plan_copy_overrides(&source, &[&source, &target]);
+
+        assert_eq!(plan.changes.len(), 1);
+        assert_eq!(plan.changes[0].assignment_id, 2);
+        assert_eq!(plan.changes[0].student_ids, vec![10]);
+        assert_eq!(plan.changes[0].after.due_at, date(3));
+        assert_eq!(plan.warnings.len(), 1);
+    }
+}
@@ -0,0 +1,91 @@
+use super::App;
+use crate::{
+    error::Result,
+    model::{DiscussionEntry, DiscussionTopic, DiscussionTopicInput},
+};
+
+impl App {
+    pub async fn create_discussion_topic(
+        &self,
+        course_id: i64,
+        input: &DiscussionTopicInput,
+        attachment: Option<&str>,
+    ) -> Result<DiscussionTopic> {
+        self.client
+            .create_discussion_topic(
+                course_id,
+                input,
+                attachment,
+                &self.config.read().await.token,
+            )
+            .await
+    }
+
+    // Replies to the topic itself when `entry_id` is None
+    pub async fn post_discussion_reply(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: Option<i64>,
+        message: &str,
+        attachment: Option<&str>,
+    ) -> Result<DiscussionEntry> {
+        let token = self.config.read().await.token.clone();
+        match entry_id {
+            Some(entry_id) => {
+                self.client
+                    .reply_discussion_entry(
+                        course_id, topic_id, entry_id, message, attachment, &token,
+                    )
+                    .await
+            }
+            None => {
+                self.client
+                    .post_discussion_entry(course_id, topic_id, message, attachment, &token)
+                    .await
+            }
+        }
+    }
+
+    pub async fn rate_discussion_entry(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: i64,
+        liked: bool,
+    ) -> Result<()> {
+        self.client
+            .rate_discussion_entry(
+                course_id,
+                topic_id,
+                entry_id,
+                liked,
+                &self.config.read().await.token,
+            )
+            .await
+    }
+
+    pub async fn mark_discussion_entry_read(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: i64,
+        read: bool,
+    ) -> Result<()> {
+        self.client
+            .mark_discussion_entry_read(
+                course_id,
+                topic_id,
+                entry_id,
+                read,
+                &self.config.read().await.token,
+            )
+            .await
+    }
+
+    pub async fn mark_discussion_topic_read(&self, course_id: i64, topic_id: i64) -> Result<()> {
+        self.client
+            .mark_discussion_topic_read(course_id, topic_id, &self.config.read().await.token)
+            .await
+    }
+}
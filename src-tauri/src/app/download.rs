@@ -0,0 +1,530 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    constants::DOWNLOAD_QUEUE_FILE_NAME,
+    sync::{archive_previous_version, record_synced_file},
+    App,
+};
+use crate::{
+    client::Client,
+    error::{AppError, Result},
+    model::{DownloadJob, DownloadState, DownloadTarget, ProgressPayload},
+    utils::{self, file::get_part_path},
+};
+
+pub type DownloadProgressSink = Arc<dyn Fn(&DownloadJob) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct DownloadContext {
+    pub client: Arc<Client>,
+    pub token: String,
+    pub max_concurrent: usize,
+}
+
+#[derive(Default)]
+struct DownloadQueue {
+    jobs: Vec<DownloadJob>,
+    running: HashMap<String, CancellationToken>,
+    waiters: HashMap<String, Vec<oneshot::Sender<Result<DownloadState>>>>,
+    next_seq: u64,
+}
+
+impl DownloadQueue {
+    fn job_mut(&mut self, id: &str) -> Result<&mut DownloadJob> {
+        self.jobs
+            .iter_mut()
+            .find(|job| job.id == id)
+            .ok_or_else(|| AppError::DownloadJobNotFound(id.to_owned()))
+    }
+
+    // Highest priority first, FIFO among equal priorities
+    fn next_queued_job(&self) -> Option<usize> {
+        self.jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| job.state == DownloadState::Queued)
+            .max_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.seq.cmp(&a.seq)))
+            .map(|(index, _)| index)
+    }
+
+    fn notify_waiters(&mut self, id: &str, result: impl Fn() -> Result<DownloadState>) {
+        for waiter in self.waiters.remove(id).unwrap_or_default() {
+            _ = waiter.send(result());
+        }
+    }
+}
+
+pub struct DownloadManager {
+    queue: Mutex<DownloadQueue>,
+    queue_path: Option<PathBuf>,
+    progress_sink: Mutex<Option<DownloadProgressSink>>,
+}
+
+// Jobs interrupted by an exit are queued again, finished ones are not kept
+fn load_jobs(queue_path: &Path) -> Vec<DownloadJob> {
+    let Ok(content) = fs::read(queue_path) else {
+        return vec![];
+    };
+    let jobs: Vec<DownloadJob> = match utils::json::parse_json(&content) {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            tracing::error!("Failed to load download queue: {}", e);
+            return vec![];
+        }
+    };
+    jobs.into_iter()
+        .filter(|job| {
+            !matches!(
+                job.state,
+                DownloadState::Completed | DownloadState::Cancelled
+            )
+        })
+        .map(|mut job| {
+            if job.state == DownloadState::Running {
+                job.state = DownloadState::Queued;
+            }
+            job
+        })
+        .collect()
+}
+
+impl DownloadManager {
+    pub fn new(queue_path: Option<PathBuf>) -> Self {
+        let jobs = queue_path.as_deref().map(load_jobs).unwrap_or_default();
+        let next_seq = jobs.iter().map(|job| job.seq + 1).max().unwrap_or_default();
+        Self {
+            queue: Mutex::new(DownloadQueue {
+                jobs,
+                next_seq,
+                ..Default::default()
+            }),
+            queue_path,
+            progress_sink: Default::default(),
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, DownloadQueue>> {
+        self.queue.lock().map_err(|_| AppError::MutexError)
+    }
+
+    fn persist(&self, queue: &DownloadQueue) -> Result<()> {
+        if let Some(queue_path) = &self.queue_path {
+            fs::write(queue_path, serde_json::to_vec(&queue.jobs)?)?;
+        }
+        Ok(())
+    }
+
+    fn emit(&self, job: &DownloadJob) {
+        let sink = self.progress_sink.lock().ok().and_then(|sink| sink.clone());
+        if let Some(sink) = sink {
+            sink(job);
+        }
+    }
+
+    pub fn set_progress_sink(&self, sink: DownloadProgressSink) -> Result<()> {
+        *self
+            .progress_sink
+            .lock()
+            .map_err(|_| AppError::MutexError)? = Some(sink);
+        Ok(())
+    }
+
+    pub fn list_jobs(&self) -> Result<Vec<DownloadJob>> {
+        Ok(self.lock()?.jobs.clone())
+    }
+
+    // Queue a job, or wait for the existing one if a job with the same id is unfinished.
+    // Waiters resolve with the state the job ends up in, or `Paused` once it gets paused.
+    pub fn submit(
+        self: &Arc<Self>,
+        id: &str,
+        target: DownloadTarget,
+        priority: i64,
+        total: u64,
+        ctx: DownloadContext,
+    ) -> Result<oneshot::Receiver<Result<DownloadState>>> {
+        let (tx, rx) = oneshot::channel();
+        let job = {
+            let mut queue = self.lock()?;
+            queue.waiters.entry(id.to_owned()).or_default().push(tx);
+            let unfinished = queue
+                .jobs
+                .iter()
+                .any(|job| job.id == id && !job.state.is_finished());
+            if unfinished {
+                return Ok(rx);
+            }
+            queue.jobs.retain(|job| job.id != id);
+            let job = DownloadJob {
+                id: id.to_owned(),
+                target,
+                priority,
+                seq: queue.next_seq,
+                state: DownloadState::Queued,
+                processed: 0,
+                total,
+                error: None,
+            };
+            queue.next_seq += 1;
+            queue.jobs.push(job.clone());
+            self.persist(&queue)?;
+            job
+        };
+        self.emit(&job);
+        self.schedule(ctx)?;
+        Ok(rx)
+    }
+
+    pub fn pause(self: &Arc<Self>, id: &str, ctx: DownloadContext) -> Result<()> {
+        let job = {
+            let mut queue = self.lock()?;
+            let job = queue.job_mut(id)?;
+            if !matches!(job.state, DownloadState::Queued | DownloadState::Running) {
+                return Ok(());
+            }
+            job.state = DownloadState::Paused;
+            let job = job.clone();
+            if let Some(token) = queue.running.remove(id) {
+                token.cancel();
+            }
+            queue.notify_waiters(id, || Ok(DownloadState::Paused));
+            self.persist(&queue)?;
+            job
+        };
+        self.emit(&job);
+        self.schedule(ctx)
+    }
+
+    pub fn resume(self: &Arc<Self>, id: &str, ctx: DownloadContext) -> Result<()> {
+        let job = {
+            let mut queue = self.lock()?;
+            let job = queue.job_mut(id)?;
+            if !matches!(job.state, DownloadState::Paused | DownloadState::Failed) {
+                return Ok(());
+            }
+            job.state = DownloadState::Queued;
+            job.error = None;
+            let job = job.clone();
+            self.persist(&queue)?;
+            job
+        };
+        self.emit(&job);
+        self.schedule(ctx)
+    }
+
+    pub fn cancel(self: &Arc<Self>, id: &str, ctx: DownloadContext) -> Result<()> {
+        let mut job = {
+            let mut queue = self.lock()?;
+            let index = queue
+                .jobs
+                .iter()
+                .position(|job| job.id == id)
+                .ok_or_else(|| AppError::DownloadJobNotFound(id.to_owned()))?;
+            let job = queue.jobs.remove(index);
+            if let Some(token) = queue.running.remove(id) {
+                token.cancel();
+            }
+            queue.notify_waiters(id, || Err(AppError::DownloadCancelled(id.to_owned())));
+            self.persist(&queue)?;
+            job
+        };
+        if let DownloadTarget::File { file, save_dir, .. } = &job.target {
+            let part_path = get_part_path(&Path::new(save_dir).join(&file.display_name));
+            _ = fs::remove_file(part_path);
+        }
+        job.state = DownloadState::Cancelled;
+        self.emit(&job);
+        self.schedule(ctx)
+    }
+
+    pub fn schedule(self: &Arc<Self>, ctx: DownloadContext) -> Result<()> {
+        loop {
+            let (job, token) = {
+                let mut queue = self.lock()?;
+                if queue.running.len() >= ctx.max_concurrent.max(1) {
+                    return Ok(());
+                }
+                let Some(index) = queue.next_queued_job() else {
+                    return Ok(());
+                };
+                let job = &mut queue.jobs[index];
+                job.state = DownloadState::Running;
+                let job = job.clone();
+                let token = CancellationToken::new();
+                queue.running.insert(job.id.clone(), token.clone());
+                self.persist(&queue)?;
+                (job, token)
+            };
+            self.emit(&job);
+            tokio::spawn(self.clone().run_job(job, token, ctx.clone()));
+        }
+    }
+
+    async fn run_job(
+        self: Arc<Self>,
+        job: DownloadJob,
+        token: CancellationToken,
+        ctx: DownloadContext,
+    ) {
+        tracing::info!("Start download job {}", job.id);
+        let result = tokio::select! {
+            // Paused or cancelled, the job state was already updated
+            _ = token.cancelled() => return,
+            result = self.download(&job, &ctx) => result,
+        };
+        if let Err(e) = self.finish(&job.id, &token, result) {
+            tracing::error!("Failed to finish download job {}: {}", job.id, e);
+        }
+        if let Err(e) = self.schedule(ctx) {
+            tracing::error!("Failed to schedule download jobs: {}", e);
+        }
+    }
+
+    async fn download(self: &Arc<Self>, job: &DownloadJob, ctx: &DownloadContext) -> Result<()> {
+        let manager = self.clone();
+        let id = job.id.clone();
+        let progress_handler =
+            move |payload: ProgressPayload| manager.update_progress(&id, payload);
+        match &job.target {
+            DownloadTarget::File {
+                file,
+                save_dir,
+                course_sync,
+            } => {
+                fs::create_dir_all(save_dir)?;
+                if let Some(course_sync) = course_sync {
+                    archive_previous_version(
+                        Path::new(&course_sync.course_dir),
+                        course_sync.course_id,
+                        file,
+                        &course_sync.folder_path,
+                    )?;
+                }
+                ctx.client
+                    .download_file(file, &ctx.token, save_dir, progress_handler)
+                    .await?;
+                if let Some(course_sync) = course_sync {
+                    record_synced_file(
+                        Path::new(&course_sync.course_dir),
+                        course_sync.course_id,
+                        file,
+                        &course_sync.folder_path,
+                    )?;
+                }
+            }
+            DownloadTarget::Video { video, save_path } => {
+                ctx.client
+                    .clone()
+                    .download_video(video, save_path, progress_handler)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn update_progress(&self, id: &str, payload: ProgressPayload) {
+        let job = {
+            let Ok(mut queue) = self.lock() else {
+                return;
+            };
+            let Ok(job) = queue.job_mut(id) else {
+                return;
+            };
+            if job.state != DownloadState::Running {
+                return;
+            }
+            job.processed = payload.processed;
+            job.total = payload.total;
+            job.clone()
+        };
+        self.emit(&job);
+    }
+
+    fn finish(&self, id: &str, token: &CancellationToken, result: Result<()>) -> Result<()> {
+        let job = {
+            let mut queue = self.lock()?;
+            // A pause or cancel raced with the end of the download
+            if token.is_cancelled() {
+                return Ok(());
+            }
+            queue.running.remove(id);
+            let job = queue.job_mut(id)?;
+            match &result {
+                Ok(()) => {
+                    job.state = DownloadState::Completed;
+                    job.processed = job.total;
+                }
+                Err(e) => {
+                    tracing::error!("Download job {} failed: {}", id, e);
+                    job.state = DownloadState::Failed;
+                    job.error = Some(e.to_string());
+                }
+            }
+            let job = job.clone();
+            if job.state == DownloadState::Completed {
+                queue.jobs.retain(|job| job.id != id);
+            }
+            let error = job.error.clone().unwrap_or_default();
+            queue.notify_waiters(id, || match job.state {
+                DownloadState::Completed => Ok(DownloadState::Completed),
+                _ => Err(AppError::DownloadJobError(error.clone())),
+            });
+            self.persist(&queue)?;
+            job
+        };
+        self.emit(&job);
+        Ok(())
+    }
+}
+
+impl App {
+    pub fn new_download_manager(config_dir: &str) -> DownloadManager {
+        DownloadManager::new(Some(Path::new(config_dir).join(DOWNLOAD_QUEUE_FILE_NAME)))
+    }
+
+    async fn download_context(&self) -> DownloadContext {
+        let config = self.config.read().await;
+        DownloadContext {
+            client: self.client.clone(),
+            token: config.token.clone(),
+            max_concurrent: config.max_concurrent_downloads,
+        }
+    }
+
+    // Start the jobs restored from the persisted queue, progress of every job goes to `sink`
+    pub async fn start_download_manager(&self, sink: DownloadProgressSink) -> Result<()> {
+        self.download_manager.set_progress_sink(sink)?;
+        let ctx = self.download_context().await;
+        self.download_manager.schedule(ctx)
+    }
+
+    // Queue a download and wait until it completes, fails, gets cancelled or paused.
+    // Returns `Completed` or `Paused`, a paused job reports its completion through the
+    // progress sink once it is resumed.
+    pub async fn enqueue_download(
+        &self,
+        id: &str,
+        target: DownloadTarget,
+        priority: i64,
+        total: u64,
+    ) -> Result<DownloadState> {
+        let ctx = self.download_context().await;
+        let downloaded_path = match &target {
+            DownloadTarget::File { file, save_dir, .. } => {
+                Some(Path::new(save_dir).join(&file.display_name))
+            }
+            DownloadTarget::Video { .. } => None,
+        };
+        let rx = self
+            .download_manager
+            .submit(id, target, priority, total, ctx)?;
+        let state = rx
+            .await
+            .map_err(|_| AppError::DownloadJobError(id.to_owned()))??;
+        if let Some(path) = downloaded_path.filter(|_| state == DownloadState::Completed) {
+            if let Err(e) = self.index_downloaded_file(&path).await {
+                tracing::warn!("Failed to index {:?}: {}", path, e);
+            }
+        }
+        Ok(state)
+    }
+
+    pub fn list_download_jobs(&self) -> Result<Vec<DownloadJob>> {
+        self.download_manager.list_jobs()
+    }
+
+    pub async fn pause_download(&self, id: &str) -> Result<()> {
+        let ctx = self.download_context().await;
+        self.download_manager.pause(id, ctx)
+    }
+
+    pub async fn resume_download(&self, id: &str) -> Result<()> {
+        let ctx = self.download_context().await;
+        self.download_manager.resume(id, ctx)
+    }
+
+    pub async fn cancel_download(&self, id: &str) -> Result<()> {
+        let ctx = self.download_context().await;
+        self.download_manager.cancel(id, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DownloadTarget, File};
+
+    fn job(id: &str, priority: i64, seq: u64, state: DownloadState) -> DownloadJob {
+        DownloadJob {
+            id: id.to_owned(),
+            target: DownloadTarget::File {
+                file: File::default(),
+                save_dir: String::new(),
+                course_sync: None,
+            },
+            priority,
+            seq,
+            state,
+            processed: 0,
+            total: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_next_queued_job() {
+        let mut queue = DownloadQueue {
+            jobs: vec![
+                job("a", 0, 0, DownloadState::Queued),
+                job("b", 5, 1, DownloadState::Paused),
+                job("c", 1, 2, DownloadState::Queued),
+                job("d", 1, 3, DownloadState::Queued),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(queue.next_queued_job(), Some(2));
+        queue.jobs[2].state = DownloadState::Running;
+        assert_eq!(queue.next_queued_job(), Some(3));
+        queue.jobs[3].state = DownloadState::Running;
+        assert_eq!(queue.next_queued_job(), Some(0));
+    }
+
+    #[test]
+    fn test_load_jobs() -> Result<()> {
+        let queue_path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        let jobs = vec![
+            job("running", 0, 0, DownloadState::Running),
+            job("paused", 0, 1, DownloadState::Paused),
+            job("failed", 0, 2, DownloadState::Failed),
+            job("completed", 0, 3, DownloadState::Completed),
+            job("cancelled", 0, 4, DownloadState::Cancelled),
+        ];
+        fs::write(&queue_path, serde_json::to_vec(&jobs)?)?;
+        let manager = DownloadManager::new(Some(queue_path.clone()));
+        _ = fs::remove_file(&queue_path);
+
+        let jobs = manager.list_jobs()?;
+        let states: Vec<_> = jobs
+            .iter()
+            .map(|job| (job.id.as_str(), job.state))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                ("running", DownloadState::Queued),
+                ("paused", DownloadState::Paused),
+                ("failed", DownloadState::Failed),
+            ]
+        );
+        assert_eq!(manager.lock()?.next_seq, 3);
+        Ok(())
+    }
+}
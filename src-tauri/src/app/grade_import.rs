@@ -0,0 +1,486 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    time::Duration,
+};
+
+use calamine::{open_workbook_auto, Reader};
+
+use super::App;
+use crate::{
+    error::{AppError, Result},
+    model::{
+        CanvasProgress, GradeImportPreview, GradeImportRow, GradeImportStatus, GradeUpdate,
+        GradingType, Submission, User,
+    },
+};
+
+// Keeps every `update_grades` request well below canvas' form size limits
+const GRADE_IMPORT_BATCH_SIZE: usize = 50;
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const PROGRESS_MAX_POLLS: usize = 300;
+
+const ID_HEADERS: [&str; 3] = ["id", "student_id", "user_id"];
+const LOGIN_ID_HEADERS: [&str; 2] = ["login_id", "学号"];
+const GRADE_HEADERS: [&str; 4] = ["grade", "score", "成绩", "分数"];
+const COMMENT_HEADERS: [&str; 2] = ["comment", "评语"];
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct GradeSheetRow {
+    row: usize,
+    student_id: Option<String>,
+    login_id: Option<String>,
+    grade: String,
+    comment: Option<String>,
+}
+
+fn read_grade_sheet(path: &Path) -> Result<Vec<Vec<String>>> {
+    let is_csv = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(path)?;
+        let mut rows = vec![];
+        for record in reader.records() {
+            rows.push(record?.iter().map(ToOwned::to_owned).collect());
+        }
+        return Ok(rows);
+    }
+
+    let mut workbook = open_workbook_auto(path)?;
+    let Some(range) = workbook.worksheet_range_at(0) else {
+        return Ok(vec![]);
+    };
+    Ok(range?
+        .rows()
+        .map(|row| row.iter().map(ToString::to_string).collect())
+        .collect())
+}
+
+fn find_column(headers: &[String], aliases: &[&str]) -> Option<usize> {
+    headers.iter().position(|header| {
+        // spreadsheets saved by excel may start with a BOM
+        let header = header.trim_start_matches('\u{feff}').trim();
+        aliases
+            .iter()
+            .any(|alias| header.eq_ignore_ascii_case(alias))
+    })
+}
+
+fn parse_grade_sheet(rows: &[Vec<String>]) -> Result<Vec<GradeSheetRow>> {
+    let Some(headers) = rows.first() else {
+        return Err(AppError::InvalidGradeImport("empty spreadsheet".to_owned()));
+    };
+    let id_col = find_column(headers, &ID_HEADERS);
+    let login_id_col = find_column(headers, &LOGIN_ID_HEADERS);
+    if id_col.is_none() && login_id_col.is_none() {
+        return Err(AppError::InvalidGradeImport(
+            "missing a student id or login_id column".to_owned(),
+        ));
+    }
+    let Some(grade_col) = find_column(headers, &GRADE_HEADERS) else {
+        return Err(AppError::InvalidGradeImport(
+            "missing a grade column".to_owned(),
+        ));
+    };
+    let comment_col = find_column(headers, &COMMENT_HEADERS);
+
+    let cell = |row: &[String], col: Option<usize>| {
+        col.and_then(|col| row.get(col))
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    };
+    Ok(rows
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, row)| row.iter().any(|value| !value.trim().is_empty()))
+        .map(|(i, row)| GradeSheetRow {
+            row: i + 1,
+            student_id: cell(row, id_col),
+            login_id: cell(row, login_id_col),
+            grade: cell(row, Some(grade_col)).unwrap_or_default(),
+            comment: cell(row, comment_col),
+        })
+        .collect())
+}
+
+fn parse_number(grade: &str) -> Option<f64> {
+    grade.parse::<f64>().ok().filter(|grade| grade.is_finite())
+}
+
+// Returns the grade as canvas expects it in `posted_grade`, or why it is rejected
+fn normalize_grade(grade: &str, grading_type: &GradingType) -> std::result::Result<String, String> {
+    match grading_type {
+        GradingType::Points => match parse_number(grade) {
+            Some(points) if points >= 0.0 => Ok(points.to_string()),
+            _ => Err(format!("{grade} is not a valid score")),
+        },
+        // a bare number would be taken as points
+        GradingType::Percent => match parse_number(grade.trim_end_matches('%')) {
+            Some(percent) if percent >= 0.0 => Ok(format!("{percent}%")),
+            _ => Err(format!("{grade} is not a valid percentage")),
+        },
+        GradingType::PassFail => match grade.to_lowercase().as_str() {
+            "complete" | "pass" => Ok("complete".to_owned()),
+            "incomplete" | "fail" => Ok("incomplete".to_owned()),
+            _ => Err(format!("{grade} is neither complete nor incomplete")),
+        },
+        // the grading scheme is resolved by canvas
+        GradingType::LetterGrade | GradingType::GpaScale => Ok(grade.to_owned()),
+        GradingType::NotGraded => Err("the assignment is not graded".to_owned()),
+    }
+}
+
+fn is_same_grade(current: &str, grade: &str) -> bool {
+    if current.eq_ignore_ascii_case(grade) {
+        return true;
+    }
+    matches!(
+        (
+            parse_number(current.trim_end_matches('%')),
+            parse_number(grade.trim_end_matches('%')),
+        ),
+        (Some(current), Some(grade)) if current == grade
+    )
+}
+
+fn find_student<'a>(students: &'a [User], sheet_row: &GradeSheetRow) -> Option<&'a User> {
+    if let Some(student_id) = &sheet_row.student_id {
+        if let Some(student) = students
+            .iter()
+            .find(|student| student.id.to_string() == *student_id)
+        {
+            return Some(student);
+        }
+    }
+    let login_id = sheet_row.login_id.as_ref()?;
+    students
+        .iter()
+        .find(|student| student.login_id == *login_id)
+}
+
+fn build_grade_import_rows(
+    sheet_rows: &[GradeSheetRow],
+    students: &[User],
+    submissions: &[Submission],
+    grading_type: &GradingType,
+) -> Vec<GradeImportRow> {
+    let submissions: HashMap<i64, &Submission> = submissions
+        .iter()
+        .map(|submission| (submission.user_id, submission))
+        .collect();
+    let mut seen_students = HashSet::new();
+
+    sheet_rows
+        .iter()
+        .map(|sheet_row| {
+            let mut row = GradeImportRow {
+                row: sheet_row.row,
+                student: sheet_row
+                    .student_id
+                    .clone()
+                    .or_else(|| sheet_row.login_id.clone())
+                    .unwrap_or_default(),
+                grade: sheet_row.grade.clone(),
+                comment: sheet_row.comment.clone(),
+                ..Default::default()
+            };
+            let Some(student) = find_student(students, sheet_row) else {
+                row.status = GradeImportStatus::Invalid;
+                row.message = Some("student is not enrolled in the course".to_owned());
+                return row;
+            };
+            row.student_id = Some(student.id);
+            row.student_name = Some(student.name.clone());
+            let submission = submissions.get(&student.id);
+            row.current_grade = submission.and_then(|submission| submission.grade.clone());
+
+            if !seen_students.insert(student.id) {
+                row.status = GradeImportStatus::Invalid;
+                row.message = Some("duplicate row of the same student".to_owned());
+                return row;
+            }
+            if row.grade.is_empty() {
+                row.status = GradeImportStatus::Skipped;
+                return row;
+            }
+            match normalize_grade(&row.grade, grading_type) {
+                Ok(grade) => row.grade = grade,
+                Err(message) => {
+                    row.status = GradeImportStatus::Invalid;
+                    row.message = Some(message);
+                    return row;
+                }
+            }
+
+            let grade_changed = !row
+                .current_grade
+                .as_ref()
+                .is_some_and(|current| is_same_grade(current, &row.grade));
+            // comments posted by an earlier import are not posted again
+            let comment_is_new = row.comment.as_ref().is_some_and(|comment| {
+                !submission.is_some_and(|submission| {
+                    submission
+                        .submission_comments
+                        .iter()
+                        .any(|submission_comment| submission_comment.comment == *comment)
+                })
+            });
+            if !grade_changed && !comment_is_new {
+                row.status = GradeImportStatus::Unchanged;
+            }
+            row
+        })
+        .collect()
+}
+
+impl App {
+    // Dry run of `apply_grade_import`, nothing is written to canvas
+    pub async fn preview_grade_import(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        file_path: &str,
+    ) -> Result<GradeImportPreview> {
+        let sheet_rows = parse_grade_sheet(&read_grade_sheet(Path::new(file_path))?)?;
+        let token = self.config.read().await.token.clone();
+        let assignment = self
+            .client
+            .get_assignment(course_id, assignment_id, &token)
+            .await?;
+        let students = self.list_course_students(course_id).await?;
+        let submissions = self
+            .list_course_assignment_submissions(course_id, assignment_id)
+            .await?;
+        Ok(GradeImportPreview {
+            rows: build_grade_import_rows(
+                &sheet_rows,
+                &students,
+                &submissions,
+                &assignment.grading_type,
+            ),
+            applied: false,
+        })
+    }
+
+    // Waits until canvas has run the job behind `progress`, failing when the job fails
+    async fn wait_for_progress(&self, mut progress: CanvasProgress) -> Result<CanvasProgress> {
+        let token = self.config.read().await.token.clone();
+        for _ in 0..PROGRESS_MAX_POLLS {
+            if progress.is_finished() {
+                break;
+            }
+            tokio::time::sleep(PROGRESS_POLL_INTERVAL).await;
+            progress = self.client.get_progress(progress.id, &token).await?;
+        }
+        match progress.workflow_state.as_str() {
+            "completed" => Ok(progress),
+            "failed" => Err(AppError::CanvasJobFailed(
+                progress.id,
+                progress.message.unwrap_or_default(),
+            )),
+            _ => Err(AppError::CanvasJobFailed(
+                progress.id,
+                "timed out waiting for canvas".to_owned(),
+            )),
+        }
+    }
+
+    // Applies the rows of the preview the user confirmed, only rows that change a grade or
+    // add a comment are sent. Each row ends up `Applied` or `Failed`.
+    pub async fn apply_grade_import(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        mut preview: GradeImportPreview,
+    ) -> Result<GradeImportPreview> {
+        let token = self.config.read().await.token.clone();
+        let mut rows: Vec<_> = preview
+            .rows
+            .iter_mut()
+            .filter(|row| row.status == GradeImportStatus::Update && row.student_id.is_some())
+            .collect();
+        let mut applied = 0;
+        for batch in rows.chunks_mut(GRADE_IMPORT_BATCH_SIZE) {
+            let grades: Vec<_> = batch
+                .iter()
+                .filter_map(|row| {
+                    Some(GradeUpdate {
+                        student_id: row.student_id?,
+                        grade: row.grade.clone(),
+                        comment: row.comment.clone(),
+                    })
+                })
+                .collect();
+            let result = match self
+                .client
+                .update_grades(course_id, assignment_id, &grades, &token)
+                .await
+            {
+                Ok(progress) => self.wait_for_progress(progress).await.map(|_| ()),
+                Err(e) => Err(e),
+            };
+            for row in batch.iter_mut() {
+                match &result {
+                    Ok(()) => row.status = GradeImportStatus::Applied,
+                    Err(e) => {
+                        row.status = GradeImportStatus::Failed;
+                        row.message = Some(e.to_string());
+                    }
+                }
+            }
+            match result {
+                Ok(()) => applied += batch.len(),
+                Err(e) => tracing::error!("Failed to import a batch of grades: {}", e),
+            }
+        }
+        tracing::info!(
+            "Imported {} grades for assignment {} of course {}",
+            applied,
+            assignment_id,
+            course_id
+        );
+        preview.applied = preview
+            .rows
+            .iter()
+            .all(|row| row.status != GradeImportStatus::Failed);
+        Ok(preview)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::SubmissionComment;
+
+    fn to_rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|value| (*value).to_owned()).collect())
+            .collect()
+    }
+
+    fn student(id: i64, login_id: &str) -> User {
+        User {
+            id,
+            name: format!("Student {id}"),
+            login_id: login_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_grade_sheet() {
+        let rows = to_rows(&[
+            &["\u{feff}学号", "Name", "Score", "Comment"],
+            &["520001", "Alice", "95", ""],
+            &["", "", "", ""],
+            &["520002", "Bob", "", "Absent"],
+        ]);
+        let sheet_rows = parse_grade_sheet(&rows).unwrap();
+
+        assert_eq!(
+            sheet_rows,
+            vec![
+                GradeSheetRow {
+                    row: 2,
+                    login_id: Some("520001".to_owned()),
+                    grade: "95".to_owned(),
+                    ..Default::default()
+                },
+                GradeSheetRow {
+                    row: 4,
+                    login_id: Some("520002".to_owned()),
+                    comment: Some("Absent".to_owned()),
+                    ..Default::default()
+                },
+            ]
+        );
+        assert!(parse_grade_sheet(&to_rows(&[&["name", "grade"]])).is_err());
+    }
+
+    #[test]
+    fn test_normalize_grade() {
+        assert_eq!(
+            normalize_grade("95.50", &GradingType::Points),
+            Ok("95.5".to_owned())
+        );
+        assert!(normalize_grade("-1", &GradingType::Points).is_err());
+        assert_eq!(
+            normalize_grade("85", &GradingType::Percent),
+            Ok("85%".to_owned())
+        );
+        assert_eq!(
+            normalize_grade("Pass", &GradingType::PassFail),
+            Ok("complete".to_owned())
+        );
+        assert!(normalize_grade("A", &GradingType::PassFail).is_err());
+        assert_eq!(
+            normalize_grade("B+", &GradingType::LetterGrade),
+            Ok("B+".to_owned())
+        );
+        assert!(normalize_grade("1", &GradingType::NotGraded).is_err());
+    }
+
+    #[test]
+    fn test_build_grade_import_rows() {
+        let students = vec![
+            student(1, "520001"),
+            student(2, "520002"),
+            student(3, "520003"),
+            student(4, "520004"),
+        ];
+        let submissions = vec![
+            Submission {
+                user_id: 1,
+                grade: Some("90".to_owned()),
+                ..Default::default()
+            },
+            Submission {
+                user_id: 2,
+                grade: Some("80".to_owned()),
+                submission_comments: vec![SubmissionComment {
+                    comment: "Good".to_owned(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+        let sheet_row = |row, login_id: &str, grade: &str, comment: Option<&str>| GradeSheetRow {
+            row,
+            login_id: Some(login_id.to_owned()),
+            grade: grade.to_owned(),
+            comment: comment.map(ToOwned::to_owned),
+            ..Default::default()
+        };
+        let sheet_rows = vec![
+            sheet_row(2, "520001", "95", None),
+            sheet_row(3, "520002", "80.0", Some("Good")),
+            sheet_row(4, "520003", "", None),
+            sheet_row(5, "529999", "60", None),
+            sheet_row(6, "520001", "70", None),
+            sheet_row(7, "520004", "abc", None),
+        ];
+        let rows =
+            build_grade_import_rows(&sheet_rows, &students, &submissions, &GradingType::Points);
+
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.status.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                GradeImportStatus::Update,
+                GradeImportStatus::Unchanged,
+                GradeImportStatus::Skipped,
+                GradeImportStatus::Invalid,
+                GradeImportStatus::Invalid,
+                GradeImportStatus::Invalid,
+            ]
+        );
+        assert_eq!(rows[0].student_id, Some(1));
+        assert_eq!(rows[0].current_grade.as_deref(), Some("90"));
+    }
+}
@@ -0,0 +1,274 @@
+use std::{collections::HashMap, path::Path};
+
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use super::App;
+use crate::{
+    error::Result,
+    model::{Assignment, Submission, User, UserSubmissions},
+};
+
+// Every assignment takes a score, a late flag and a submitted_at column
+const COLUMNS_PER_ASSIGNMENT: u16 = 3;
+const STUDENT_COLUMNS: [&str; 3] = ["id", "name", "login_id"];
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct ScoreSummary {
+    count: usize,
+    mean: Option<f64>,
+    max: Option<f64>,
+    min: Option<f64>,
+}
+
+struct GradebookRow<'a> {
+    student: &'a User,
+    submissions: Vec<Option<&'a Submission>>,
+    total: f64,
+}
+
+struct Gradebook<'a> {
+    assignments: Vec<&'a Assignment>,
+    rows: Vec<GradebookRow<'a>>,
+    summaries: Vec<ScoreSummary>,
+}
+
+fn summarize_scores(scores: &[f64]) -> ScoreSummary {
+    if scores.is_empty() {
+        return Default::default();
+    }
+    ScoreSummary {
+        count: scores.len(),
+        mean: Some(scores.iter().sum::<f64>() / scores.len() as f64),
+        max: scores.iter().copied().reduce(f64::max),
+        min: scores.iter().copied().reduce(f64::min),
+    }
+}
+
+// Unpublished assignments are left out since students can not submit them
+fn build_gradebook<'a>(
+    students: &'a [User],
+    assignments: &'a [Assignment],
+    user_submissions: &'a [UserSubmissions],
+) -> Gradebook<'a> {
+    let assignments: Vec<_> = assignments
+        .iter()
+        .filter(|assignment| assignment.published)
+        .collect();
+    let submissions: HashMap<(i64, i64), &Submission> = user_submissions
+        .iter()
+        .flat_map(|user_submissions| &user_submissions.submissions)
+        .map(|submission| ((submission.user_id, submission.assignment_id), submission))
+        .collect();
+
+    let rows: Vec<_> = students
+        .iter()
+        .map(|student| {
+            let submissions: Vec<_> = assignments
+                .iter()
+                .map(|assignment| submissions.get(&(student.id, assignment.id)).copied())
+                .collect();
+            let total = submissions
+                .iter()
+                .filter_map(|submission| submission.and_then(|submission| submission.score))
+                .sum();
+            GradebookRow {
+                student,
+                submissions,
+                total,
+            }
+        })
+        .collect();
+    let summaries = (0..assignments.len())
+        .map(|i| {
+            let scores: Vec<_> = rows
+                .iter()
+                .filter_map(|row| row.submissions[i].and_then(|submission| submission.score))
+                .collect();
+            summarize_scores(&scores)
+        })
+        .collect();
+
+    Gradebook {
+        assignments,
+        rows,
+        summaries,
+    }
+}
+
+fn write_optional_number(
+    sheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: Option<f64>,
+) -> Result<()> {
+    if let Some(value) = value {
+        sheet.write_number(row, col, value)?;
+    }
+    Ok(())
+}
+
+fn write_gradebook(gradebook: &Gradebook, sheet: &mut Worksheet) -> Result<()> {
+    let bold = Format::new().set_bold();
+    let student_columns = STUDENT_COLUMNS.len() as u16;
+    let total_col = student_columns + gradebook.assignments.len() as u16 * COLUMNS_PER_ASSIGNMENT;
+
+    // setup headers, the second row holds points possible
+    for (col, header) in STUDENT_COLUMNS.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *header, &bold)?;
+    }
+    sheet.write_string_with_format(1, 1, "points_possible", &bold)?;
+    for (i, assignment) in gradebook.assignments.iter().enumerate() {
+        let col = student_columns + i as u16 * COLUMNS_PER_ASSIGNMENT;
+        sheet.write_string_with_format(0, col, &assignment.name, &bold)?;
+        sheet.write_string_with_format(0, col + 1, format!("{} late", assignment.name), &bold)?;
+        sheet.write_string_with_format(
+            0,
+            col + 2,
+            format!("{} submitted_at", assignment.name),
+            &bold,
+        )?;
+        write_optional_number(sheet, 1, col, assignment.points_possible)?;
+    }
+    sheet.write_string_with_format(0, total_col, "total", &bold)?;
+    let points_possible: f64 = gradebook
+        .assignments
+        .iter()
+        .filter_map(|assignment| assignment.points_possible)
+        .sum();
+    sheet.write_number(1, total_col, points_possible)?;
+
+    let first_student_row = 2;
+    for (i, gradebook_row) in gradebook.rows.iter().enumerate() {
+        let row = first_student_row + i as u32;
+        let student = gradebook_row.student;
+        sheet.write_number(row, 0, student.id as f64)?;
+        sheet.write_string(row, 1, &student.name)?;
+        sheet.write_string(row, 2, &student.login_id)?;
+        for (j, submission) in gradebook_row.submissions.iter().enumerate() {
+            let Some(submission) = submission else {
+                continue;
+            };
+            let col = student_columns + j as u16 * COLUMNS_PER_ASSIGNMENT;
+            write_optional_number(sheet, row, col, submission.score)?;
+            sheet.write_boolean(row, col + 1, submission.late)?;
+            if let Some(submitted_at) = &submission.submitted_at {
+                sheet.write_string(row, col + 2, submitted_at)?;
+            }
+        }
+        sheet.write_number(row, total_col, gradebook_row.total)?;
+    }
+
+    // per-assignment statistics below the students
+    let first_summary_row = first_student_row + gradebook.rows.len() as u32 + 1;
+    let labels = ["graded_count", "mean", "max", "min"];
+    for (i, label) in labels.iter().enumerate() {
+        sheet.write_string_with_format(first_summary_row + i as u32, 1, *label, &bold)?;
+    }
+    for (i, summary) in gradebook.summaries.iter().enumerate() {
+        let col = student_columns + i as u16 * COLUMNS_PER_ASSIGNMENT;
+        sheet.write_number(first_summary_row, col, summary.count as f64)?;
+        write_optional_number(sheet, first_summary_row + 1, col, summary.mean)?;
+        write_optional_number(sheet, first_summary_row + 2, col, summary.max)?;
+        write_optional_number(sheet, first_summary_row + 3, col, summary.min)?;
+    }
+    sheet.set_freeze_panes(first_student_row, student_columns)?;
+    Ok(())
+}
+
+impl App {
+    pub async fn export_gradebook(
+        &self,
+        course_id: i64,
+        file_name: &str,
+        folder_path: &str,
+    ) -> Result<()> {
+        let students = self.list_course_students(course_id).await?;
+        let assignments = self.list_course_assignments(course_id).await?;
+        let student_ids: Vec<_> = students.iter().map(|student| student.id).collect();
+        let user_submissions = self.list_user_submissions(course_id, &student_ids).await?;
+        let gradebook = build_gradebook(&students, &assignments, &user_submissions);
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        write_gradebook(&gradebook, sheet)?;
+        workbook.save(Path::new(folder_path).join(file_name))?;
+        tracing::info!(
+            "Exported gradebook of course {} with {} students",
+            course_id,
+            students.len()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(id: i64) -> User {
+        User {
+            id,
+            name: format!("Student {id}"),
+            ..Default::default()
+        }
+    }
+
+    fn assignment(id: i64, published: bool) -> Assignment {
+        Assignment {
+            id,
+            published,
+            points_possible: Some(10.0),
+            ..Default::default()
+        }
+    }
+
+    fn submission(user_id: i64, assignment_id: i64, score: Option<f64>) -> Submission {
+        Submission {
+            user_id,
+            assignment_id,
+            score,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_gradebook() {
+        let students = vec![student(1), student(2)];
+        let assignments = vec![
+            assignment(10, true),
+            assignment(11, false),
+            assignment(12, true),
+        ];
+        let user_submissions = vec![
+            UserSubmissions {
+                user_id: 1,
+                submissions: vec![submission(1, 10, Some(8.0)), submission(1, 12, Some(6.5))],
+            },
+            UserSubmissions {
+                user_id: 2,
+                submissions: vec![submission(2, 10, Some(10.0)), submission(2, 12, None)],
+            },
+        ];
+        let gradebook = build_gradebook(&students, &assignments, &user_submissions);
+
+        assert_eq!(gradebook.assignments.len(), 2);
+        assert_eq!(
+            gradebook
+                .rows
+                .iter()
+                .map(|row| row.total)
+                .collect::<Vec<_>>(),
+            vec![14.5, 10.0]
+        );
+        assert_eq!(
+            gradebook.summaries[0],
+            ScoreSummary {
+                count: 2,
+                mean: Some(9.0),
+                max: Some(10.0),
+                min: Some(8.0),
+            }
+        );
+        assert_eq!(gradebook.summaries[1].count, 1);
+    }
+}
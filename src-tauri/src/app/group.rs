@@ -0,0 +1,83 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use super::App;
+use crate::{
+    error::Result,
+    model::{Assignment, Group, GroupCategory, GroupMembership, Submission, User},
+};
+
+impl App {
+    pub async fn list_course_groups(&self, course_id: i64) -> Result<Vec<Group>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_course_groups(course_id, &token).await
+    }
+
+    pub async fn list_group_categories(&self, course_id: i64) -> Result<Vec<GroupCategory>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_group_categories(course_id, &token).await
+    }
+
+    pub async fn list_group_category_groups(&self, group_category_id: i64) -> Result<Vec<Group>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .list_group_category_groups(group_category_id, &token)
+            .await
+    }
+
+    pub async fn list_group_memberships(&self, group_id: i64) -> Result<Vec<GroupMembership>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_group_memberships(group_id, &token).await
+    }
+
+    pub async fn list_group_users(&self, group_id: i64) -> Result<Vec<User>> {
+        let token = self.config.read().await.token.clone();
+        self.client.list_group_users(group_id, &token).await
+    }
+
+    pub async fn list_group_assignment_submissions(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+    ) -> Result<Vec<Submission>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .list_group_assignment_submissions(course_id, assignment_id, &token)
+            .await
+    }
+
+    // Canvas leaves `student_ids` empty on overrides that target a group, fill them in
+    // with the group members so that callers can treat every override the same way.
+    // Best effort, groups whose members cannot be listed keep their empty `student_ids`.
+    pub(super) async fn expand_group_overrides(&self, assignments: &mut [Assignment]) {
+        let mut group_members: HashMap<i64, Vec<i64>> = HashMap::new();
+        for assignment in assignments.iter_mut() {
+            for assignment_override in &mut assignment.overrides {
+                let group_id = assignment_override.group_id;
+                if group_id == 0 || !assignment_override.student_ids.is_empty() {
+                    continue;
+                }
+                if let Entry::Vacant(entry) = group_members.entry(group_id) {
+                    let members = match self.list_group_users(group_id).await {
+                        Ok(users) => users.iter().map(|user| user.id).collect(),
+                        Err(e) => {
+                            tracing::warn!("Failed to list members of group {}: {}", group_id, e);
+                            vec![]
+                        }
+                    };
+                    entry.insert(members);
+                }
+                assignment_override.student_ids = group_members[&group_id].clone();
+            }
+        }
+    }
+
+    // For deadline planning, which needs to know the students behind group overrides
+    pub async fn list_course_assignments_with_group_members(
+        &self,
+        course_id: i64,
+    ) -> Result<Vec<Assignment>> {
+        let mut assignments = self.list_course_assignments(course_id).await?;
+        self.expand_group_overrides(&mut assignments).await;
+        Ok(assignments)
+    }
+}
@@ -1,16 +1,37 @@
 use cache::Cache;
+use download::DownloadManager;
+use search::SearchIndex;
 use std::sync::Arc;
-use tokio::{sync::RwLock, task::JoinHandle};
+use tokio::{
+    sync::{Mutex, RwLock},
+    task::JoinHandle,
+};
 
 use crate::{
     client::Client,
     model::{Account, AppConfig},
 };
+pub mod announcement;
 pub mod annual;
+pub mod assignment;
 pub mod basic;
 pub mod cache;
+pub mod comment;
 mod constants;
+pub mod conversation;
+pub mod deadline;
+pub mod discussion;
+pub mod download;
+pub mod grade_import;
+pub mod gradebook;
+pub mod group;
 pub mod jbox;
+pub mod rubric;
+pub mod search;
+pub mod similarity;
+pub mod submission;
+pub mod sync;
+pub mod upload;
 pub mod video;
 
 pub struct App {
@@ -20,6 +41,8 @@ pub struct App {
     handle: RwLock<Option<JoinHandle<()>>>,
     mcp_handle: RwLock<Option<JoinHandle<()>>>,
     cache: Cache,
+    download_manager: Arc<DownloadManager>,
+    search_index: Mutex<Option<SearchIndex>>,
 }
 
 #[cfg(test)]
@@ -134,12 +157,8 @@ mod test {
         let video = app.get_video_info(course.response_vo_list[0].id).await?;
         tracing::info!("video = {:?}", video);
 
-        app.download_video(
-            &video.video_play_response_vo_list[0],
-            "download.mp4",
-            |_| {},
-        )
-        .await?;
+        app.download_video(&video.video_play_response_vo_list[0], "download.mp4", 0)
+            .await?;
         Ok(())
     }
 
@@ -0,0 +1,60 @@
+use super::App;
+use crate::{
+    client::rubric::score_rubric_assessment,
+    error::Result,
+    model::{RubricAssessment, RubricCriterion, Submission},
+};
+
+impl App {
+    pub async fn get_assignment_rubric(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+    ) -> Result<Vec<RubricCriterion>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .get_assignment_rubric(course_id, assignment_id, &token)
+            .await
+    }
+
+    pub async fn list_rubric_assessments(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+    ) -> Result<Vec<Submission>> {
+        let token = self.config.read().await.token.clone();
+        self.client
+            .list_rubric_assessments(course_id, assignment_id, &token)
+            .await
+    }
+
+    // Returns the score computed from the rubric. For group assignments canvas applies
+    // the assessment to the whole group unless students are graded individually.
+    pub async fn submit_rubric_assessment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        student_id: i64,
+        mut assessment: RubricAssessment,
+        comment: Option<&str>,
+    ) -> Result<f64> {
+        let token = self.config.read().await.token.clone();
+        let rubric = self
+            .client
+            .get_assignment_rubric(course_id, assignment_id, &token)
+            .await?;
+        let score = score_rubric_assessment(&rubric, &mut assessment)?;
+        self.client
+            .update_rubric_assessment(
+                course_id,
+                assignment_id,
+                student_id,
+                &assessment,
+                score,
+                comment,
+                &token,
+            )
+            .await?;
+        Ok(score)
+    }
+}
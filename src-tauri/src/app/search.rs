@@ -0,0 +1,480 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::MutexGuard;
+
+use super::{constants::SEARCH_INDEX_FILE_NAME, App};
+use crate::{error::Result, model::SearchResult, utils};
+
+const INDEXED_EXTENSIONS: [&str; 4] = ["pdf", "docx", "txt", "md"];
+const SNIPPET_RADIUS: usize = 60;
+// BM25 parameters
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexedDocument {
+    course: String,
+    modified: u64,
+    size: u64,
+    length: usize,
+    text: String,
+}
+
+// Inverted index over the text of downloaded files, keyed by paths relative to `root`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndex {
+    root: String,
+    documents: HashMap<String, IndexedDocument>,
+    // term -> relative path -> term frequency
+    postings: HashMap<String, HashMap<String, usize>>,
+}
+
+pub(super) fn is_cjk(c: char) -> bool {
+    matches!(c as u32, 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF)
+}
+
+// Latin text is split into lowercase words, CJK text (which has no word boundaries)
+// into overlapping character bigrams
+fn tokenize(text: &str) -> Vec<String> {
+    fn flush_cjk(run: &mut Vec<char>, tokens: &mut Vec<String>) {
+        if run.len() == 1 {
+            tokens.push(run[0].to_string());
+        }
+        tokens.extend(run.windows(2).map(|pair| pair.iter().collect::<String>()));
+        run.clear();
+    }
+
+    let mut tokens = vec![];
+    let mut word = String::new();
+    let mut cjk_run = vec![];
+    for c in text.chars() {
+        if is_cjk(c) {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            cjk_run.push(c);
+        } else if c.is_alphanumeric() {
+            flush_cjk(&mut cjk_run, &mut tokens);
+            word.extend(c.to_lowercase());
+        } else {
+            flush_cjk(&mut cjk_run, &mut tokens);
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+        }
+    }
+    flush_cjk(&mut cjk_run, &mut tokens);
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+fn make_snippet(text: &str, terms: &[String]) -> String {
+    let lower_text = text.to_lowercase();
+    let position = terms
+        .iter()
+        .filter_map(|term| lower_text.find(term.as_str()))
+        .min()
+        .unwrap_or_default();
+    // `to_lowercase` may change byte lengths, so work on char offsets of the original text
+    let chars: Vec<char> = text.chars().collect();
+    let center = lower_text[..position].chars().count().min(chars.len());
+    let begin = center.saturating_sub(SNIPPET_RADIUS);
+    let end = (center + SNIPPET_RADIUS).min(chars.len());
+    let snippet: String = chars[begin..end]
+        .iter()
+        .map(|c| if c.is_whitespace() { ' ' } else { *c })
+        .collect();
+    let mut snippet = snippet.trim().to_owned();
+    if begin > 0 {
+        snippet.insert_str(0, "...");
+    }
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+impl SearchIndex {
+    pub fn new(root: &str) -> Self {
+        Self {
+            root: root.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    // The modification time and size the document was indexed with
+    fn stamp(&self, path: &str) -> Option<(u64, u64)> {
+        self.documents.get(path).map(|doc| (doc.modified, doc.size))
+    }
+
+    fn is_up_to_date(&self, path: &str, modified: u64, size: u64) -> bool {
+        self.stamp(path) == Some((modified, size))
+    }
+
+    pub fn add_document(
+        &mut self,
+        path: &str,
+        course: &str,
+        modified: u64,
+        size: u64,
+        text: String,
+    ) {
+        self.remove_document(path);
+        let tokens = tokenize(&text);
+        for token in &tokens {
+            *self
+                .postings
+                .entry(token.clone())
+                .or_default()
+                .entry(path.to_owned())
+                .or_default() += 1;
+        }
+        self.documents.insert(
+            path.to_owned(),
+            IndexedDocument {
+                course: course.to_owned(),
+                modified,
+                size,
+                length: tokens.len(),
+                text,
+            },
+        );
+    }
+
+    pub fn remove_document(&mut self, path: &str) {
+        let Some(doc) = self.documents.remove(path) else {
+            return;
+        };
+        for token in tokenize(&doc.text) {
+            if let Some(posting) = self.postings.get_mut(&token) {
+                posting.remove(path);
+                if posting.is_empty() {
+                    self.postings.remove(&token);
+                }
+            }
+        }
+    }
+
+    // Rank documents containing any of the query terms with BM25
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let terms: Vec<String> = tokenize(query)
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if terms.is_empty() || self.documents.is_empty() {
+            return vec![];
+        }
+        let total_docs = self.documents.len() as f64;
+        let avg_length = self
+            .documents
+            .values()
+            .map(|doc| doc.length as f64)
+            .sum::<f64>()
+            / total_docs;
+
+        let mut scores: HashMap<&str, f64> = HashMap::new();
+        for term in &terms {
+            let Some(posting) = self.postings.get(term) else {
+                continue;
+            };
+            let df = posting.len() as f64;
+            let idf = ((total_docs - df + 0.5) / (df + 0.5) + 1.0).ln();
+            for (path, tf) in posting {
+                let length = self.documents[path].length as f64;
+                let tf = *tf as f64;
+                let score = idf * tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * length / avg_length));
+                *scores.entry(path).or_default() += score;
+            }
+        }
+
+        let mut ranked: Vec<_> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(path, score)| {
+                let doc = &self.documents[path];
+                SearchResult {
+                    path: Path::new(&self.root)
+                        .join(path)
+                        .to_string_lossy()
+                        .into_owned(),
+                    file_name: path.rsplit('/').next().unwrap_or(path).to_owned(),
+                    course: doc.course.clone(),
+                    snippet: make_snippet(&doc.text, &terms),
+                    score,
+                }
+            })
+            .collect()
+    }
+}
+
+fn collect_indexable_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        // Skips sync manifests, version history and other hidden files
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            collect_indexable_files(&path, files)?;
+        } else if is_indexable(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_indexable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| INDEXED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+// Returns the path relative to `root` with '/' separators and the course directory
+fn split_relative_path(root: &Path, path: &Path) -> Option<(String, String)> {
+    let relative = path.strip_prefix(root).ok()?;
+    let components: Vec<_> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let course = if components.len() > 1 {
+        components[0].clone()
+    } else {
+        String::new()
+    };
+    Some((components.join("/"), course))
+}
+
+fn file_stamp(path: &Path) -> Result<(u64, u64)> {
+    let metadata = fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    Ok((modified, metadata.len()))
+}
+
+impl App {
+    fn search_index_path() -> Result<PathBuf> {
+        Ok(Path::new(&App::config_dir()?).join(SEARCH_INDEX_FILE_NAME))
+    }
+
+    fn save_search_index(index: &SearchIndex) -> Result<()> {
+        fs::write(App::search_index_path()?, serde_json::to_vec(index)?)?;
+        Ok(())
+    }
+
+    // Loads the persisted index, starting over when the save path has changed
+    async fn lock_search_index(&self) -> Result<MutexGuard<'_, Option<SearchIndex>>> {
+        let save_path = self.config.read().await.save_path.clone();
+        let mut guard = self.search_index.lock().await;
+        if guard.is_none() {
+            let index = fs::read(App::search_index_path()?)
+                .ok()
+                .and_then(|content| utils::json::parse_json::<SearchIndex>(&content).ok());
+            *guard = index;
+        }
+        if guard.as_ref().is_none_or(|index| index.root != save_path) {
+            *guard = Some(SearchIndex::new(&save_path));
+        }
+        Ok(guard)
+    }
+
+    async fn extract_text(&self, path: &Path) -> String {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let text = match ext.as_str() {
+            "txt" | "md" => fs::read(path)
+                .map(|content| String::from_utf8_lossy(&content).into_owned())
+                .map_err(Into::into),
+            _ => self.client.parse_local_file(path).await,
+        };
+        // Unreadable files are indexed without text so they are not parsed again and again
+        text.unwrap_or_else(|e| {
+            tracing::warn!("Failed to extract text from {:?}: {}", path, e);
+            String::new()
+        })
+    }
+
+    async fn index_file(&self, index: &mut SearchIndex, path: &Path) -> Result<bool> {
+        let root = PathBuf::from(&index.root);
+        let Some((relative_path, course)) = split_relative_path(&root, path) else {
+            return Ok(false);
+        };
+        let (modified, size) = file_stamp(path)?;
+        if index.is_up_to_date(&relative_path, modified, size) {
+            return Ok(false);
+        }
+        let text = self.extract_text(path).await;
+        index.add_document(&relative_path, &course, modified, size, text);
+        Ok(true)
+    }
+
+    // Index new and modified files under the save path and drop deleted ones. This walks
+    // the whole save path, so it only runs at startup or when asked for, downloads keep
+    // the index current through `index_downloaded_file`. Files are parsed without holding
+    // the index lock, which is only taken to read the current stamps and to merge.
+    pub async fn refresh_search_index(&self) -> Result<usize> {
+        let (root, stamps) = {
+            let mut guard = self.lock_search_index().await?;
+            let index = guard.get_or_insert_with(Default::default);
+            let stamps: HashMap<String, (u64, u64)> = index
+                .documents
+                .iter()
+                .map(|(path, doc)| (path.clone(), (doc.modified, doc.size)))
+                .collect();
+            (index.root.clone(), stamps)
+        };
+        let root_path = PathBuf::from(&root);
+        let mut files = vec![];
+        if fs::metadata(&root_path).is_ok() {
+            collect_indexable_files(&root_path, &mut files)?;
+        }
+
+        let mut seen = HashSet::new();
+        let mut updates = vec![];
+        for path in &files {
+            let Some((relative_path, course)) = split_relative_path(&root_path, path) else {
+                continue;
+            };
+            // e.g. deleted during the walk or not readable, it is dropped like a deleted file
+            let (modified, size) = match file_stamp(path) {
+                Ok(stamp) => stamp,
+                Err(e) => {
+                    tracing::warn!("Skipped indexing {:?}: {}", path, e);
+                    continue;
+                }
+            };
+            seen.insert(relative_path.clone());
+            if stamps.get(&relative_path) == Some(&(modified, size)) {
+                continue;
+            }
+            let text = self.extract_text(path).await;
+            updates.push((relative_path, course, modified, size, text));
+        }
+
+        let mut guard = self.lock_search_index().await?;
+        let index = guard.get_or_insert_with(Default::default);
+        if index.root != root {
+            // the save path changed meanwhile, the results belong to the old one
+            return Ok(index.documents.len());
+        }
+        // Documents a download indexed during the walk are newer than what was read here
+        let mut changed = false;
+        for (relative_path, course, modified, size, text) in updates {
+            if index.stamp(&relative_path) == stamps.get(&relative_path).copied() {
+                index.add_document(&relative_path, &course, modified, size, text);
+                changed = true;
+            }
+        }
+        let removed: Vec<_> = stamps
+            .iter()
+            .filter(|(path, stamp)| !seen.contains(*path) && index.stamp(path) == Some(**stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        for path in &removed {
+            index.remove_document(path);
+        }
+        if changed || !removed.is_empty() {
+            App::save_search_index(index)?;
+        }
+        Ok(index.documents.len())
+    }
+
+    pub async fn index_downloaded_file(&self, path: &Path) -> Result<()> {
+        if !is_indexable(path) {
+            return Ok(());
+        }
+        let mut guard = self.lock_search_index().await?;
+        let index = guard.get_or_insert_with(Default::default);
+        if self.index_file(index, path).await? {
+            App::save_search_index(index)?;
+        }
+        Ok(())
+    }
+
+    pub async fn search_course_materials(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let guard = self.lock_search_index().await?;
+        Ok(guard
+            .as_ref()
+            .map(|index| index.search(query, limit))
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("Fourier 变换, FFT2"),
+            vec!["fourier", "变换", "fft2"]
+        );
+        assert_eq!(tokenize("傅里叶变换"), vec!["傅里", "里叶", "叶变", "变换"]);
+        assert_eq!(tokenize("第3章"), vec!["第", "3", "章"]);
+    }
+
+    #[test]
+    fn test_search_ranking() {
+        let mut index = SearchIndex::new("/canvas");
+        index.add_document(
+            "Signals/lecture1.pdf",
+            "Signals",
+            0,
+            0,
+            "Introduction to signals and systems".to_owned(),
+        );
+        index.add_document(
+            "Signals/lecture5.pdf",
+            "Signals",
+            0,
+            0,
+            "The Fourier transform. Fourier series converge, and the Fourier transform of a \
+             rectangle is a sinc."
+                .to_owned(),
+        );
+        index.add_document(
+            "Calculus/notes.docx",
+            "Calculus",
+            0,
+            0,
+            "傅里叶变换与Fourier级数".to_owned(),
+        );
+
+        let results = index.search("fourier transform", 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].file_name, "lecture5.pdf");
+        assert_eq!(results[0].course, "Signals");
+        assert!(results[0].snippet.contains("Fourier"));
+        assert_eq!(results[1].course, "Calculus");
+
+        let results = index.search("傅里叶", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].file_name, "notes.docx");
+
+        index.remove_document("Signals/lecture5.pdf");
+        assert_eq!(index.search("transform", 10).len(), 0);
+        assert!(!index.postings.contains_key("transform"));
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
+    ops::Range,
+    path::Path,
+};
+
+use rust_xlsxwriter::{Format, Workbook};
+
+use super::{search::is_cjk, App};
+use crate::{
+    client::constants::PLAIN_TEXT_EXTENSIONS,
+    error::Result,
+    model::{SimilarityPair, SimilarityReport, Submission},
+};
+
+const PARSED_EXTENSIONS: [&str; 2] = ["pdf", "docx"];
+const DEFAULT_MIN_SIMILARITY: f64 = 0.3;
+// Number of consecutive tokens in a shingle
+const SHINGLE_SIZE: usize = 8;
+const NUM_HASHES: usize = 128;
+// MinHash only estimates the similarity, so pairs slightly below the threshold are still
+// compared exactly
+const ESTIMATE_MARGIN: f64 = 0.1;
+const MAX_PASSAGES: usize = 5;
+const MAX_PASSAGE_CHARS: usize = 300;
+const REPORT_HEADERS: [&str; 8] = [
+    "user_id",
+    "name",
+    "login_id",
+    "other_user_id",
+    "other_name",
+    "other_login_id",
+    "similarity",
+    "matched_passages",
+];
+
+// Words are lowercased, every CJK character is a token on its own.
+// Tokens keep their byte range in `text` so that matched passages can be cut out of it.
+fn tokenize(text: &str) -> Vec<(String, Range<usize>)> {
+    let mut tokens = vec![];
+    let mut word: Option<(String, usize)> = None;
+    for (i, c) in text.char_indices() {
+        if c.is_alphanumeric() && !is_cjk(c) {
+            word.get_or_insert_with(|| (String::new(), i))
+                .0
+                .extend(c.to_lowercase());
+            continue;
+        }
+        if let Some((word, start)) = word.take() {
+            tokens.push((word, start..i));
+        }
+        if is_cjk(c) {
+            tokens.push((c.to_string(), i..i + c.len_utf8()));
+        }
+    }
+    if let Some((word, start)) = word {
+        tokens.push((word, start..text.len()));
+    }
+    tokens
+}
+
+fn hash_shingle(tokens: &[String]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    tokens.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Fixed (splitmix64) coefficients keep signatures comparable across runs
+fn minhash_coefficients() -> Vec<(u64, u64)> {
+    let mut state = 0u64;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    // an odd multiplier makes `a * x + b` a permutation of u64
+    (0..NUM_HASHES).map(|_| (next() | 1, next())).collect()
+}
+
+struct Document<'a> {
+    text: &'a str,
+    spans: Vec<Range<usize>>,
+    // hash of the shingle starting at every token
+    shingles: Vec<u64>,
+    shingle_set: HashSet<u64>,
+    signature: Vec<u64>,
+}
+
+impl<'a> Document<'a> {
+    fn new(text: &'a str, coefficients: &[(u64, u64)]) -> Self {
+        let (tokens, spans): (Vec<_>, Vec<_>) = tokenize(text).into_iter().unzip();
+        let shingles: Vec<_> = tokens.windows(SHINGLE_SIZE).map(hash_shingle).collect();
+        let shingle_set: HashSet<_> = shingles.iter().copied().collect();
+        let signature = coefficients
+            .iter()
+            .map(|(a, b)| {
+                shingle_set
+                    .iter()
+                    .map(|shingle| a.wrapping_mul(*shingle).wrapping_add(*b))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect();
+        Self {
+            text,
+            spans,
+            shingles,
+            shingle_set,
+            signature,
+        }
+    }
+
+    fn estimate_similarity(&self, other: &Document) -> f64 {
+        let matches = self
+            .signature
+            .iter()
+            .zip(&other.signature)
+            .filter(|(a, b)| a == b)
+            .count();
+        matches as f64 / NUM_HASHES as f64
+    }
+
+    // The longest runs of shared shingles in reading order, overlapping shingles are merged
+    fn matched_passages(&self, shared: &HashSet<u64>) -> Vec<String> {
+        let mut runs: Vec<Range<usize>> = vec![];
+        for (i, shingle) in self.shingles.iter().enumerate() {
+            if !shared.contains(shingle) {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(run) if i <= run.end => run.end = i + SHINGLE_SIZE,
+                _ => runs.push(i..i + SHINGLE_SIZE),
+            }
+        }
+        runs.sort_by_key(|run| Reverse(run.len()));
+        runs.truncate(MAX_PASSAGES);
+        runs.sort_by_key(|run| run.start);
+        runs.into_iter()
+            .map(|run| {
+                let passage = &self.text[self.spans[run.start].start..self.spans[run.end - 1].end];
+                truncate_passage(passage)
+            })
+            .collect()
+    }
+}
+
+fn truncate_passage(passage: &str) -> String {
+    let passage = passage.split_whitespace().collect::<Vec<_>>().join(" ");
+    if passage.chars().count() <= MAX_PASSAGE_CHARS {
+        return passage;
+    }
+    let mut truncated: String = passage.chars().take(MAX_PASSAGE_CHARS).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SimilarPair {
+    left: usize,
+    right: usize,
+    similarity: f64,
+    matched_passages: Vec<String>,
+}
+
+// Returns pairs of `texts` indices whose jaccard similarity reaches `min_similarity`,
+// most similar first. MinHash signatures are used to skip clearly dissimilar pairs.
+fn find_similar_pairs(texts: &[String], min_similarity: f64) -> Vec<SimilarPair> {
+    let coefficients = minhash_coefficients();
+    let documents: Vec<_> = texts
+        .iter()
+        .map(|text| Document::new(text, &coefficients))
+        .collect();
+
+    let mut pairs = vec![];
+    for (left, document) in documents.iter().enumerate() {
+        for (right, other) in documents.iter().enumerate().skip(left + 1) {
+            if document.shingle_set.is_empty() || other.shingle_set.is_empty() {
+                continue;
+            }
+            if document.estimate_similarity(other) < min_similarity - ESTIMATE_MARGIN {
+                continue;
+            }
+            let shared: HashSet<_> = document
+                .shingle_set
+                .intersection(&other.shingle_set)
+                .copied()
+                .collect();
+            let union = document.shingle_set.len() + other.shingle_set.len() - shared.len();
+            let similarity = shared.len() as f64 / union as f64;
+            if similarity < min_similarity {
+                continue;
+            }
+            pairs.push(SimilarPair {
+                left,
+                right,
+                similarity,
+                matched_passages: document.matched_passages(&shared),
+            });
+        }
+    }
+    pairs.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    pairs
+}
+
+fn get_extension(file_name: &str) -> String {
+    file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default()
+}
+
+impl App {
+    // Concatenated text of all readable attachments, unsupported or broken files are skipped
+    async fn extract_submission_text(&self, submission: &Submission, token: &str) -> String {
+        let mut texts = vec![];
+        for attachment in &submission.attachments {
+            let ext = get_extension(&attachment.display_name);
+            if !PLAIN_TEXT_EXTENSIONS.contains(&ext.as_str())
+                && !PARSED_EXTENSIONS.contains(&ext.as_str())
+            {
+                continue;
+            }
+            match self
+                .client
+                .parse_remote_file(&attachment.url, &ext, token)
+                .await
+            {
+                Ok(text) => texts.push(text),
+                Err(e) => tracing::warn!(
+                    "Failed to parse attachment {} of user {}: {:?}",
+                    attachment.display_name,
+                    submission.user_id,
+                    e
+                ),
+            }
+        }
+        texts.join("\n")
+    }
+
+    pub async fn detect_submission_similarity(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        min_similarity: Option<f64>,
+    ) -> Result<SimilarityReport> {
+        let min_similarity = min_similarity.unwrap_or(DEFAULT_MIN_SIMILARITY);
+        let token = self.config.read().await.token.clone();
+        let submissions = self
+            .list_course_assignment_submissions(course_id, assignment_id)
+            .await?;
+        let students: HashMap<_, _> = self
+            .list_course_students(course_id)
+            .await?
+            .into_iter()
+            .map(|student| (student.id, student))
+            .collect();
+
+        let mut user_ids = vec![];
+        let mut texts = vec![];
+        let mut skipped_user_ids = vec![];
+        // members of a group share the same attachments, which should not be reported
+        let mut seen_attachments = HashSet::new();
+        for submission in &submissions {
+            if submission.attachments.is_empty()
+                || submission
+                    .attachments
+                    .iter()
+                    .all(|attachment| seen_attachments.contains(&attachment.id))
+            {
+                continue;
+            }
+            seen_attachments.extend(
+                submission
+                    .attachments
+                    .iter()
+                    .map(|attachment| attachment.id),
+            );
+            let text = self.extract_submission_text(submission, &token).await;
+            if text.trim().is_empty() {
+                skipped_user_ids.push(submission.user_id);
+            } else {
+                user_ids.push(submission.user_id);
+                texts.push(text);
+            }
+        }
+
+        let student_info = |user_id: i64| {
+            students
+                .get(&user_id)
+                .map(|student| (student.name.clone(), student.login_id.clone()))
+                .unwrap_or_default()
+        };
+        let pairs = find_similar_pairs(&texts, min_similarity)
+            .into_iter()
+            .map(|pair| {
+                let user_id = user_ids[pair.left];
+                let other_user_id = user_ids[pair.right];
+                let (user_name, login_id) = student_info(user_id);
+                let (other_user_name, other_login_id) = student_info(other_user_id);
+                SimilarityPair {
+                    user_id,
+                    user_name,
+                    login_id,
+                    other_user_id,
+                    other_user_name,
+                    other_login_id,
+                    similarity: pair.similarity,
+                    matched_passages: pair.matched_passages,
+                }
+            })
+            .collect();
+        tracing::info!(
+            "Compared {} submissions of assignment {}",
+            texts.len(),
+            assignment_id
+        );
+        Ok(SimilarityReport {
+            assignment_id,
+            compared_count: texts.len(),
+            skipped_user_ids,
+            pairs,
+        })
+    }
+
+    pub fn export_similarity_report(
+        &self,
+        report: &SimilarityReport,
+        file_name: &str,
+        folder_path: &str,
+    ) -> Result<()> {
+        let bold = Format::new().set_bold();
+        let percent = Format::new().set_num_format("0.0%");
+        let wrap = Format::new().set_text_wrap();
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        for (col, header) in REPORT_HEADERS.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, *header, &bold)?;
+        }
+        for (i, pair) in report.pairs.iter().enumerate() {
+            let row = i as u32 + 1;
+            sheet.write_number(row, 0, pair.user_id as f64)?;
+            sheet.write_string(row, 1, &pair.user_name)?;
+            sheet.write_string(row, 2, &pair.login_id)?;
+            sheet.write_number(row, 3, pair.other_user_id as f64)?;
+            sheet.write_string(row, 4, &pair.other_user_name)?;
+            sheet.write_string(row, 5, &pair.other_login_id)?;
+            sheet.write_number_with_format(row, 6, pair.similarity, &percent)?;
+            sheet.write_string_with_format(row, 7, pair.matched_passages.join("\n\n"), &wrap)?;
+        }
+        sheet.set_column_width(7, 80)?;
+        sheet.set_freeze_panes(1, 0)?;
+        workbook.save(Path::new(folder_path).join(file_name))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let text = "Hello, 世界 foo_bar";
+        let tokens = tokenize(text);
+        let words: Vec<_> = tokens.iter().map(|(word, _)| word.as_str()).collect();
+        assert_eq!(words, vec!["hello", "世", "界", "foo", "bar"]);
+        assert_eq!(&text[tokens[2].1.clone()], "界");
+        assert_eq!(&text[tokens[4].1.clone()], "bar");
+    }
+
+    #[test]
+    fn test_find_similar_pairs() {
+        let shared = "the quick brown fox jumps over the lazy dog near the quiet river bank";
+        let texts = vec![
+            format!("{shared} and then it slept"),
+            format!("Introduction. {shared} before sunrise"),
+            "a completely different essay about distributed systems and consensus protocols \
+             written by another student"
+                .to_owned(),
+            "too short".to_owned(),
+        ];
+
+        let pairs = find_similar_pairs(&texts, 0.3);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].left, pairs[0].right), (0, 1));
+        assert!(pairs[0].similarity > 0.3 && pairs[0].similarity < 1.0);
+        assert_eq!(pairs[0].matched_passages, vec![shared.to_owned()]);
+
+        let identical = vec![texts[2].clone(), texts[2].clone()];
+        assert_eq!(find_similar_pairs(&identical, 0.3)[0].similarity, 1.0);
+    }
+
+    #[test]
+    fn test_truncate_passage() {
+        assert_eq!(truncate_passage("a\n  b"), "a b");
+        let long = "字".repeat(MAX_PASSAGE_CHARS + 10);
+        assert_eq!(
+            truncate_passage(&long).chars().count(),
+            MAX_PASSAGE_CHARS + 1
+        );
+    }
+}
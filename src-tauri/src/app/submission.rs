@@ -0,0 +1,354 @@
+use std::{fs, path::Path};
+
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+
+use super::{constants::LARGE_SUBMISSION_FILE_SIZE, App};
+use crate::{
+    error::{AppError, Result},
+    model::{
+        Assignment, NewSubmission, Submission, SubmissionIssue, SubmissionIssueKind,
+        SubmissionValidation,
+    },
+};
+
+fn parse_date(date: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date?)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+// With `include[]=all_dates` canvas only lists the dates that apply to the current student,
+// so an override takes precedence over the base dates, which fall back to the assignment fields.
+fn effective_dates(assignment: &Assignment) -> [Option<DateTime<Utc>>; 3] {
+    let date = assignment
+        .all_dates
+        .iter()
+        .find(|date| !date.base)
+        .or_else(|| assignment.all_dates.iter().find(|date| date.base));
+    match date {
+        Some(date) => [
+            parse_date(date.unlock_at.as_deref()),
+            parse_date(date.due_at.as_deref()),
+            parse_date(date.lock_at.as_deref()),
+        ],
+        None => [
+            parse_date(assignment.unlock_at.as_deref()),
+            parse_date(assignment.due_at.as_deref()),
+            parse_date(assignment.lock_at.as_deref()),
+        ],
+    }
+}
+
+fn issue(kind: SubmissionIssueKind, message: String) -> SubmissionIssue {
+    SubmissionIssue {
+        kind,
+        message,
+        file_path: None,
+    }
+}
+
+fn file_issue(kind: SubmissionIssueKind, message: String, file_path: &str) -> SubmissionIssue {
+    SubmissionIssue {
+        kind,
+        message,
+        file_path: Some(file_path.to_owned()),
+    }
+}
+
+fn validate_files(
+    assignment: &Assignment,
+    file_paths: &[String],
+    result: &mut SubmissionValidation,
+) {
+    if file_paths.is_empty() {
+        result.errors.push(issue(
+            SubmissionIssueKind::MissingFile,
+            "no file selected".to_owned(),
+        ));
+    }
+    for file_path in file_paths {
+        let extension = Path::new(file_path)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if !assignment.allowed_extensions.is_empty()
+            && !assignment
+                .allowed_extensions
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(&extension))
+        {
+            result.errors.push(file_issue(
+                SubmissionIssueKind::Extension,
+                format!(
+                    "only {} files are accepted",
+                    assignment.allowed_extensions.join(", ")
+                ),
+                file_path,
+            ));
+        }
+        match fs::metadata(file_path) {
+            Ok(metadata) if metadata.is_file() => {
+                if metadata.len() == 0 {
+                    result.errors.push(file_issue(
+                        SubmissionIssueKind::EmptyFile,
+                        "file is empty".to_owned(),
+                        file_path,
+                    ));
+                } else if metadata.len() > LARGE_SUBMISSION_FILE_SIZE {
+                    result.warnings.push(file_issue(
+                        SubmissionIssueKind::LargeFile,
+                        format!("file is {} MB", metadata.len() / 1024 / 1024),
+                        file_path,
+                    ));
+                }
+            }
+            _ => result.errors.push(file_issue(
+                SubmissionIssueKind::MissingFile,
+                "file does not exist".to_owned(),
+                file_path,
+            )),
+        }
+    }
+}
+
+// Checks the submission against what the assignment accepts before anything is uploaded
+fn validate_submission(
+    assignment: &Assignment,
+    submission: &NewSubmission,
+    now: DateTime<Utc>,
+) -> SubmissionValidation {
+    let mut result = SubmissionValidation::default();
+    let submission_type = submission.submission_type();
+    if !assignment
+        .submission_types
+        .iter()
+        .any(|allowed| allowed == submission_type)
+    {
+        result.errors.push(issue(
+            SubmissionIssueKind::SubmissionType,
+            format!(
+                "{} is not accepted, allowed types: {}",
+                submission_type,
+                assignment.submission_types.join(", ")
+            ),
+        ));
+    }
+
+    let [unlock_at, due_at, lock_at] = effective_dates(assignment);
+    if let Some(unlock_at) = unlock_at.filter(|unlock_at| now < *unlock_at) {
+        result.errors.push(issue(
+            SubmissionIssueKind::NotUnlocked,
+            format!("assignment opens at {}", unlock_at.to_rfc3339()),
+        ));
+    }
+    if let Some(lock_at) = lock_at.filter(|lock_at| now > *lock_at) {
+        result.errors.push(issue(
+            SubmissionIssueKind::Locked,
+            format!("assignment was locked at {}", lock_at.to_rfc3339()),
+        ));
+    } else if let Some(due_at) = due_at.filter(|due_at| now > *due_at) {
+        result.warnings.push(issue(
+            SubmissionIssueKind::Late,
+            format!(
+                "assignment was due at {}, the submission will be late",
+                due_at.to_rfc3339()
+            ),
+        ));
+    }
+
+    match submission {
+        NewSubmission::Upload { file_paths } => validate_files(assignment, file_paths, &mut result),
+        NewSubmission::TextEntry { body } => {
+            if body.trim().is_empty() {
+                result.errors.push(issue(
+                    SubmissionIssueKind::EmptyContent,
+                    "text entry is empty".to_owned(),
+                ));
+            }
+        }
+        NewSubmission::Url { url } => {
+            let valid =
+                Url::parse(url).is_ok_and(|url| url.scheme() == "http" || url.scheme() == "https");
+            if !valid {
+                result.errors.push(issue(
+                    SubmissionIssueKind::InvalidUrl,
+                    format!("{url} is not a valid url"),
+                ));
+            }
+        }
+    }
+    result
+}
+
+impl App {
+    pub async fn validate_submission(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        submission: &NewSubmission,
+    ) -> Result<SubmissionValidation> {
+        let assignment = self
+            .client
+            .get_assignment(course_id, assignment_id, &self.config.read().await.token)
+            .await?;
+        Ok(validate_submission(&assignment, submission, Utc::now()))
+    }
+
+    // Refuses to submit when validation finds errors, so that no attempt is used up.
+    // Returns the submission as recorded by canvas.
+    pub async fn submit_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        submission: &NewSubmission,
+        comment: Option<&str>,
+    ) -> Result<Submission> {
+        let validation = self
+            .validate_submission(course_id, assignment_id, submission)
+            .await?;
+        if !validation.errors.is_empty() {
+            let messages = validation
+                .errors
+                .iter()
+                .map(|error| match &error.file_path {
+                    Some(file_path) => format!("{file_path}: {}", error.message),
+                    None => error.message.clone(),
+                })
+                .collect::<Vec<_>>();
+            return Err(AppError::InvalidSubmission(messages.join("; ")));
+        }
+        let token = self.config.read().await.token.clone();
+        let result = self
+            .client
+            .submit_assignment(course_id, assignment_id, submission, comment, &token)
+            .await?;
+        tracing::info!(
+            "Submitted {} to assignment {}, attempt {:?}",
+            submission.submission_type(),
+            assignment_id,
+            result.attempt
+        );
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::AssignmentDate;
+    use uuid::Uuid;
+
+    fn assignment(submission_types: &[&str], allowed_extensions: &[&str]) -> Assignment {
+        Assignment {
+            name: "Homework 1".to_owned(),
+            submission_types: submission_types.iter().map(|t| t.to_string()).collect(),
+            allowed_extensions: allowed_extensions.iter().map(|e| e.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn kinds(issues: &[SubmissionIssue]) -> Vec<SubmissionIssueKind> {
+        issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    fn now() -> DateTime<Utc> {
+        parse_date(Some("2024-03-10T00:00:00Z")).unwrap()
+    }
+
+    #[test]
+    fn test_validate_submission_content() {
+        let text_or_url = assignment(&["online_text_entry", "online_url"], &[]);
+        let url = |url: &str| NewSubmission::Url {
+            url: url.to_owned(),
+        };
+
+        let result = validate_submission(&text_or_url, &url("https://example.com/report"), now());
+        assert_eq!(result, SubmissionValidation::default());
+        let result = validate_submission(&text_or_url, &url("example.com"), now());
+        assert_eq!(kinds(&result.errors), vec![SubmissionIssueKind::InvalidUrl]);
+        let body = NewSubmission::TextEntry {
+            body: "  ".to_owned(),
+        };
+        let result = validate_submission(&text_or_url, &body, now());
+        assert_eq!(
+            kinds(&result.errors),
+            vec![SubmissionIssueKind::EmptyContent]
+        );
+        let upload = NewSubmission::Upload { file_paths: vec![] };
+        let result = validate_submission(&text_or_url, &upload, now());
+        assert_eq!(
+            kinds(&result.errors),
+            vec![
+                SubmissionIssueKind::SubmissionType,
+                SubmissionIssueKind::MissingFile
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_submission_files() {
+        let dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        fs::create_dir_all(&dir).unwrap();
+        let report = dir.join("report.PDF");
+        let empty = dir.join("empty.pdf");
+        let archive = dir.join("code.zip");
+        fs::write(&report, b"%PDF").unwrap();
+        fs::write(&empty, b"").unwrap();
+        fs::write(&archive, b"PK").unwrap();
+        let path = |path: &Path| path.to_string_lossy().into_owned();
+
+        let pdf_only = assignment(&["online_upload"], &["pdf"]);
+        let upload = NewSubmission::Upload {
+            file_paths: vec![path(&report), path(&empty), path(&archive)],
+        };
+        let result = validate_submission(&pdf_only, &upload, now());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            kinds(&result.errors),
+            vec![
+                SubmissionIssueKind::EmptyFile,
+                SubmissionIssueKind::Extension
+            ]
+        );
+        assert_eq!(result.errors[1].file_path, Some(path(&archive)));
+    }
+
+    #[test]
+    fn test_validate_submission_dates() {
+        let body = NewSubmission::TextEntry {
+            body: "answer".to_owned(),
+        };
+        let mut late = assignment(&["online_text_entry"], &[]);
+        late.due_at = Some("2024-03-01T00:00:00Z".to_owned());
+        late.lock_at = Some("2024-03-20T00:00:00Z".to_owned());
+        let result = validate_submission(&late, &body, now());
+        assert!(result.errors.is_empty());
+        assert_eq!(kinds(&result.warnings), vec![SubmissionIssueKind::Late]);
+
+        // the student's override extends the lock date past the base one
+        let mut extended = assignment(&["online_text_entry"], &[]);
+        extended.all_dates = vec![
+            AssignmentDate {
+                base: true,
+                due_at: Some("2024-03-01T00:00:00Z".to_owned()),
+                lock_at: Some("2024-03-05T00:00:00Z".to_owned()),
+                ..Default::default()
+            },
+            AssignmentDate {
+                id: 1,
+                due_at: Some("2024-03-15T00:00:00Z".to_owned()),
+                lock_at: Some("2024-03-15T00:00:00Z".to_owned()),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            validate_submission(&extended, &body, now()),
+            SubmissionValidation::default()
+        );
+        extended.all_dates.pop();
+        let result = validate_submission(&extended, &body, now());
+        assert_eq!(kinds(&result.errors), vec![SubmissionIssueKind::Locked]);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    collections::hash_map::Entry,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use similar::TextDiff;
+
+use super::{
+    constants::{HISTORY_DIR_NAME, SYNC_MANIFEST_FILE_NAME},
+    App,
+};
+use crate::{
+    client::sync::{diff_course_files, get_local_path},
+    error::{AppError, Result},
+    model::{Course, CourseSyncDiff, File, FileVersion, SyncManifest, SyncManifestEntry},
+    utils,
+};
+
+lazy_static! {
+    // Serializes read-modify-write cycles on manifests from concurrent downloads
+    static ref SYNC_MANIFEST_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn read_sync_manifest(course_dir: &Path, course_id: i64) -> Result<SyncManifest> {
+    let path = course_dir.join(SYNC_MANIFEST_FILE_NAME);
+    if fs::metadata(&path).is_err() {
+        return Ok(SyncManifest {
+            course_id,
+            ..Default::default()
+        });
+    }
+    let content = fs::read(&path)?;
+    utils::json::parse_json(&content)
+}
+
+fn write_sync_manifest(course_dir: &Path, manifest: &SyncManifest) -> Result<()> {
+    fs::create_dir_all(course_dir)?;
+    let path = course_dir.join(SYNC_MANIFEST_FILE_NAME);
+    fs::write(path, serde_json::to_vec(manifest)?)?;
+    Ok(())
+}
+
+// Record a successfully downloaded course file, dropping the stale local copy if the
+// file was renamed or moved on Canvas since the last sync.
+pub(super) fn record_synced_file(
+    course_dir: &Path,
+    course_id: i64,
+    file: &File,
+    folder_path: &str,
+) -> Result<()> {
+    let _guard = SYNC_MANIFEST_LOCK
+        .lock()
+        .map_err(|_| AppError::MutexError)?;
+    let mut manifest = read_sync_manifest(course_dir, course_id)?;
+    let local_path = get_local_path(folder_path, &file.display_name);
+    if let Some(previous) = manifest.entries.get(&file.id) {
+        if previous.local_path != local_path {
+            _ = fs::remove_file(course_dir.join(&previous.local_path));
+        }
+    }
+    let mut entry = SyncManifestEntry::new(file, local_path);
+    if let Some(previous) = manifest.entries.remove(&file.id) {
+        entry.versions = previous.versions;
+    }
+    manifest.entries.insert(file.id, entry);
+    write_sync_manifest(course_dir, &manifest)
+}
+
+// Copy the local copy of a course file into the history store before a changed version
+// from Canvas overwrites it.
+pub(super) fn archive_previous_version(
+    course_dir: &Path,
+    course_id: i64,
+    file: &File,
+    folder_path: &str,
+) -> Result<()> {
+    let _guard = SYNC_MANIFEST_LOCK
+        .lock()
+        .map_err(|_| AppError::MutexError)?;
+    let mut manifest = read_sync_manifest(course_dir, course_id)?;
+    let local_path = get_local_path(folder_path, &file.display_name);
+    if let Entry::Vacant(vacant) = manifest.entries.entry(file.id) {
+        // Downloaded before the manifest existed, only the size on disk is known
+        let Ok(metadata) = fs::metadata(course_dir.join(&local_path)) else {
+            return Ok(());
+        };
+        if metadata.len() == file.size {
+            return Ok(());
+        }
+        let entry = SyncManifestEntry {
+            size: metadata.len(),
+            updated_at: None,
+            ..SyncManifestEntry::new(file, local_path)
+        };
+        vacant.insert(entry);
+    }
+    let Some(entry) = manifest.entries.get_mut(&file.id) else {
+        return Ok(());
+    };
+    let current_path = course_dir.join(&entry.local_path);
+    if !entry.is_outdated_by(file) || fs::metadata(&current_path).is_err() {
+        return Ok(());
+    }
+    // Retried downloads must not archive the same version twice
+    if let Some(last) = entry.versions.last() {
+        if last.size == entry.size && last.updated_at == entry.updated_at {
+            return Ok(());
+        }
+    }
+
+    let version = entry.versions.last().map(|v| v.version + 1).unwrap_or(1);
+    let history_path = Path::new(HISTORY_DIR_NAME)
+        .join(file.id.to_string())
+        .join(format!("v{}_{}", version, entry.display_name));
+    fs::create_dir_all(course_dir.join(history_path.parent().unwrap()))?;
+    fs::copy(&current_path, course_dir.join(&history_path))?;
+    tracing::info!("Archived {:?} as version {}", current_path, version);
+    entry.versions.push(FileVersion {
+        version,
+        display_name: entry.display_name.clone(),
+        updated_at: entry.updated_at.clone(),
+        size: entry.size,
+        local_path: history_path.to_string_lossy().into_owned(),
+        archived_at: chrono::Local::now().to_rfc3339(),
+    });
+    write_sync_manifest(course_dir, &manifest)
+}
+
+fn find_file_version(
+    course_dir: &Path,
+    course_id: i64,
+    file_id: i64,
+    version: u32,
+) -> Result<(SyncManifestEntry, FileVersion)> {
+    let manifest = read_sync_manifest(course_dir, course_id)?;
+    let entry = manifest
+        .entries
+        .get(&file_id)
+        .ok_or(AppError::FileVersionNotFound(file_id, version))?;
+    let file_version = entry
+        .versions
+        .iter()
+        .find(|v| v.version == version)
+        .ok_or(AppError::FileVersionNotFound(file_id, version))?;
+    Ok((entry.clone(), file_version.clone()))
+}
+
+impl App {
+    async fn get_course_dir(&self, course: &Course) -> PathBuf {
+        let save_path = self.config.read().await.save_path.clone();
+        Path::new(&save_path).join(self.client.get_course_identifier(course))
+    }
+
+    pub async fn sync_course_files(&self, course: &Course) -> Result<CourseSyncDiff> {
+        let token = self.config.read().await.token.clone();
+        let course_dir = self.get_course_dir(course).await;
+        let folders_and_files = self.client.get_folders_and_files(course.id, &token).await?;
+        tracing::info!("folders_map: {:?}", folders_and_files.folders_map);
+
+        let _guard = SYNC_MANIFEST_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        let mut manifest = read_sync_manifest(&course_dir, course.id)?;
+        let diff = diff_course_files(
+            folders_and_files.files,
+            &folders_and_files.folders_map,
+            &mut manifest,
+            &course_dir,
+        );
+        write_sync_manifest(&course_dir, &manifest)?;
+        Ok(diff)
+    }
+
+    // Delete local copies of files that were removed on Canvas
+    pub async fn remove_synced_course_files(
+        &self,
+        course: &Course,
+        file_ids: &[i64],
+    ) -> Result<()> {
+        let course_dir = self.get_course_dir(course).await;
+        let _guard = SYNC_MANIFEST_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        let mut manifest = read_sync_manifest(&course_dir, course.id)?;
+        for file_id in file_ids {
+            if let Some(entry) = manifest.entries.remove(file_id) {
+                let path = course_dir.join(&entry.local_path);
+                if fs::metadata(&path).is_ok() {
+                    fs::remove_file(path)?;
+                }
+                _ = fs::remove_dir_all(course_dir.join(HISTORY_DIR_NAME).join(file_id.to_string()));
+            }
+        }
+        write_sync_manifest(&course_dir, &manifest)
+    }
+
+    pub async fn list_file_versions(
+        &self,
+        course: &Course,
+        file_id: i64,
+    ) -> Result<Vec<FileVersion>> {
+        let course_dir = self.get_course_dir(course).await;
+        let manifest = read_sync_manifest(&course_dir, course.id)?;
+        Ok(manifest
+            .entries
+            .get(&file_id)
+            .map(|entry| entry.versions.clone())
+            .unwrap_or_default())
+    }
+
+    // Overwrite the local copy of a course file with an archived version
+    pub async fn restore_file_version(
+        &self,
+        course: &Course,
+        file_id: i64,
+        version: u32,
+    ) -> Result<()> {
+        let course_dir = self.get_course_dir(course).await;
+        let _guard = SYNC_MANIFEST_LOCK
+            .lock()
+            .map_err(|_| AppError::MutexError)?;
+        let (entry, file_version) = find_file_version(&course_dir, course.id, file_id, version)?;
+        fs::copy(
+            course_dir.join(&file_version.local_path),
+            course_dir.join(&entry.local_path),
+        )?;
+        Ok(())
+    }
+
+    // Unified text diff between an archived version and the current local copy
+    pub async fn diff_file_version(
+        &self,
+        course: &Course,
+        file_id: i64,
+        version: u32,
+    ) -> Result<String> {
+        let course_dir = self.get_course_dir(course).await;
+        let (entry, file_version) = {
+            let _guard = SYNC_MANIFEST_LOCK
+                .lock()
+                .map_err(|_| AppError::MutexError)?;
+            find_file_version(&course_dir, course.id, file_id, version)?
+        };
+        let old_text = self
+            .client
+            .parse_local_file(&course_dir.join(&file_version.local_path))
+            .await?;
+        let new_text = self
+            .client
+            .parse_local_file(&course_dir.join(&entry.local_path))
+            .await?;
+        let diff = TextDiff::from_lines(&old_text, &new_text)
+            .unified_diff()
+            .header(&file_version.local_path, &entry.local_path)
+            .to_string();
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn file(size: u64, updated_at: &str) -> File {
+        File {
+            id: 1,
+            display_name: "slides.pdf".to_owned(),
+            size,
+            updated_at: Some(updated_at.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_archive_previous_version() -> Result<()> {
+        let course_dir = std::env::temp_dir().join(format!("history_test_{}", Uuid::new_v4()));
+        fs::create_dir_all(&course_dir)?;
+        let v1 = file(2, "2024-03-01T00:00:00Z");
+        let v2 = file(3, "2024-03-02T00:00:00Z");
+        fs::write(course_dir.join("slides.pdf"), b"v1")?;
+        record_synced_file(&course_dir, 1, &v1, "")?;
+
+        // unchanged files are not archived
+        archive_previous_version(&course_dir, 1, &v1, "")?;
+        // retried downloads archive the previous version only once
+        archive_previous_version(&course_dir, 1, &v2, "")?;
+        archive_previous_version(&course_dir, 1, &v2, "")?;
+        fs::write(course_dir.join("slides.pdf"), b"v22")?;
+        record_synced_file(&course_dir, 1, &v2, "")?;
+
+        let manifest = read_sync_manifest(&course_dir, 1)?;
+        let entry = &manifest.entries[&1];
+        let archived = fs::read(course_dir.join(&entry.versions[0].local_path));
+        _ = fs::remove_dir_all(&course_dir);
+
+        assert_eq!(entry.size, 3);
+        assert_eq!(entry.versions.len(), 1);
+        assert_eq!(entry.versions[0].version, 1);
+        assert_eq!(entry.versions[0].size, 2);
+        assert_eq!(archived?, b"v1");
+        Ok(())
+    }
+}
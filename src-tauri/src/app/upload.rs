@@ -0,0 +1,70 @@
+use std::{fs, sync::Arc};
+
+use super::App;
+use crate::{
+    error::Result,
+    model::{DownloadState, File, Folder, ProgressPayload, UploadConflictPolicy},
+};
+
+impl App {
+    pub async fn create_folder(&self, parent_folder_id: i64, name: &str) -> Result<Folder> {
+        self.client
+            .create_folder(parent_folder_id, name, &self.config.read().await.token)
+            .await
+    }
+
+    // Files are uploaded one by one, the progress of each is reported with its path as uuid.
+    // Stops at the first failure, files uploaded before it are kept.
+    pub async fn upload_files_to_folder<F: Fn(ProgressPayload) + Send + Sync + 'static>(
+        &self,
+        folder_id: i64,
+        file_paths: &[String],
+        policy: UploadConflictPolicy,
+        progress_handler: F,
+    ) -> Result<Vec<File>> {
+        let token = self.config.read().await.token.clone();
+        let progress_handler = Arc::new(progress_handler);
+        let mut files = vec![];
+        for file_path in file_paths {
+            let total = fs::metadata(file_path)?.len();
+            let payload = |processed, state| ProgressPayload {
+                uuid: file_path.clone(),
+                processed,
+                total,
+                state,
+            };
+            let handler = progress_handler.clone();
+            let uuid = file_path.clone();
+            let result = self
+                .client
+                .upload_file_to_folder(
+                    folder_id,
+                    file_path,
+                    policy,
+                    move |processed| {
+                        handler(ProgressPayload {
+                            uuid: uuid.clone(),
+                            processed,
+                            total,
+                            state: DownloadState::Running,
+                        })
+                    },
+                    &token,
+                )
+                .await;
+            match result {
+                Ok(file) => {
+                    progress_handler(payload(total, DownloadState::Completed));
+                    files.push(file);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to upload {}: {:?}", file_path, e);
+                    progress_handler(payload(0, DownloadState::Failed));
+                    return Err(e);
+                }
+            }
+        }
+        tracing::info!("Uploaded {} files to folder {}", files.len(), folder_id);
+        Ok(files)
+    }
+}
@@ -3,7 +3,10 @@ use std::{fs, path::Path};
 use super::App;
 use crate::{
     error::{AppError, Result},
-    model::{CanvasVideo, ProgressPayload, Subject, VideoCourse, VideoInfo, VideoPlayInfo},
+    model::{
+        CanvasVideo, DownloadState, DownloadTarget, ProgressPayload, Subject, VideoCourse,
+        VideoInfo, VideoPlayInfo,
+    },
 };
 // Apis for course video
 impl App {
@@ -69,17 +72,19 @@ impl App {
         self.client.get_canvas_videos(course_id).await
     }
 
-    pub async fn download_video<F: Fn(ProgressPayload) + Send + 'static>(
+    pub async fn download_video(
         &self,
         video: &VideoPlayInfo,
         save_name: &str,
-        progress_handler: F,
-    ) -> Result<()> {
+        priority: i64,
+    ) -> Result<DownloadState> {
         let save_dir = self.config.read().await.save_path.clone();
         let save_path = Path::new(&save_dir).join(save_name);
-        self.client
-            .clone()
-            .download_video(video, save_path.to_str().unwrap(), progress_handler)
+        let target = DownloadTarget::Video {
+            video: video.clone(),
+            save_path: save_path.to_string_lossy().into_owned(),
+        };
+        self.enqueue_download(&video.id.to_string(), target, priority, 0)
             .await
     }
 
@@ -10,8 +10,8 @@ use serde_json::json;
 use crate::{
     error::{AppError, Result},
     model::{
-        AppConfig, Assignment, CalendarEvent, Colors, Course, DiscussionTopic, File, Folder,
-        FullDiscussion, LLMChatMessage, LlmApiKeyEntry, ModuleItem, Submission, User,
+        AnnouncementFeed, AppConfig, Assignment, CalendarEvent, Colors, Course, DiscussionTopic,
+        File, Folder, FullDiscussion, LLMChatMessage, LlmApiKeyEntry, ModuleItem, Submission, User,
         UserSubmissions,
     },
     APP,
@@ -442,6 +442,50 @@ impl Tool for ListCalendarEventsTool {
     }
 }
 
+#[derive(Deserialize)]
+struct AnnouncementArgs {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ListAnnouncementsTool;
+
+impl Tool for ListAnnouncementsTool {
+    const NAME: &'static str = "list_announcements";
+    type Error = CanvasAgentToolError;
+    type Args = AnnouncementArgs;
+    type Output = AnnouncementFeed;
+
+    async fn definition(&self, _prompt: String) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description:
+                "List announcements of all current-term Canvas courses with read state and unread counts"
+                    .to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "start_date": {
+                        "type": "string",
+                        "description": "Optional start date in ISO-8601 format, defaults to 14 days ago"
+                    },
+                    "end_date": {
+                        "type": "string",
+                        "description": "Optional end date in ISO-8601 format"
+                    }
+                }
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> std::result::Result<Self::Output, Self::Error> {
+        APP.list_announcements(args.start_date.as_deref(), args.end_date.as_deref())
+            .await
+            .map_err(Into::into)
+    }
+}
+
 #[derive(Deserialize, Serialize)]
 struct ListDiscussionTopicsTool;
 
@@ -723,6 +767,7 @@ fn boxed_tools() -> Vec<Box<dyn ToolDyn>> {
         Box::new(GetFolderByIdTool),
         Box::new(GetColorsTool),
         Box::new(ListCalendarEventsTool),
+        Box::new(ListAnnouncementsTool),
         Box::new(ListDiscussionTopicsTool),
         Box::new(GetFullDiscussionTool),
         Box::new(ListCourseImagesTool),
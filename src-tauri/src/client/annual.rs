@@ -72,6 +72,40 @@ impl Client {
                     }
                 }
             }
+            // Quizzes may be hidden from students, which should not fail the whole report
+            let quizzes = self
+                .list_quizzes(course.id, token)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!("Failed to list quizzes of course {}: {:?}", course.id, e);
+                    vec![]
+                });
+            let mut quiz_count = 0;
+            let mut quiz_submitted_count = 0;
+            let mut quiz_points_possible = 0.0;
+            let mut quiz_total_score = 0.0;
+            for quiz in quizzes.iter().filter(|quiz| quiz.published) {
+                quiz_count += 1;
+                quiz_points_possible += quiz.points_possible.unwrap_or_default();
+                let quiz_submissions = self
+                    .list_my_quiz_submissions(course.id, quiz.id, token)
+                    .await
+                    .unwrap_or_default();
+                let Some(quiz_submission) = quiz_submissions
+                    .into_iter()
+                    .find(|quiz_submission| quiz_submission.finished_at.is_some())
+                else {
+                    continue;
+                };
+                quiz_submitted_count += 1;
+                quiz_total_score += quiz_submission
+                    .kept_score
+                    .or(quiz_submission.score)
+                    .unwrap_or_default();
+                if let Some(finished_at) = quiz_submission.finished_at {
+                    active_days.insert(finished_at.chars().take(10).collect::<String>());
+                }
+            }
             course_to_statistic.insert(
                 course.id,
                 AnnualCourseStatistic {
@@ -84,6 +118,10 @@ impl Client {
                     total_points_possible,
                     total_score,
                     submit_time_list,
+                    quiz_count,
+                    quiz_submitted_count,
+                    quiz_points_possible,
+                    quiz_total_score,
                 },
             );
         }
@@ -0,0 +1,544 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs,
+    io::Write,
+    path::Path,
+};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use super::{constants::CHUNK_SIZE, Client};
+use crate::{
+    error::Result,
+    model::{Attachment, File, Folder, ProgressPayload, Submission, User},
+    utils::file::sanitize_file_name,
+};
+
+// Apis here are for exporting canvas folders and submissions as archives
+impl Client {
+    // Walk the folder hierarchy breadth first, returning every file under `folder_id`
+    // together with the folder it belongs to
+    pub async fn list_folder_tree_files(
+        &self,
+        folder_id: i64,
+        token: &str,
+    ) -> Result<Vec<(Folder, File)>> {
+        let root = self.get_folder_by_id(folder_id, token).await?;
+        let mut folders = VecDeque::from([root]);
+        let mut entries = vec![];
+        while let Some(folder) = folders.pop_front() {
+            let files = self.list_folder_files(folder.id, token).await?;
+            entries.extend(files.into_iter().map(|file| (folder.clone(), file)));
+            let sub_folders = self.list_folder_folders(folder.id, token).await?;
+            folders.extend(sub_folders);
+        }
+        Ok(entries)
+    }
+
+    pub async fn download_folder_as_zip<F: Fn(ProgressPayload) + Send>(
+        &self,
+        folder_id: i64,
+        token: &str,
+        save_path: &str,
+        progress_handler: F,
+    ) -> Result<()> {
+        let entries = self.list_folder_tree_files(folder_id, token).await?;
+        let mut payload = ProgressPayload {
+            uuid: format!("folder_{folder_id}"),
+            processed: 0,
+            total: entries.iter().map(|(_, file)| file.size).sum(),
+            ..Default::default()
+        };
+        progress_handler(payload.clone());
+
+        let mut zip = ZipWriter::new(fs::File::create(save_path)?);
+        let mut last_chunk_no = 0;
+        for (folder, file) in &entries {
+            let options = SimpleFileOptions::default()
+                .compression_method(CompressionMethod::Deflated)
+                .large_file(file.size >= u32::MAX as u64);
+            zip.start_file(get_zip_entry_name(folder, file), options)?;
+            self.write_remote_file(
+                &file.url,
+                token,
+                &mut zip,
+                &mut payload,
+                &mut last_chunk_no,
+                &progress_handler,
+            )
+            .await?;
+        }
+        zip.finish()?;
+        progress_handler(payload);
+        tracing::info!(
+            "Folder {} archived to {} with {} files",
+            folder_id,
+            save_path,
+            entries.len()
+        );
+        Ok(())
+    }
+
+    async fn write_remote_file<W: Write, F: Fn(ProgressPayload)>(
+        &self,
+        url: &str,
+        token: &str,
+        writer: &mut W,
+        payload: &mut ProgressPayload,
+        last_chunk_no: &mut u64,
+        progress_handler: &F,
+    ) -> Result<()> {
+        let mut response = self
+            .get_request_with_token(url, None::<&str>, token)
+            .await?
+            .error_for_status()?;
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk)?;
+            payload.processed += chunk.len() as u64;
+            let chunk_no = payload.processed / CHUNK_SIZE;
+            if chunk_no != *last_chunk_no {
+                *last_chunk_no = chunk_no;
+                progress_handler(payload.clone());
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn list_submissions_with_history(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Vec<Submission>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions?include[]=submission_history",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    // `entries` map relative paths to attachments, see `get_submission_entries`. They are
+    // written under `save_path` as a directory, or into a zip file at `save_path`.
+    pub async fn download_submission_attachments<F: Fn(ProgressPayload) + Send>(
+        &self,
+        entries: &[(String, Attachment)],
+        token: &str,
+        save_path: &Path,
+        zip: bool,
+        uuid: &str,
+        progress_handler: F,
+    ) -> Result<()> {
+        let mut payload = ProgressPayload {
+            uuid: uuid.to_owned(),
+            processed: 0,
+            total: entries
+                .iter()
+                .map(|(_, attachment)| attachment.size.max(0) as u64)
+                .sum(),
+            ..Default::default()
+        };
+        progress_handler(payload.clone());
+
+        let mut last_chunk_no = 0;
+        if zip {
+            let mut zip = ZipWriter::new(fs::File::create(save_path)?);
+            for (entry_name, attachment) in entries {
+                let options = SimpleFileOptions::default()
+                    .compression_method(CompressionMethod::Deflated)
+                    .large_file(attachment.size >= u32::MAX as i64);
+                zip.start_file(entry_name, options)?;
+                self.write_remote_file(
+                    &attachment.url,
+                    token,
+                    &mut zip,
+                    &mut payload,
+                    &mut last_chunk_no,
+                    &progress_handler,
+                )
+                .await?;
+            }
+            zip.finish()?;
+        } else {
+            for (entry_name, attachment) in entries {
+                let path = save_path.join(entry_name);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut file = fs::File::create(&path)?;
+                self.write_remote_file(
+                    &attachment.url,
+                    token,
+                    &mut file,
+                    &mut payload,
+                    &mut last_chunk_no,
+                    &progress_handler,
+                )
+                .await?;
+            }
+        }
+        progress_handler(payload);
+        tracing::info!(
+            "Downloaded {} submission attachments to {}",
+            entries.len(),
+            save_path.display()
+        );
+        Ok(())
+    }
+}
+
+// Fills in {login_id}, {name}, {user_id} and {late} of the template
+pub fn get_submission_folder_name(
+    template: &str,
+    student: Option<&User>,
+    submission: &Submission,
+) -> String {
+    let user_id = submission.user_id.to_string();
+    let (login_id, name) = match student {
+        Some(student) => (student.login_id.as_str(), student.name.as_str()),
+        None => ("", user_id.as_str()),
+    };
+    let late = if submission.late { "_迟交" } else { "" };
+    let folder_name = template
+        .replace("{login_id}", login_id)
+        .replace("{name}", name)
+        .replace("{user_id}", &user_id)
+        .replace("{late}", late);
+    match sanitize_file_name(&folder_name) {
+        folder_name if folder_name.is_empty() => user_id,
+        folder_name => folder_name,
+    }
+}
+
+// Attachments of the latest attempt go right into the student folder, with
+// `all_attempts` every attempt gets an "attempt_<n>" sub folder instead
+pub fn get_submission_entries(
+    submissions: &[Submission],
+    students: &[User],
+    template: &str,
+    all_attempts: bool,
+) -> Vec<(String, Attachment)> {
+    let students: HashMap<i64, &User> = students
+        .iter()
+        .map(|student| (student.id, student))
+        .collect();
+    let mut used_names = HashSet::new();
+    let mut entries = vec![];
+    for submission in submissions {
+        let folder_name = get_submission_folder_name(
+            template,
+            students.get(&submission.user_id).copied(),
+            submission,
+        );
+        if !all_attempts {
+            entries.extend(submission.attachments.iter().map(|attachment| {
+                let name = format!(
+                    "{folder_name}/{}",
+                    sanitize_file_name(&attachment.display_name)
+                );
+                (unique_entry_name(&mut used_names, name), attachment.clone())
+            }));
+            continue;
+        }
+        for attempt in &submission.submission_history {
+            let attempt_no = attempt.attempt.unwrap_or_default();
+            entries.extend(attempt.attachments.iter().map(|attachment| {
+                let name = format!(
+                    "{folder_name}/attempt_{attempt_no}/{}",
+                    sanitize_file_name(&attachment.display_name)
+                );
+                (unique_entry_name(&mut used_names, name), attachment.clone())
+            }));
+        }
+    }
+    entries
+}
+
+// Canvas allows several attachments with the same name in one submission, later ones are
+// renamed like "report (1).pdf" so that they neither clash in a zip nor overwrite on disk
+fn unique_entry_name(used_names: &mut HashSet<String>, name: String) -> String {
+    if used_names.insert(name.clone()) {
+        return name;
+    }
+    let (dir, file_name) = match name.rsplit_once('/') {
+        Some((dir, file_name)) => (format!("{dir}/"), file_name),
+        None => (String::new(), name.as_str()),
+    };
+    let (stem, ext) = match file_name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem, format!(".{ext}")),
+        _ => (file_name, String::new()),
+    };
+    let mut index = 1;
+    loop {
+        let candidate = format!("{dir}{stem} ({index}){ext}");
+        if used_names.insert(candidate.clone()) {
+            return candidate;
+        }
+        index += 1;
+    }
+}
+
+// Zip entries keep the canvas folder path, e.g. "course files/slides/week1.pdf".
+// Every segment is sanitized so that the archive extracts on windows and stays inside
+// its root, segments left empty such as ".." are dropped.
+fn get_zip_entry_name(folder: &Folder, file: &File) -> String {
+    let mut segments: Vec<_> = folder
+        .full_name
+        .split(['/', '\\'])
+        .map(sanitize_file_name)
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let file_name = match sanitize_file_name(&file.display_name) {
+        file_name if file_name.is_empty() => file.id.to_string(),
+        file_name => file_name,
+    };
+    segments.push(file_name);
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(id: i64, display_name: &str) -> Attachment {
+        Attachment {
+            id,
+            display_name: display_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_submission_entries() {
+        let students = vec![User {
+            id: 1,
+            name: "Alice".to_owned(),
+            login_id: "520001".to_owned(),
+            ..Default::default()
+        }];
+        let submissions = vec![
+            Submission {
+                user_id: 1,
+                late: true,
+                attachments: vec![attachment(12, "report.pdf")],
+                submission_history: vec![
+                    Submission {
+                        attempt: Some(1),
+                        attachments: vec![attachment(11, "report.pdf")],
+                        ..Default::default()
+                    },
+                    Submission {
+                        attempt: Some(2),
+                        attachments: vec![attachment(12, "report.pdf")],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            // students who left the course fall back to their user id
+            Submission {
+                user_id: 2,
+                attachments: vec![attachment(20, "a/b.txt")],
+                ..Default::default()
+            },
+        ];
+        let template = "{login_id}_{name}{late}";
+
+        let names = |entries: Vec<(String, Attachment)>| {
+            entries
+                .into_iter()
+                .map(|(name, attachment)| (name, attachment.id))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(get_submission_entries(
+                &submissions,
+                &students,
+                template,
+                false
+            )),
+            vec![
+                ("520001_Alice_迟交/report.pdf".to_owned(), 12),
+                ("_2/a_b.txt".to_owned(), 20),
+            ]
+        );
+        assert_eq!(
+            names(get_submission_entries(
+                &submissions,
+                &students,
+                template,
+                true
+            )),
+            vec![
+                ("520001_Alice_迟交/attempt_1/report.pdf".to_owned(), 11),
+                ("520001_Alice_迟交/attempt_2/report.pdf".to_owned(), 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_zip_entry_name() {
+        let folder = Folder {
+            full_name: "course files/../week:1".to_owned(),
+            ..Default::default()
+        };
+        let file = File {
+            id: 7,
+            display_name: "a/b?|*.pdf".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_zip_entry_name(&folder, &file),
+            "course files/week_1/a_b___.pdf"
+        );
+        let file = File {
+            id: 7,
+            display_name: "..".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(get_zip_entry_name(&folder, &file), "course files/week_1/7");
+    }
+
+    #[test]
+    fn test_get_submission_entries_with_duplicate_names() {
+        let submissions = vec![Submission {
+            user_id: 1,
+            attachments: vec![
+                attachment(1, "report.pdf"),
+                attachment(2, "report.pdf"),
+                attachment(3, "report (1).pdf"),
+                attachment(4, "README"),
+                attachment(5, "README"),
+            ],
+            ..Default::default()
+        }];
+        let names: Vec<_> = get_submission_entries(&submissions, &[], "{user_id}", false)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "1/report.pdf",
+                "1/report (1).pdf",
+                "1/report (1) (1).pdf",
+                "1/README",
+                "1/README (1)",
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use serde_json::json;
+    use std::io::Read;
+    use uuid::Uuid;
+
+    fn folder(id: i64, name: &str, full_name: &str) -> Folder {
+        Folder {
+            id,
+            name: name.to_owned(),
+            full_name: full_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn file(server: &MockServer, id: i64, folder_id: i64, display_name: &str, size: u64) -> File {
+        File {
+            id,
+            uuid: format!("file-uuid-{id}"),
+            folder_id,
+            display_name: display_name.to_owned(),
+            url: server.url(format!("/files/{id}/download")),
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_folder_as_zip() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let root = folder(1, "course files", "course files");
+        let week1 = folder(2, "week1", "course files/week1");
+        let syllabus = file(&server, 10, 1, "syllabus.pdf", 8);
+        let slides = file(&server, 11, 2, "slides.pdf", 6);
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/v1/folders/1");
+            then.status(200).json_body(json!(root));
+        });
+        for (folder_id, files, folders) in [
+            (1, json!([syllabus]), json!([week1])),
+            (2, json!([slides]), json!([])),
+        ] {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/api/v1/folders/{folder_id}/files"))
+                    .query_param("page", "1");
+                then.status(200).json_body(files);
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/api/v1/folders/{folder_id}/folders"))
+                    .query_param("page", "1");
+                then.status(200).json_body(folders);
+            });
+        }
+        server.mock(|when, then| {
+            when.method(GET).path("/files/10/download");
+            then.status(200).body("syllabus");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/files/11/download");
+            then.status(200).body("slides");
+        });
+
+        let client = Client::new_without_proxy(
+            server.base_url(),
+            "".to_owned(),
+            "".to_owned(),
+            "".to_owned(),
+            None,
+        );
+        let save_path = std::env::temp_dir().join(format!("{}.zip", Uuid::new_v4()));
+        let progress = std::sync::Mutex::new(vec![]);
+        client
+            .download_folder_as_zip(1, token, save_path.to_str().unwrap(), |payload| {
+                progress.lock().unwrap().push(payload)
+            })
+            .await
+            .unwrap();
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(&save_path).unwrap()).unwrap();
+        let mut contents = vec![];
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).unwrap();
+            let mut content = String::new();
+            entry.read_to_string(&mut content).unwrap();
+            contents.push((entry.name().to_owned(), content));
+        }
+        _ = fs::remove_file(&save_path);
+
+        assert_eq!(
+            contents,
+            vec![
+                (
+                    "course files/syllabus.pdf".to_owned(),
+                    "syllabus".to_owned()
+                ),
+                (
+                    "course files/week1/slides.pdf".to_owned(),
+                    "slides".to_owned()
+                ),
+            ]
+        );
+        let last = progress.lock().unwrap().last().cloned().unwrap();
+        assert_eq!(last.processed, 14);
+        assert_eq!(last.total, 14);
+    }
+}
@@ -0,0 +1,222 @@
+use super::Client;
+use crate::{
+    error::Result,
+    model::{Assignment, AssignmentGroup, AssignmentInput},
+    utils,
+};
+
+fn assignment_form(input: &AssignmentInput) -> Result<Vec<(String, String)>> {
+    let mut form = vec![
+        ("assignment[name]".to_owned(), input.name.clone()),
+        (
+            "assignment[due_at]".to_owned(),
+            input.due_at.clone().unwrap_or_default(),
+        ),
+        (
+            "assignment[unlock_at]".to_owned(),
+            input.unlock_at.clone().unwrap_or_default(),
+        ),
+        (
+            "assignment[lock_at]".to_owned(),
+            input.lock_at.clone().unwrap_or_default(),
+        ),
+    ];
+    if let Some(description) = &input.description {
+        form.push(("assignment[description]".to_owned(), description.clone()));
+    }
+    if let Some(points_possible) = input.points_possible {
+        form.push((
+            "assignment[points_possible]".to_owned(),
+            points_possible.to_string(),
+        ));
+    }
+    if let Some(grading_type) = &input.grading_type {
+        let grading_type = serde_json::to_value(grading_type)?;
+        form.push((
+            "assignment[grading_type]".to_owned(),
+            grading_type.as_str().unwrap_or_default().to_owned(),
+        ));
+    }
+    for submission_type in &input.submission_types {
+        form.push((
+            "assignment[submission_types][]".to_owned(),
+            submission_type.clone(),
+        ));
+    }
+    for extension in &input.allowed_extensions {
+        form.push((
+            "assignment[allowed_extensions][]".to_owned(),
+            extension.clone(),
+        ));
+    }
+    if let Some(assignment_group_id) = input.assignment_group_id {
+        form.push((
+            "assignment[assignment_group_id]".to_owned(),
+            assignment_group_id.to_string(),
+        ));
+    }
+    if let Some(published) = input.published {
+        form.push(("assignment[published]".to_owned(), published.to_string()));
+    }
+    Ok(form)
+}
+
+// Apis here are for managing assignments as a teacher
+impl Client {
+    pub async fn list_assignment_groups(
+        &self,
+        course_id: i64,
+        token: &str,
+    ) -> Result<Vec<AssignmentGroup>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignment_groups",
+            self.base_url.read().await,
+            course_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn create_assignment(
+        &self,
+        course_id: i64,
+        input: &AssignmentInput,
+        token: &str,
+    ) -> Result<Assignment> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments",
+            self.base_url.read().await,
+            course_id
+        );
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &assignment_form(input)?, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn update_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        input: &AssignmentInput,
+        token: &str,
+    ) -> Result<Assignment> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let response = self
+            .put_form_with_token(&url, None::<&str>, &assignment_form(input)?, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn set_assignment_published(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        published: bool,
+        token: &str,
+    ) -> Result<Assignment> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let form = [("assignment[published]", published.to_string())];
+        let response = self
+            .put_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn delete_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        self.cli
+            .delete(url)
+            .header("Authorization", format!("Bearer {token}"))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    // Canvas copies the assignment within its course, named "<name> Copy"
+    pub async fn duplicate_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Assignment> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/duplicate",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let response = self
+            .cli
+            .post(url)
+            .header("Authorization", format!("Bearer {token}"))
+            .send()
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::GradingType;
+
+    #[test]
+    fn test_assignment_form() {
+        let input = AssignmentInput {
+            name: "Homework 1".to_owned(),
+            points_possible: Some(100.0),
+            grading_type: Some(GradingType::PassFail),
+            submission_types: vec!["online_upload".to_owned()],
+            allowed_extensions: vec!["pdf".to_owned(), "zip".to_owned()],
+            due_at: Some("2024-03-01T15:59:59Z".to_owned()),
+            ..Default::default()
+        };
+        let form = assignment_form(&input).unwrap();
+        let value = |key: &str| {
+            form.iter()
+                .filter(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(value("assignment[name]"), vec!["Homework 1"]);
+        assert_eq!(value("assignment[points_possible]"), vec!["100"]);
+        assert_eq!(value("assignment[grading_type]"), vec!["pass_fail"]);
+        assert_eq!(
+            value("assignment[allowed_extensions][]"),
+            vec!["pdf", "zip"]
+        );
+        // dates left empty are cleared
+        assert_eq!(value("assignment[lock_at]"), vec![""]);
+        assert!(value("assignment[published]").is_empty());
+    }
+}
@@ -1,20 +1,28 @@
-use super::{constants::BASE_URL, file_parser, llm, Client};
+use super::{constants::BASE_URL, file_parser, llm, pagination::ListOptions, Client};
 use ::bytes::Bytes;
-use reqwest::{cookie, multipart};
+use futures::TryStreamExt;
+use reqwest::{cookie, header::RANGE, multipart, Body, StatusCode};
 use serde::de::DeserializeOwned;
 use std::{cmp::min, collections::HashSet, fs, io::Write, ops::Deref, path::Path, sync::Arc};
 use tokio::{sync::RwLock, task::JoinSet};
+use tokio_util::io::ReaderStream;
 
 use crate::{
-    client::constants::CHUNK_SIZE,
+    client::constants::{
+        CHUNK_SIZE, DOWNLOAD_MAX_RETRIES, MAX_CONCURRENT_REQUESTS, PLAIN_TEXT_EXTENSIONS,
+    },
     error::{AppError, Result},
     model::{
-        Assignment, CalendarEvent, Colors, Course, DiscussionTopic, File, Folder,
-        FoldersAndFiles, FullDiscussion, Module, ModuleItem, ProgressPayload, RelationshipEdge,
-        RelationshipNode, RelationshipNodeType, RelationshipTopo, Submission, SubmissionUploadResult,
-        SubmissionUploadSuccessResponse, User, UserSubmissions,
+        Announcement, Assignment, CalendarEvent, CanvasProgress, Colors, Course, DiscussionTopic,
+        File, Folder, FullDiscussion, GradeUpdate, Module, ModuleItem, NewSubmission,
+        ProgressPayload, RelationshipEdge, RelationshipNode, RelationshipNodeType,
+        RelationshipTopo, Submission, SubmissionUploadResult, SubmissionUploadSuccessResponse,
+        User, UserSubmissions,
+    },
+    utils::{
+        self,
+        file::{get_file_name, get_part_path},
     },
-    utils::{self, file::get_file_name},
 };
 
 // Apis here are for canvas
@@ -57,6 +65,8 @@ impl Client {
             llm_cli,
             file_parser,
             debug_store: super::debug::NetworkDebugStore::new(false),
+            throttle: super::retry::RequestThrottle::new(MAX_CONCURRENT_REQUESTS),
+            retry_policy: Default::default(),
         }
     }
 
@@ -91,6 +101,8 @@ impl Client {
             llm_cli,
             file_parser,
             debug_store: super::debug::NetworkDebugStore::new(false),
+            throttle: super::retry::RequestThrottle::new(MAX_CONCURRENT_REQUESTS),
+            retry_policy: Default::default(),
         }
     }
 
@@ -157,11 +169,13 @@ impl Client {
         Ok(())
     }
 
+    // Give every student in `student_ids` the same grade, e.g. all members of a group.
+    // The comment is posted once as a group comment when grading several students.
     pub async fn update_grade(
         &self,
         course_id: i64,
         assignment_id: i64,
-        student_id: i64,
+        student_ids: &[i64],
         grade: &str,
         comment: Option<&str>,
         token: &str,
@@ -172,19 +186,64 @@ impl Client {
             course_id,
             assignment_id
         );
-        let form = match comment {
-            Some(comment) => vec![
-                (format!("grade_data[{student_id}][posted_grade]"), grade),
-                (format!("grade_data[{student_id}][text_comment]"), comment),
-            ],
-            None => vec![(format!("grade_data[{student_id}][posted_grade]"), grade)],
-        };
+        let mut form = vec![];
+        for student_id in student_ids {
+            form.push((format!("grade_data[{student_id}][posted_grade]"), grade));
+        }
+        if let (Some(comment), Some(student_id)) = (comment, student_ids.first()) {
+            form.push((format!("grade_data[{student_id}][text_comment]"), comment));
+            if student_ids.len() > 1 {
+                form.push((format!("grade_data[{student_id}][group_comment]"), "true"));
+            }
+        }
         self.post_form_with_token(&url, None::<&str>, &form, token)
             .await?
             .error_for_status()?;
         Ok(())
     }
 
+    // Canvas grades the entries asynchronously and returns a progress object
+    pub async fn update_grades(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        grades: &[GradeUpdate],
+        token: &str,
+    ) -> Result<CanvasProgress> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions/update_grades",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let mut form = vec![];
+        for grade in grades {
+            let student_id = grade.student_id;
+            form.push((
+                format!("grade_data[{student_id}][posted_grade]"),
+                grade.grade.as_str(),
+            ));
+            if let Some(comment) = &grade.comment {
+                form.push((format!("grade_data[{student_id}][text_comment]"), comment));
+            }
+        }
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn get_progress(&self, progress_id: i64, token: &str) -> Result<CanvasProgress> {
+        let url = format!(
+            "{}/api/v1/progress/{}",
+            self.base_url.read().await,
+            progress_id
+        );
+        self.get_json_with_token(&url, None::<&str>, token).await
+    }
+
     pub async fn modify_assignment_ddl(
         &self,
         course_id: i64,
@@ -333,73 +392,142 @@ impl Client {
         Ok(bytes)
     }
 
-    pub async fn download_file<F: Fn(ProgressPayload) + Send>(
+    pub async fn parse_local_file(&self, path: &Path) -> Result<String> {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let data = Bytes::from(fs::read(path)?);
+        self.file_parser.parse(data, &ext).await
+    }
+
+    // Plain text and source files are decoded as is, other formats go through the file parser
+    pub async fn parse_remote_file(&self, url: &str, ext: &str, token: &str) -> Result<String> {
+        let data = self
+            .get_request_with_token(url, None::<&str>, token)
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        if PLAIN_TEXT_EXTENSIONS.contains(&ext) {
+            return Ok(String::from_utf8_lossy(&data).into_owned());
+        }
+        self.file_parser.parse(data, ext).await
+    }
+
+    // Download (or resume) the file into `<display_name>.part`, returns the size on disk
+    async fn download_file_part<F: Fn(ProgressPayload) + Send>(
         &self,
         file: &File,
         token: &str,
-        save_path: &str,
-        progress_handler: F,
-    ) -> Result<()> {
+        part_path: &Path,
+        payload: &mut ProgressPayload,
+        progress_handler: &F,
+    ) -> Result<u64> {
+        let mut offset = fs::metadata(part_path).map(|m| m.len()).unwrap_or(0);
+        if offset > file.size {
+            fs::remove_file(part_path)?;
+            offset = 0;
+        }
+        if offset == file.size && offset > 0 {
+            return Ok(offset);
+        }
+
+        let mut request = self
+            .cli
+            .get(&file.url)
+            .header("Authorization", format!("Bearer {token}"));
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={offset}-"));
+        }
         let mut response = self
-            .get_request_with_token(&file.url, None::<&str>, token)
+            .execute_request(request.build()?)
             .await?
             .error_for_status()?;
 
-        let mut payload = ProgressPayload {
-            uuid: file.uuid.clone(),
-            processed: 0,
-            total: file.size,
+        // Servers that ignore the Range header send the whole file again
+        let mut output = if response.status() == StatusCode::PARTIAL_CONTENT {
+            tracing::info!("Resume downloading {:?} from {}", part_path, offset);
+            fs::OpenOptions::new().append(true).open(part_path)?
+        } else {
+            offset = 0;
+            fs::File::create(part_path)?
         };
-        let path = Path::new(save_path).join(&file.display_name);
-        let total = file.size;
-        let mut file = fs::File::create(path.to_str().unwrap())?;
-        let mut last_chunk_no = 0;
+        payload.processed = offset;
+        progress_handler(payload.clone());
+
+        let mut last_chunk_no = offset / CHUNK_SIZE;
         while let Some(chunk) = response.chunk().await? {
+            output.write_all(&chunk)?;
             payload.processed += chunk.len() as u64;
             let chunk_no = payload.processed / CHUNK_SIZE;
-            if chunk_no != last_chunk_no || payload.processed == total {
+            if chunk_no != last_chunk_no || payload.processed == payload.total {
                 last_chunk_no = chunk_no;
                 progress_handler(payload.clone());
             }
-            file.write_all(&chunk)?;
         }
-
-        tracing::info!("File {:?} downloaded successfully!", path);
-        Ok(())
+        output.flush()?;
+        Ok(payload.processed)
     }
 
-    pub async fn list_items_with_page<T: DeserializeOwned>(
+    pub async fn download_file<F: Fn(ProgressPayload) + Send>(
         &self,
-        url: &str,
+        file: &File,
         token: &str,
-        page: u64,
-    ) -> Result<Vec<T>> {
-        let items = self
-            .get_json_with_token(
-                url,
-                Some(&vec![
-                    ("page", page.to_string()),
-                    ("per_page", "100".to_owned()),
-                ]),
-                token,
-            )
-            .await?;
-        Ok(items)
+        save_path: &str,
+        progress_handler: F,
+    ) -> Result<()> {
+        let mut payload = ProgressPayload {
+            uuid: file.uuid.clone(),
+            processed: 0,
+            total: file.size,
+            ..Default::default()
+        };
+        let path = Path::new(save_path).join(&file.display_name);
+        let part_path = get_part_path(&path);
+
+        let mut retries = 0;
+        let downloaded = loop {
+            let result = self
+                .download_file_part(file, token, &part_path, &mut payload, &progress_handler)
+                .await;
+            match result {
+                Ok(downloaded) if downloaded == file.size => break downloaded,
+                Ok(downloaded) => {
+                    tracing::warn!(
+                        "File {:?} incomplete: {} of {} bytes",
+                        path,
+                        downloaded,
+                        file.size
+                    );
+                    if retries == DOWNLOAD_MAX_RETRIES {
+                        break downloaded;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to download {:?}: {}", path, e);
+                    if retries == DOWNLOAD_MAX_RETRIES {
+                        return Err(e);
+                    }
+                }
+            }
+            // give a dropped connection time to come back before resuming
+            tokio::time::sleep(self.retry_policy.backoff(retries)).await;
+            retries += 1;
+        };
+
+        if downloaded != file.size {
+            return Err(AppError::FileDownloadError(file.display_name.clone()));
+        }
+        fs::rename(&part_path, &path)?;
+        tracing::info!("File {:?} downloaded successfully!", path);
+        Ok(())
     }
 
     pub async fn list_items<T: DeserializeOwned>(&self, url: &str, token: &str) -> Result<Vec<T>> {
-        let mut all_items = vec![];
-        let mut page = 1;
-
-        loop {
-            let items = self.list_items_with_page(url, token, page).await?;
-            if items.is_empty() {
-                break;
-            }
-            page += 1;
-            all_items.extend(items);
-        }
-        Ok(all_items)
+        self.list_items_with_options(url, token, &ListOptions::default())
+            .await
     }
 
     pub async fn list_discussion_topics(
@@ -431,12 +559,22 @@ impl Client {
     }
 
     pub async fn list_course_files(&self, course_id: i64, token: &str) -> Result<Vec<File>> {
+        self.list_course_files_with_options(course_id, &ListOptions::default(), token)
+            .await
+    }
+
+    pub async fn list_course_files_with_options(
+        &self,
+        course_id: i64,
+        options: &ListOptions,
+        token: &str,
+    ) -> Result<Vec<File>> {
         let url = format!(
             "{}/api/v1/courses/{}/files",
             self.base_url.read().await,
             course_id
         );
-        self.list_items(&url, token).await
+        self.list_items_with_options(&url, token, options).await
     }
 
     pub async fn list_course_images(&self, course_id: i64, token: &str) -> Result<Vec<File>> {
@@ -449,12 +587,30 @@ impl Client {
     }
 
     pub async fn list_folder_files(&self, folder_id: i64, token: &str) -> Result<Vec<File>> {
+        self.list_folder_files_by_page(folder_id, token, |_| {})
+            .await
+    }
+
+    // Same as `list_folder_files`, but hands every page to `on_page` as soon as it arrives
+    pub async fn list_folder_files_by_page<F: FnMut(&[File])>(
+        &self,
+        folder_id: i64,
+        token: &str,
+        mut on_page: F,
+    ) -> Result<Vec<File>> {
         let url = format!(
             "{}/api/v1/folders/{}/files",
             self.base_url.read().await,
             folder_id
         );
-        self.list_items(&url, token).await
+        let mut files = vec![];
+        self.for_each_page(&url, token, &ListOptions::default(), |page: Vec<File>| {
+            on_page(&page);
+            files.extend(page);
+            Ok(())
+        })
+        .await?;
+        Ok(files)
     }
 
     pub async fn list_course_folders(&self, course_id: i64, token: &str) -> Result<Vec<Folder>> {
@@ -490,12 +646,6 @@ impl Client {
         Ok(folder)
     }
 
-    async fn get_folders_and_files(&self, course_id: i64, token: &str) -> Result<FoldersAndFiles> {
-        let folders = self.list_course_folders(course_id, token).await?;
-        let files = self.list_course_files(course_id, token).await?;
-        Ok(FoldersAndFiles::new(folders, files))
-    }
-
     pub fn get_course_identifier(&self, course: &Course) -> String {
         format!(
             "{}({} {})",
@@ -503,41 +653,6 @@ impl Client {
         )
     }
 
-    pub async fn sync_course_files(
-        &self,
-        course: &Course,
-        save_dir: &str,
-        token: &str,
-    ) -> Result<Vec<File>> {
-        let folders_and_files = self.get_folders_and_files(course.id, token).await?;
-        let folders_map = &folders_and_files.folders_map;
-        tracing::info!("folders_map: {:?}", folders_map);
-        let files = folders_and_files
-            .files
-            .into_iter()
-            .filter(|file| {
-                let folder = folders_map.get(&file.folder_id);
-                match folder {
-                    Some(folder) => {
-                        let folder_name = &folder.full_name;
-                        if folder_name.len() < 12 {
-                            return false;
-                        }
-
-                        let mut path = Path::new(save_dir).join(self.get_course_identifier(course));
-                        if folder_name != "course files" {
-                            path = path.join(&folder_name[13..]);
-                        }
-                        path = path.join(&file.display_name);
-                        fs::metadata(&path).is_err()
-                    }
-                    None => true,
-                }
-            })
-            .collect();
-        Ok(files)
-    }
-
     pub async fn get_colors(&self, token: &str) -> Result<Colors> {
         let url = format!("{}/api/v1/users/self/colors", self.base_url.read().await);
         let colors = self.get_json_with_token(&url, None::<&str>, token).await?;
@@ -596,6 +711,58 @@ impl Client {
         Ok(all_events)
     }
 
+    pub async fn list_announcements(
+        &self,
+        token: &str,
+        context_codes: &[String],
+        start_date: Option<&str>,
+        end_date: Option<&str>,
+    ) -> Result<Vec<Announcement>> {
+        const BATCH_SIZE: usize = 10;
+        let mut all_announcements = vec![];
+        for context_codes_batch in context_codes.chunks(BATCH_SIZE) {
+            let mut url = format!("{}/api/v1/announcements?", self.base_url.read().await);
+            url += &context_codes_batch
+                .iter()
+                .map(|context_code| format!("context_codes[]={context_code}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            // Canvas only returns the last 14 days when no date range is given
+            if let Some(start_date) = start_date {
+                url += &format!("&start_date={start_date}");
+            }
+            if let Some(end_date) = end_date {
+                url += &format!("&end_date={end_date}");
+            }
+            let announcements: Vec<Announcement> = self.list_items(&url, token).await?;
+            all_announcements.extend(announcements);
+        }
+        Ok(all_announcements)
+    }
+
+    // Announcements of every active course, newest first
+    pub async fn list_current_term_announcements(
+        &self,
+        token: &str,
+        start_date: Option<&str>,
+        end_date: Option<&str>,
+    ) -> Result<Vec<Announcement>> {
+        let context_codes: Vec<_> = self
+            .list_current_term_courses(token)
+            .await?
+            .iter()
+            .map(|course| format!("course_{}", course.id))
+            .collect();
+        if context_codes.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut announcements = self
+            .list_announcements(token, &context_codes, start_date, end_date)
+            .await?;
+        announcements.sort_by(|a, b| b.posted_at.cmp(&a.posted_at));
+        Ok(announcements)
+    }
+
     pub async fn list_course_users(&self, course_id: i64, token: &str) -> Result<Vec<User>> {
         let url = format!(
             "{}/api/v1/courses/{}/users",
@@ -613,7 +780,7 @@ impl Client {
         token: &str,
     ) -> Result<Submission> {
         let url = format!(
-            "{}/api/v1/courses/{}/assignments/{}/submissions/{}?include[]=submission_comments",
+            "{}/api/v1/courses/{}/assignments/{}/submissions/{}?include[]=submission_comments&include[]=rubric_assessment",
             self.base_url.read().await,
             course_id,
             assignment_id,
@@ -623,6 +790,21 @@ impl Client {
         Ok(submission)
     }
 
+    pub async fn get_assignment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Assignment> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}?include[]=all_dates",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        self.get_json_with_token(&url, None::<&str>, token).await
+    }
+
     pub async fn list_course_assignment_submissions(
         &self,
         course_id: i64,
@@ -749,14 +931,32 @@ impl Client {
         Ok(me)
     }
 
-    async fn upload_submission_file_with(
+    pub(super) async fn upload_submission_file_with(
         &self,
         params: &SubmissionUploadSuccessResponse,
         file_path: &str,
+    ) -> Result<File> {
+        self.upload_file_with_progress(params, file_path, |_| {})
+            .await
+    }
+
+    // Step 2 of the canvas file upload flow, `handler` receives the bytes sent so far
+    pub(super) async fn upload_file_with_progress<F: Fn(u64) + Send + Sync + 'static>(
+        &self,
+        params: &SubmissionUploadSuccessResponse,
+        file_path: &str,
+        handler: F,
     ) -> Result<File> {
         let upload_params = &params.upload_params;
-        let file_fs = fs::read(file_path)?;
-        let file = multipart::Part::bytes(file_fs).file_name("filename.filetype");
+        let file_fs = tokio::fs::File::open(file_path).await?;
+        let size = file_fs.metadata().await?.len();
+        let mut processed = 0;
+        let stream = ReaderStream::new(file_fs).inspect_ok(move |chunk| {
+            processed += chunk.len() as u64;
+            handler(processed);
+        });
+        let file = multipart::Part::stream_with_length(Body::wrap_stream(stream), size)
+            .file_name("filename.filetype");
         let form = reqwest::multipart::Form::new()
             .text("x-amz-credential", upload_params.x_amz_credential.clone())
             .text("x-amz-algorithm", upload_params.x_amz_algorithm.clone())
@@ -800,15 +1000,30 @@ impl Client {
             course_id,
             assignment_id,
         );
+        self.prepare_upload_file(&url, file_path, file_name, &[], token)
+            .await
+    }
+
+    // Step 1 of the canvas file upload flow, shared by every upload endpoint
+    pub(super) async fn prepare_upload_file(
+        &self,
+        url: &str,
+        file_path: &str,
+        file_name: &str,
+        extra_form: &[(&str, &str)],
+        token: &str,
+    ) -> Result<SubmissionUploadSuccessResponse> {
         let metadata = fs::metadata(file_path)?;
         if !metadata.is_file() {
             let error_message = format!("{file_path} is not a valid file!");
             return Err(AppError::SubmissionUpload(error_message));
         }
 
-        let form = [("name", file_name), ("size", &metadata.len().to_string())];
+        let size = metadata.len().to_string();
+        let mut form = vec![("name", file_name), ("size", &size)];
+        form.extend_from_slice(extra_form);
         let resp = self
-            .post_form_with_token(&url, None::<&str>, &form, token)
+            .post_form_with_token(url, None::<&str>, &form, token)
             .await?;
         let bytes = resp.bytes().await?;
         let result = match utils::json::parse_json::<SubmissionUploadResult>(&bytes)? {
@@ -824,17 +1039,39 @@ impl Client {
         &self,
         course_id: i64,
         assignment_id: i64,
-        file_paths: &[String],
+        submission: &NewSubmission,
         comment: Option<&str>,
         token: &str,
-    ) -> Result<()> {
-        let mut file_ids = vec![];
-        for file_path in file_paths {
-            let file_name = &get_file_name(file_path);
-            let file = self
-                .upload_submission_file(course_id, assignment_id, file_path, file_name, token)
-                .await?;
-            file_ids.push(file.id);
+    ) -> Result<Submission> {
+        let mut form = vec![(
+            "submission[submission_type]",
+            submission.submission_type().to_owned(),
+        )];
+        match submission {
+            NewSubmission::Upload { file_paths } => {
+                for file_path in file_paths {
+                    let file_name = &get_file_name(file_path);
+                    let file = self
+                        .upload_submission_file(
+                            course_id,
+                            assignment_id,
+                            file_path,
+                            file_name,
+                            token,
+                        )
+                        .await?;
+                    form.push(("submission[file_ids][]", file.id.to_string()));
+                }
+            }
+            NewSubmission::TextEntry { body } => {
+                form.push(("submission[body]", body.clone()));
+            }
+            NewSubmission::Url { url } => {
+                form.push(("submission[url]", url.clone()));
+            }
+        }
+        if let Some(comment) = comment {
+            form.push(("comment[text_comment]", comment.to_owned()));
         }
 
         let url = format!(
@@ -843,16 +1080,12 @@ impl Client {
             course_id,
             assignment_id,
         );
-        let mut form = vec![("submission[submission_type]", "online_upload".to_owned())];
-        for file_id in file_ids {
-            form.push(("submission[file_ids][]", file_id.to_string()));
-        }
-        if let Some(comment) = comment {
-            form.push(("comment[text_comment]", comment.to_owned()));
-        }
-        self.post_form_with_token(&url, None::<&str>, &form, token)
-            .await?;
-        Ok(())
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
     }
 
     // Reference: https://canvas.instructure.com/doc/api/file.file_uploads.html
@@ -980,18 +1213,93 @@ impl Client {
 
 #[cfg(test)]
 mod mock_tests {
-    use super::super::Client;
+    use super::super::create_test_client;
+    use crate::model::{File, GradeUpdate, NewSubmission};
     use httpmock::prelude::*;
     use serde_json::json;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_get_assignment_includes_all_dates() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+
+        let assignment_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/assignments/2")
+                .query_param("include[]", "all_dates");
+            then.status(200).json_body(json!({
+                "id": 2,
+                "course_id": 1,
+                "name": "Homework 1",
+                "html_url": "",
+                "submission_types": ["online_upload"],
+                "has_submitted_submissions": false,
+                "published": true,
+                "submission": null,
+                "due_at": "2024-03-15T00:00:00Z",
+                "all_dates": [
+                    { "base": true, "due_at": "2024-03-01T00:00:00Z" },
+                    { "id": 3, "due_at": "2024-03-15T00:00:00Z" }
+                ]
+            }));
+        });
 
-    fn create_test_client(mock_server_url: &str) -> Client {
-        Client::new_without_proxy(mock_server_url, "", "", "", None)
+        let client = create_test_client(&server.base_url());
+        let assignment = client.get_assignment(1, 2, token).await.unwrap();
+        assignment_mock.assert();
+        assert_eq!(assignment.all_dates.len(), 2);
+        assert!(assignment.all_dates[0].base);
+    }
+
+    #[tokio::test]
+    async fn test_update_grades_returns_progress() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+
+        let update_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/assignments/2/submissions/update_grades")
+                .x_www_form_urlencoded_tuple("grade_data[3][posted_grade]", "95");
+            then.status(200).json_body(json!({
+                "id": 7,
+                "workflow_state": "queued",
+                "completion": 0.0
+            }));
+        });
+        let progress_mock = server.mock(|when, then| {
+            when.method(GET).path("/api/v1/progress/7");
+            then.status(200).json_body(json!({
+                "id": 7,
+                "workflow_state": "failed",
+                "completion": 50.0,
+                "message": "grade is invalid"
+            }));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let grades = [GradeUpdate {
+            student_id: 3,
+            grade: "95".to_owned(),
+            comment: None,
+        }];
+        let progress = client.update_grades(1, 2, &grades, token).await.unwrap();
+        assert_eq!(progress.id, 7);
+        assert!(!progress.is_finished());
+
+        let progress = client.get_progress(7, token).await.unwrap();
+        assert!(progress.is_finished());
+        assert_eq!(progress.message.as_deref(), Some("grade is invalid"));
+        update_mock.assert();
+        progress_mock.assert();
     }
 
     #[tokio::test]
     async fn test_list_courses() {
         let server = MockServer::start();
         let token = "test_token_12345";
+        let page2_url =
+            server.url("/api/v1/courses?include[]=teachers&include[]=term&page=2&per_page=100");
 
         let page1_mock = server.mock(|when, then| {
             when.method(GET)
@@ -1002,6 +1310,7 @@ mod mock_tests {
                 .query_param("per_page", "100");
             then.status(200)
                 .header("Content-Type", "application/json")
+                .header("Link", format!("<{page2_url}>; rel=\"next\""))
                 .json_body(json!([
                     {
                         "id": 1,
@@ -1130,6 +1439,176 @@ mod mock_tests {
 
         mock.assert();
     }
+
+    fn create_download_test_file(server: &MockServer, display_name: &str, size: u64) -> File {
+        File {
+            uuid: "file-uuid".to_owned(),
+            display_name: display_name.to_owned(),
+            url: server.url("/files/1/download"),
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_file_resumes_part_file() {
+        let server = MockServer::start();
+        let save_dir = std::env::temp_dir().join(format!("download_test_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&save_dir).unwrap();
+        let file = create_download_test_file(&server, "slides.pdf", 10);
+        std::fs::write(save_dir.join("slides.pdf.part"), b"01234").unwrap();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/files/1/download")
+                .header("Range", "bytes=5-");
+            then.status(206).body("56789");
+        });
+
+        let client = create_test_client(&server.base_url());
+        let result = client
+            .download_file(&file, "token", save_dir.to_str().unwrap(), |_| {})
+            .await;
+        let content = std::fs::read(save_dir.join("slides.pdf"));
+        let part_exists = save_dir.join("slides.pdf.part").exists();
+        let _ = std::fs::remove_dir_all(&save_dir);
+
+        assert!(result.is_ok());
+        assert_eq!(content.unwrap(), b"0123456789");
+        assert!(!part_exists);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_restarts_without_range_support() {
+        let server = MockServer::start();
+        let save_dir = std::env::temp_dir().join(format!("download_test_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&save_dir).unwrap();
+        let file = create_download_test_file(&server, "notes.pdf", 10);
+        std::fs::write(save_dir.join("notes.pdf.part"), b"stale").unwrap();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/files/1/download");
+            then.status(200).body("0123456789");
+        });
+
+        let client = create_test_client(&server.base_url());
+        let result = client
+            .download_file(&file, "token", save_dir.to_str().unwrap(), |_| {})
+            .await;
+        let content = std::fs::read(save_dir.join("notes.pdf"));
+        let _ = std::fs::remove_dir_all(&save_dir);
+
+        assert!(result.is_ok());
+        assert_eq!(content.unwrap(), b"0123456789");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_keeps_incomplete_part_file() {
+        let server = MockServer::start();
+        let save_dir = std::env::temp_dir().join(format!("download_test_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&save_dir).unwrap();
+        let file = create_download_test_file(&server, "video.mp4", 10);
+
+        server.mock(|when, then| {
+            when.method(GET).path("/files/1/download");
+            then.status(200).body("01234");
+        });
+
+        let client = create_test_client(&server.base_url());
+        let result = client
+            .download_file(&file, "token", save_dir.to_str().unwrap(), |_| {})
+            .await;
+        let final_exists = save_dir.join("video.mp4").exists();
+        let part_exists = save_dir.join("video.mp4.part").exists();
+        let _ = std::fs::remove_dir_all(&save_dir);
+
+        assert!(result.is_err());
+        assert!(!final_exists);
+        assert!(part_exists);
+    }
+
+    #[tokio::test]
+    async fn test_list_current_term_announcements() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses")
+                .query_param("enrollment_state", "active")
+                .query_param("page", "1");
+            then.status(200)
+                .json_body(json!([{ "id": 1 }, { "id": 2 }]));
+        });
+        let announcements_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/announcements")
+                .query_param("context_codes[]", "course_1")
+                .query_param("context_codes[]", "course_2")
+                .query_param("start_date", "2024-03-01")
+                .query_param("page", "1");
+            then.status(200).json_body(json!([
+                {
+                    "id": 10,
+                    "title": "Room change",
+                    "posted_at": "2024-03-02T08:00:00Z",
+                    "context_code": "course_1"
+                },
+                {
+                    "id": 11,
+                    "title": "Midterm moved",
+                    "posted_at": "2024-03-05T08:00:00Z",
+                    "context_code": "course_2"
+                }
+            ]));
+        });
+        let client = create_test_client(&server.base_url());
+        let announcements = client
+            .list_current_term_announcements(token, Some("2024-03-01"), None)
+            .await
+            .unwrap();
+
+        announcements_mock.assert();
+        assert_eq!(
+            announcements.iter().map(|a| a.id).collect::<Vec<_>>(),
+            vec![11, 10]
+        );
+        assert!(announcements.iter().all(|a| !a.read));
+    }
+
+    #[tokio::test]
+    async fn test_submit_online_text_entry() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let submit_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/assignments/2/submissions")
+                .x_www_form_urlencoded_tuple("submission[submission_type]", "online_text_entry")
+                .x_www_form_urlencoded_tuple("submission[body]", "<p>My answer</p>");
+            then.status(201).json_body(json!({
+                "id": 3,
+                "assignment_id": 2,
+                "user_id": 10,
+                "late": false,
+                "workflow_state": "submitted",
+                "submitted_at": "2024-03-01T12:00:00Z"
+            }));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let submission = NewSubmission::TextEntry {
+            body: "<p>My answer</p>".to_owned(),
+        };
+        let result = client
+            .submit_assignment(1, 2, &submission, None, token)
+            .await
+            .unwrap();
+
+        submit_mock.assert();
+        assert_eq!(result.submitted_at.as_deref(), Some("2024-03-01T12:00:00Z"));
+    }
 }
 
 #[cfg(test)]
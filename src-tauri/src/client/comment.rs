@@ -0,0 +1,115 @@
+use super::Client;
+use crate::{
+    error::Result,
+    model::{File, NewSubmissionComment, Submission},
+    utils::{self, file::get_file_name},
+};
+
+fn comment_form(comment: &NewSubmissionComment, file_ids: &[i64]) -> Vec<(&'static str, String)> {
+    let mut form = vec![("comment[text_comment]", comment.text.clone())];
+    for file_id in file_ids {
+        form.push(("comment[file_ids][]", file_id.to_string()));
+    }
+    if let Some(media_comment_id) = &comment.media_comment_id {
+        form.push(("comment[media_comment_id]", media_comment_id.clone()));
+        form.push((
+            "comment[media_comment_type]",
+            comment
+                .media_comment_type
+                .clone()
+                .unwrap_or_else(|| "video".to_owned()),
+        ));
+    }
+    if comment.group_comment {
+        form.push(("comment[group_comment]", "true".to_owned()));
+    }
+    form
+}
+
+// Apis here are for commenting on submissions, `student_id` is "self" for your own one
+impl Client {
+    pub async fn upload_submission_comment_file(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        student_id: &str,
+        file_path: &str,
+        token: &str,
+    ) -> Result<File> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions/{}/comments/files",
+            self.base_url.read().await,
+            course_id,
+            assignment_id,
+            student_id
+        );
+        let file_name = get_file_name(file_path);
+        let params = self
+            .prepare_upload_file(&url, file_path, &file_name, &[], token)
+            .await?;
+        self.upload_submission_file_with(&params, file_path).await
+    }
+
+    // `file_ids` come from `upload_submission_comment_file` of the same submission
+    pub async fn add_submission_comment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        student_id: &str,
+        comment: &NewSubmissionComment,
+        file_ids: &[i64],
+        token: &str,
+    ) -> Result<Submission> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id,
+            student_id
+        );
+        let form = comment_form(comment, file_ids);
+        let response = self
+            .put_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_add_submission_comment() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let comment_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/v1/courses/1/assignments/2/submissions/self")
+                .x_www_form_urlencoded_tuple("comment[text_comment]", "See attached")
+                .x_www_form_urlencoded_tuple("comment[file_ids][]", "7")
+                .x_www_form_urlencoded_tuple("comment[media_comment_id]", "m-123")
+                .x_www_form_urlencoded_tuple("comment[media_comment_type]", "audio");
+            then.status(200)
+                .body(r#"{"id": 3, "assignment_id": 2, "user_id": 10, "late": false, "workflow_state": "submitted"}"#);
+        });
+
+        let client = create_test_client(&server.base_url());
+        let comment = NewSubmissionComment {
+            text: "See attached".to_owned(),
+            media_comment_id: Some("m-123".to_owned()),
+            media_comment_type: Some("audio".to_owned()),
+            ..Default::default()
+        };
+        let submission = client
+            .add_submission_comment(1, 2, "self", &comment, &[7], token)
+            .await
+            .unwrap();
+        comment_mock.assert();
+        assert_eq!(submission.user_id, 10);
+    }
+}
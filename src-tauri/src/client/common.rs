@@ -12,7 +12,7 @@ impl Client {
         &self,
         request: reqwest::Request,
     ) -> std::result::Result<Response, reqwest::Error> {
-        self.debug_store.send(&self.cli, request).await
+        self.execute_with_retry(request).await
     }
 
     pub async fn get_request_with_token<T: Serialize + ?Sized>(
@@ -20,6 +20,19 @@ pub const OAUTH_RANDOM_P1_VAL: &str = "ABCDEFGH";
 pub const OAUTH_RANDOM_P2_VAL: &str = "STUVWXYZ";
 pub const CHUNK_SIZE: u64 = 16 * 1024 * 1024;
 pub const VIDEO_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+pub const DOWNLOAD_MAX_RETRIES: u32 = 3;
+pub const REQUEST_MAX_RETRIES: u32 = 3;
+pub const REQUEST_RETRY_BASE_DELAY_MS: u64 = 500;
+pub const REQUEST_RETRY_MAX_DELAY_MS: u64 = 30_000;
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+// Canvas refills a bucket of 700 request units, concurrency shrinks below the low mark
+// and grows back above the high one
+pub const RATE_LIMIT_LOW_REMAINING: f64 = 100.0;
+pub const RATE_LIMIT_HIGH_REMAINING: f64 = 300.0;
+pub const PLAIN_TEXT_EXTENSIONS: [&str; 16] = [
+    "txt", "md", "csv", "json", "py", "c", "h", "cpp", "hpp", "cc", "java", "js", "ts", "rs", "go",
+    "m",
+];
 
 pub const JBOX_LOGIN_URL: &str =
     "https://pan.sjtu.edu.cn/user/v1/sign-in/sso-login-redirect/xpw8ou8y";
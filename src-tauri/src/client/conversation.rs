@@ -0,0 +1,215 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::Client;
+use crate::{
+    error::Result,
+    model::{Conversation, File, NewConversation},
+    utils::{self, file::get_file_name},
+};
+
+const CONVERSATION_ATTACHMENTS_FOLDER: &str = "conversation attachments";
+
+#[derive(Deserialize)]
+struct UnreadCountResponse {
+    // canvas returns the count as a string
+    unread_count: Value,
+}
+
+// Apis here are for the canvas inbox
+impl Client {
+    // `scope` is one of "unread", "starred", "archived" or "sent", all active
+    // conversations are returned when it is empty
+    pub async fn list_conversations(
+        &self,
+        scope: Option<&str>,
+        token: &str,
+    ) -> Result<Vec<Conversation>> {
+        let mut url = format!("{}/api/v1/conversations", self.base_url.read().await);
+        if let Some(scope) = scope {
+            url += &format!("?scope={scope}");
+        }
+        self.list_items(&url, token).await
+    }
+
+    // Fetching a conversation marks it as read
+    pub async fn get_conversation(
+        &self,
+        conversation_id: i64,
+        token: &str,
+    ) -> Result<Conversation> {
+        let url = format!(
+            "{}/api/v1/conversations/{}",
+            self.base_url.read().await,
+            conversation_id
+        );
+        self.get_json_with_token(&url, None::<&str>, token).await
+    }
+
+    pub async fn get_unread_conversation_count(&self, token: &str) -> Result<i64> {
+        let url = format!(
+            "{}/api/v1/conversations/unread_count",
+            self.base_url.read().await
+        );
+        let response: UnreadCountResponse =
+            self.get_json_with_token(&url, None::<&str>, token).await?;
+        let count = match response.unread_count {
+            Value::Number(count) => count.as_i64(),
+            Value::String(count) => count.parse().ok(),
+            _ => None,
+        };
+        Ok(count.unwrap_or_default())
+    }
+
+    // Attachments go to the user's "conversation attachments" folder, following the same
+    // upload flow as submission files
+    pub async fn upload_conversation_attachment(
+        &self,
+        file_path: &str,
+        token: &str,
+    ) -> Result<File> {
+        let url = format!("{}/api/v1/users/self/files", self.base_url.read().await);
+        let file_name = get_file_name(file_path);
+        let params = self
+            .prepare_upload_file(
+                &url,
+                file_path,
+                &file_name,
+                &[("parent_folder_path", CONVERSATION_ATTACHMENTS_FOLDER)],
+                token,
+            )
+            .await?;
+        self.upload_submission_file_with(&params, file_path).await
+    }
+
+    pub async fn upload_conversation_attachments(
+        &self,
+        file_paths: &[String],
+        token: &str,
+    ) -> Result<Vec<i64>> {
+        let mut attachment_ids = vec![];
+        for file_path in file_paths {
+            let file = self
+                .upload_conversation_attachment(file_path, token)
+                .await?;
+            attachment_ids.push(file.id);
+        }
+        Ok(attachment_ids)
+    }
+
+    // `attachment_ids` come from `upload_conversation_attachments`, so that the same
+    // files can be attached to several batches of recipients
+    pub async fn create_conversation(
+        &self,
+        conversation: &NewConversation,
+        attachment_ids: &[i64],
+        token: &str,
+    ) -> Result<Vec<Conversation>> {
+        let url = format!("{}/api/v1/conversations", self.base_url.read().await);
+        let mut form = vec![
+            ("subject", conversation.subject.clone()),
+            ("body", conversation.body.clone()),
+            ("group_conversation", "true".to_owned()),
+            ("bulk_message", conversation.bulk_message.to_string()),
+        ];
+        for recipient in &conversation.recipients {
+            form.push(("recipients[]", recipient.clone()));
+        }
+        for attachment_id in attachment_ids {
+            form.push(("attachment_ids[]", attachment_id.to_string()));
+        }
+        if let Some(context_code) = &conversation.context_code {
+            form.push(("context_code", context_code.clone()));
+        }
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn reply_conversation(
+        &self,
+        conversation_id: i64,
+        body: &str,
+        attachment_paths: &[String],
+        token: &str,
+    ) -> Result<Conversation> {
+        let attachment_ids = self
+            .upload_conversation_attachments(attachment_paths, token)
+            .await?;
+        let url = format!(
+            "{}/api/v1/conversations/{}/add_message",
+            self.base_url.read().await,
+            conversation_id
+        );
+        let mut form = vec![("body", body.to_owned())];
+        for attachment_id in attachment_ids {
+            form.push(("attachment_ids[]", attachment_id.to_string()));
+        }
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_unread_conversation_count() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/v1/conversations/unread_count");
+            then.status(200).json_body(json!({ "unread_count": "3" }));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let count = client.get_unread_conversation_count("token").await.unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_create_conversation() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let create_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/conversations")
+                .header("Authorization", format!("Bearer {token}"))
+                .x_www_form_urlencoded_tuple("recipients[]", "7")
+                .x_www_form_urlencoded_tuple("subject", "Lab moved")
+                .x_www_form_urlencoded_tuple("bulk_message", "true")
+                .x_www_form_urlencoded_tuple("context_code", "course_1");
+            then.status(201).json_body(json!([
+                { "id": 42, "subject": "Lab moved", "workflow_state": "read" }
+            ]));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let conversation = NewConversation {
+            recipients: vec!["7".to_owned()],
+            subject: "Lab moved".to_owned(),
+            body: "The lab is moved to Friday.".to_owned(),
+            context_code: Some("course_1".to_owned()),
+            bulk_message: true,
+            ..Default::default()
+        };
+        let conversations = client
+            .create_conversation(&conversation, &[], token)
+            .await
+            .unwrap();
+
+        create_mock.assert();
+        assert_eq!(conversations.len(), 1);
+        assert_eq!(conversations[0].id, 42);
+    }
+}
@@ -0,0 +1,158 @@
+use super::Client;
+use crate::{
+    error::Result,
+    model::{AssignmentOverride, DeadlineChange, DeadlineDates},
+    utils,
+};
+
+fn dates_form<'a>(prefix: &str, dates: &'a DeadlineDates) -> Vec<(String, &'a str)> {
+    // empty values clear the date on canvas
+    [
+        ("due_at", &dates.due_at),
+        ("unlock_at", &dates.unlock_at),
+        ("lock_at", &dates.lock_at),
+    ]
+    .into_iter()
+    .map(|(key, value)| {
+        (
+            format!("{prefix}[{key}]"),
+            value.as_deref().unwrap_or_default(),
+        )
+    })
+    .collect()
+}
+
+// Apis here are for batch deadline operations
+impl Client {
+    pub async fn update_assignment_dates(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        dates: &DeadlineDates,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let form = dates_form("assignment", dates);
+        self.put_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn update_assignment_override_dates(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        override_id: i64,
+        dates: &DeadlineDates,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/overrides/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id,
+            override_id
+        );
+        let form = dates_form("assignment_override", dates);
+        self.put_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    // Creates the override described by a planned `CreateOverride` change
+    pub async fn create_assignment_override(
+        &self,
+        course_id: i64,
+        change: &DeadlineChange,
+        token: &str,
+    ) -> Result<AssignmentOverride> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/overrides",
+            self.base_url.read().await,
+            course_id,
+            change.assignment_id
+        );
+        let mut form: Vec<(String, String)> = dates_form("assignment_override", &change.after)
+            .into_iter()
+            .map(|(key, value)| (key, value.to_owned()))
+            .collect();
+        if !change.student_ids.is_empty() {
+            form.push((
+                "assignment_override[title]".to_owned(),
+                change.title.clone(),
+            ));
+            form.extend(change.student_ids.iter().map(|student_id| {
+                (
+                    "assignment_override[student_ids][]".to_owned(),
+                    student_id.to_string(),
+                )
+            }));
+        } else if change.group_id != 0 {
+            form.push((
+                "assignment_override[group_id]".to_owned(),
+                change.group_id.to_string(),
+            ));
+        } else {
+            form.push((
+                "assignment_override[course_section_id]".to_owned(),
+                change.course_section_id.to_string(),
+            ));
+        }
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use crate::model::DeadlineChangeKind;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_create_assignment_override() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let override_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/assignments/2/overrides")
+                .x_www_form_urlencoded_tuple("assignment_override[due_at]", "2024-03-08T15:59:59Z")
+                .x_www_form_urlencoded_tuple("assignment_override[unlock_at]", "")
+                .x_www_form_urlencoded_tuple("assignment_override[student_ids][]", "10")
+                .x_www_form_urlencoded_tuple("assignment_override[title]", "Alice");
+            then.status(200)
+                .body(r#"{"id": 5, "assignment_id": 2, "student_ids": [10]}"#);
+        });
+
+        let client = create_test_client(&server.base_url());
+        let change = DeadlineChange {
+            kind: DeadlineChangeKind::CreateOverride,
+            assignment_id: 2,
+            title: "Alice".to_owned(),
+            student_ids: vec![10],
+            after: DeadlineDates {
+                due_at: Some("2024-03-08T15:59:59Z".to_owned()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let created = client
+            .create_assignment_override(1, &change, token)
+            .await
+            .unwrap();
+        override_mock.assert();
+        assert_eq!(created.id, 5);
+    }
+}
@@ -0,0 +1,231 @@
+use reqwest::multipart;
+use serde::de::DeserializeOwned;
+
+use super::Client;
+use crate::{
+    error::Result,
+    model::{DiscussionEntry, DiscussionTopic, DiscussionTopicInput},
+    utils::{self, file::get_file_name},
+};
+
+// Canvas takes a single `attachment` part along with the message
+async fn with_attachment(
+    form: multipart::Form,
+    attachment: Option<&str>,
+) -> Result<multipart::Form> {
+    let Some(file_path) = attachment else {
+        return Ok(form);
+    };
+    let bytes = tokio::fs::read(file_path).await?;
+    let part = multipart::Part::bytes(bytes).file_name(get_file_name(file_path));
+    Ok(form.part("attachment", part))
+}
+
+// Apis here are for taking part in discussions
+impl Client {
+    async fn post_multipart_with_token<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        form: multipart::Form,
+        token: &str,
+    ) -> Result<T> {
+        let response = self
+            .cli
+            .post(url)
+            .header("Authorization", format!("Bearer {token}"))
+            .multipart(form)
+            .send()
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    pub async fn create_discussion_topic(
+        &self,
+        course_id: i64,
+        input: &DiscussionTopicInput,
+        attachment: Option<&str>,
+        token: &str,
+    ) -> Result<DiscussionTopic> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics",
+            self.base_url.read().await,
+            course_id
+        );
+        let discussion_type = if input.threaded {
+            "threaded"
+        } else {
+            "side_comment"
+        };
+        let form = multipart::Form::new()
+            .text("title", input.title.clone())
+            .text("message", input.message.clone())
+            .text("discussion_type", discussion_type)
+            .text("published", input.published.to_string());
+        let form = with_attachment(form, attachment).await?;
+        self.post_multipart_with_token(&url, form, token).await
+    }
+
+    pub async fn post_discussion_entry(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        message: &str,
+        attachment: Option<&str>,
+        token: &str,
+    ) -> Result<DiscussionEntry> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics/{}/entries",
+            self.base_url.read().await,
+            course_id,
+            topic_id
+        );
+        let form = multipart::Form::new().text("message", message.to_owned());
+        let form = with_attachment(form, attachment).await?;
+        self.post_multipart_with_token(&url, form, token).await
+    }
+
+    // `entry_id` may be a top level entry or any `Reply` of a threaded topic
+    pub async fn reply_discussion_entry(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: i64,
+        message: &str,
+        attachment: Option<&str>,
+        token: &str,
+    ) -> Result<DiscussionEntry> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics/{}/entries/{}/replies",
+            self.base_url.read().await,
+            course_id,
+            topic_id,
+            entry_id
+        );
+        let form = multipart::Form::new().text("message", message.to_owned());
+        let form = with_attachment(form, attachment).await?;
+        self.post_multipart_with_token(&url, form, token).await
+    }
+
+    // Only works on topics with `allow_rating` enabled
+    pub async fn rate_discussion_entry(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: i64,
+        liked: bool,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics/{}/entries/{}/rating",
+            self.base_url.read().await,
+            course_id,
+            topic_id,
+            entry_id
+        );
+        let form = [("rating", if liked { "1" } else { "0" })];
+        self.post_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn mark_discussion_entry_read(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        entry_id: i64,
+        read: bool,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics/{}/entries/{}/read",
+            self.base_url.read().await,
+            course_id,
+            topic_id,
+            entry_id
+        );
+        let request = if read {
+            self.cli.put(url)
+        } else {
+            self.cli.delete(url)
+        };
+        request
+            .header("Authorization", format!("Bearer {token}"))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    // Marks the topic and all of its entries as read
+    pub async fn mark_discussion_topic_read(
+        &self,
+        course_id: i64,
+        topic_id: i64,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/discussion_topics/{}/read_all",
+            self.base_url.read().await,
+            course_id,
+            topic_id
+        );
+        self.cli
+            .put(url)
+            .header("Authorization", format!("Bearer {token}"))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_reply_discussion_entry() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let reply_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/discussion_topics/2/entries/3/replies")
+                .header("Authorization", format!("Bearer {token}"))
+                .body_contains("Thanks!");
+            then.status(201)
+                .body(r#"{"id": 4, "user_id": 10, "parent_id": 3, "message": "<p>Thanks!</p>"}"#);
+        });
+
+        let client = create_test_client(&server.base_url());
+        let entry = client
+            .reply_discussion_entry(1, 2, 3, "Thanks!", None, token)
+            .await
+            .unwrap();
+        reply_mock.assert();
+        assert_eq!(entry.id, 4);
+        assert_eq!(entry.parent_id, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_rate_discussion_entry() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let rating_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/discussion_topics/2/entries/3/rating")
+                .x_www_form_urlencoded_tuple("rating", "1");
+            then.status(204);
+        });
+
+        let client = create_test_client(&server.base_url());
+        client
+            .rate_discussion_entry(1, 2, 3, true, token)
+            .await
+            .unwrap();
+        rating_mock.assert();
+    }
+}
@@ -0,0 +1,110 @@
+use super::Client;
+use crate::{
+    error::Result,
+    model::{Group, GroupCategory, GroupMembership, Submission, User},
+};
+
+// Apis here are for canvas groups
+impl Client {
+    pub async fn list_course_groups(&self, course_id: i64, token: &str) -> Result<Vec<Group>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/groups",
+            self.base_url.read().await,
+            course_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn list_group_categories(
+        &self,
+        course_id: i64,
+        token: &str,
+    ) -> Result<Vec<GroupCategory>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/group_categories",
+            self.base_url.read().await,
+            course_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn list_group_category_groups(
+        &self,
+        group_category_id: i64,
+        token: &str,
+    ) -> Result<Vec<Group>> {
+        let url = format!(
+            "{}/api/v1/group_categories/{}/groups",
+            self.base_url.read().await,
+            group_category_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn list_group_memberships(
+        &self,
+        group_id: i64,
+        token: &str,
+    ) -> Result<Vec<GroupMembership>> {
+        let url = format!(
+            "{}/api/v1/groups/{}/memberships",
+            self.base_url.read().await,
+            group_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn list_group_users(&self, group_id: i64, token: &str) -> Result<Vec<User>> {
+        let url = format!(
+            "{}/api/v1/groups/{}/users",
+            self.base_url.read().await,
+            group_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    // One submission per group for group assignments, with `group` filled in. Students
+    // outside of any group keep their own submission.
+    pub async fn list_group_assignment_submissions(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Vec<Submission>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions?grouped=true&include[]=group&include[]=submission_comments",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        self.list_items(&url, token).await
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_update_grade_for_group() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let grade_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/assignments/2/submissions/update_grades")
+                .x_www_form_urlencoded_tuple("grade_data[10][posted_grade]", "95")
+                .x_www_form_urlencoded_tuple("grade_data[11][posted_grade]", "95")
+                .x_www_form_urlencoded_tuple("grade_data[10][text_comment]", "Nice work")
+                .x_www_form_urlencoded_tuple("grade_data[10][group_comment]", "true");
+            then.status(200).body("{}");
+        });
+
+        let client = create_test_client(&server.base_url());
+        client
+            .update_grade(1, 2, &[10, 11], "95", Some("Nice work"), token)
+            .await
+            .unwrap();
+        grade_mock.assert();
+    }
+}
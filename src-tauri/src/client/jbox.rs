@@ -189,6 +189,7 @@ impl Client {
             uuid: file.uuid.clone(),
             processed: 0,
             total: file_size as u64,
+            ..Default::default()
         };
         for part_number in 1..=chunk_count {
             let start = (part_number - 1) * JBOX_UPLOAD_CHUNK_SIZE;
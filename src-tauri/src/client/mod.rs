@@ -5,13 +5,27 @@ use tokio::sync::RwLock;
 
 pub mod ai;
 pub mod annual;
+pub mod archive;
+pub mod assignment;
 pub mod basic;
+pub mod comment;
 mod common;
 pub mod constants;
+pub mod conversation;
+pub mod deadline;
 mod debug;
+pub mod discussion;
 mod file_parser;
+pub mod group;
 pub mod jbox;
 mod llm;
+pub mod page;
+pub mod pagination;
+pub mod quiz;
+pub mod retry;
+pub mod rubric;
+pub mod sync;
+pub mod upload;
 pub mod video;
 
 pub struct Client {
@@ -22,4 +36,11 @@ pub struct Client {
     llm_cli: Box<dyn LLMClient>,
     file_parser: file_parser::GenericFileParser,
     debug_store: debug::NetworkDebugStore,
+    throttle: retry::RequestThrottle,
+    retry_policy: retry::RetryPolicy,
+}
+
+#[cfg(test)]
+pub(crate) fn create_test_client(mock_server_url: &str) -> Client {
+    Client::new_without_proxy(mock_server_url, "", "", "", None)
 }
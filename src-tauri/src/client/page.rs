@@ -0,0 +1,164 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use reqwest::header::CONTENT_TYPE;
+
+use super::Client;
+use crate::{
+    error::Result,
+    model::Page,
+    utils::html::{extract_image_urls, html_to_markdown},
+};
+
+const PAGE_IMAGES_DIR_NAME: &str = "images";
+
+// Apis here are for canvas pages (course wiki)
+impl Client {
+    pub async fn list_pages(&self, course_id: i64, token: &str) -> Result<Vec<Page>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/pages",
+            self.base_url.read().await,
+            course_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn get_page(&self, course_id: i64, page_url: &str, token: &str) -> Result<Page> {
+        let url = format!(
+            "{}/api/v1/courses/{}/pages/{}",
+            self.base_url.read().await,
+            course_id,
+            urlencoding::encode(page_url)
+        );
+        self.get_json_with_token(&url, None::<&str>, token).await
+    }
+
+    // Write `<page url>.md` into `save_dir`. Images hosted on canvas are downloaded into
+    // `save_dir/images` and linked relatively, external images are left untouched.
+    pub async fn export_page_markdown(
+        &self,
+        page: &Page,
+        save_dir: &Path,
+        token: &str,
+    ) -> Result<PathBuf> {
+        let mut markdown = get_page_markdown(page);
+        let base_url = self.base_url.read().await.clone();
+        let images_dir = save_dir.join(PAGE_IMAGES_DIR_NAME);
+        let image_urls = extract_image_urls(page.body.as_deref().unwrap_or_default());
+        for (i, src) in image_urls.iter().enumerate() {
+            let url = if src.starts_with('/') {
+                format!("{base_url}{src}")
+            } else if src.starts_with(&base_url) {
+                src.clone()
+            } else {
+                continue;
+            };
+            let response = self
+                .get_request_with_token(&url, None::<&str>, token)
+                .await?;
+            if !response.status().is_success() {
+                tracing::warn!(
+                    "Failed to download page image {}: {}",
+                    url,
+                    response.status()
+                );
+                continue;
+            }
+            let extension = get_image_extension(&response);
+            let bytes = response.bytes().await?;
+            let file_name = format!("{}_{}.{}", page.url, i + 1, extension);
+            fs::create_dir_all(&images_dir)?;
+            fs::write(images_dir.join(&file_name), &bytes)?;
+            markdown = markdown.replace(
+                &format!("]({src})"),
+                &format!("]({PAGE_IMAGES_DIR_NAME}/{file_name})"),
+            );
+        }
+
+        fs::create_dir_all(save_dir)?;
+        let path = save_dir.join(format!("{}.md", page.url));
+        fs::write(&path, markdown)?;
+        tracing::info!("Page {} exported to {}", page.url, path.display());
+        Ok(path)
+    }
+}
+
+pub fn get_page_markdown(page: &Page) -> String {
+    let body = html_to_markdown(page.body.as_deref().unwrap_or_default());
+    format!("# {}\n\n{}\n", page.title, body)
+}
+
+fn get_image_extension(response: &reqwest::Response) -> &'static str {
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    match content_type {
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/svg+xml" => "svg",
+        "image/webp" => "webp",
+        _ => "png",
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_export_page_markdown() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let body = r#"<p>See the figure:</p><p><img src="/courses/1/files/3/preview" alt="figure"><img src="https://example.com/logo.png" alt="logo"></p>"#;
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/pages/week-1")
+                .header("Authorization", format!("Bearer {token}"));
+            then.status(200).json_body(json!({
+                "page_id": 7,
+                "url": "week-1",
+                "title": "Week 1",
+                "body": body,
+            }));
+        });
+        let image_mock = server.mock(|when, then| {
+            when.method(GET).path("/courses/1/files/3/preview");
+            then.status(200)
+                .header("Content-Type", "image/jpeg")
+                .body("jpeg-bytes");
+        });
+
+        let client = Client::new_without_proxy(
+            server.base_url(),
+            "".to_owned(),
+            "".to_owned(),
+            "".to_owned(),
+            None,
+        );
+        let page = client.get_page(1, "week-1", token).await.unwrap();
+        let save_dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let path = client
+            .export_page_markdown(&page, &save_dir, token)
+            .await
+            .unwrap();
+
+        let markdown = fs::read_to_string(&path).unwrap();
+        let image = fs::read_to_string(save_dir.join("images/week-1_1.jpg")).unwrap();
+        _ = fs::remove_dir_all(&save_dir);
+
+        image_mock.assert();
+        assert_eq!(path, save_dir.join("week-1.md"));
+        assert_eq!(image, "jpeg-bytes");
+        assert_eq!(
+            markdown,
+            "# Week 1\n\nSee the figure:\n\n![figure](images/week-1_1.jpg)![logo](https://example.com/logo.png)\n"
+        );
+    }
+}
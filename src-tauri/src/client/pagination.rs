@@ -0,0 +1,221 @@
+use reqwest::header::{HeaderMap, LINK};
+use serde::de::DeserializeOwned;
+
+use super::Client;
+use crate::{error::Result, utils};
+
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListOptions {
+    pub per_page: u64,
+    // Stops requesting further pages once this many items have been collected
+    pub max_items: Option<usize>,
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            per_page: DEFAULT_PAGE_SIZE,
+            max_items: None,
+        }
+    }
+}
+
+impl ListOptions {
+    pub fn with_max_items(max_items: usize) -> Self {
+        Self {
+            max_items: Some(max_items),
+            ..Default::default()
+        }
+    }
+}
+
+// Canvas returns e.g. `<https://canvas/api/v1/courses?page=2&per_page=100>; rel="next"`.
+// The url may carry a numeric page or a bookmark cursor, so it is followed as is.
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let mut parts = link.split(';');
+            let url = parts.next()?.trim();
+            let is_next = parts.any(|param| {
+                param
+                    .trim()
+                    .replace(' ', "")
+                    .eq_ignore_ascii_case("rel=\"next\"")
+            });
+            is_next.then(|| url.trim_start_matches('<').trim_end_matches('>').to_owned())
+        })
+}
+
+impl Client {
+    // Calls `on_page` with every page as soon as it arrives, following the `Link` headers.
+    // Callers that show partial results, e.g. `list_folder_files_by_page`, build on this.
+    pub async fn for_each_page<T, F>(
+        &self,
+        url: &str,
+        token: &str,
+        options: &ListOptions,
+        mut on_page: F,
+    ) -> Result<()>
+    where
+        T: DeserializeOwned,
+        F: FnMut(Vec<T>) -> Result<()>,
+    {
+        let per_page = match options.max_items {
+            Some(max_items) => options.per_page.min(max_items.max(1) as u64),
+            None => options.per_page,
+        };
+        let mut remaining = options.max_items;
+        let query = [("page", "1".to_owned()), ("per_page", per_page.to_string())];
+        let mut response = self
+            .get_request_with_token(url, Some(&query), token)
+            .await?;
+        loop {
+            let page = response.error_for_status()?;
+            let next_url = next_page_url(page.headers());
+            let bytes = page.bytes().await?;
+            self.debug_store.capture_response_body(&bytes).await;
+            let mut items: Vec<T> = utils::json::parse_json(&bytes)?;
+            if let Some(remaining) = remaining.as_mut() {
+                items.truncate(*remaining);
+                *remaining -= items.len();
+            }
+            on_page(items)?;
+
+            let Some(next_url) = next_url.filter(|_| remaining != Some(0)) else {
+                break;
+            };
+            response = self
+                .get_request_with_token(&next_url, None::<&str>, token)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn list_items_with_options<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        token: &str,
+        options: &ListOptions,
+    ) -> Result<Vec<T>> {
+        let mut all_items = vec![];
+        self.for_each_page(url, token, options, |items| {
+            all_items.extend(items);
+            Ok(())
+        })
+        .await?;
+        Ok(all_items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_next_page_url() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                "<https://canvas/api/v1/files?page=1&per_page=10>; rel=\"current\",<https://canvas/api/v1/files?page=bookmark:WzFd&per_page=10>; rel=\"next\",<https://canvas/api/v1/files?page=1&per_page=10>; rel=\"first\"",
+            ),
+        );
+        assert_eq!(
+            next_page_url(&headers),
+            Some("https://canvas/api/v1/files?page=bookmark:WzFd&per_page=10".to_owned())
+        );
+
+        headers.insert(
+            LINK,
+            HeaderValue::from_static("<https://canvas/api/v1/files?page=3>; rel=\"last\""),
+        );
+        assert_eq!(next_page_url(&headers), None);
+        assert_eq!(next_page_url(&HeaderMap::new()), None);
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_for_each_page_follows_link_header() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let next_url = server.url("/api/v1/courses/1/users?page=bookmark:WzJd&per_page=2");
+        let first_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/users")
+                .query_param("page", "1")
+                .query_param("per_page", "2");
+            then.status(200)
+                .header("Link", format!("<{next_url}>; rel=\"next\""))
+                .json_body(json!([1, 2]));
+        });
+        let next_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/users")
+                .query_param("page", "bookmark:WzJd");
+            then.status(200).json_body(json!([3]));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let url = server.url("/api/v1/courses/1/users");
+        let options = ListOptions {
+            per_page: 2,
+            max_items: None,
+        };
+        let mut pages = vec![];
+        client
+            .for_each_page(&url, token, &options, |items: Vec<i64>| {
+                pages.push(items);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        first_mock.assert();
+        next_mock.assert();
+        assert_eq!(pages, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[tokio::test]
+    async fn test_list_items_with_max_items() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let next_url = server.url("/api/v1/courses/1/files?page=2&per_page=3");
+        let first_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/files")
+                .query_param("per_page", "3");
+            then.status(200)
+                .header("Link", format!("<{next_url}>; rel=\"next\""))
+                .json_body(json!([1, 2, 3]));
+        });
+        let next_mock = server.mock(|when, then| {
+            when.method(GET).query_param("page", "2");
+            then.status(200).json_body(json!([4, 5, 6]));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let url = server.url("/api/v1/courses/1/files");
+        let items: Vec<i64> = client
+            .list_items_with_options(&url, token, &ListOptions::with_max_items(3))
+            .await
+            .unwrap();
+
+        first_mock.assert();
+        next_mock.assert_hits(0);
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::DateTime;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use super::Client;
+use crate::{
+    error::Result,
+    model::{
+        Assignment, CalendarEvent, Quiz, QuizAttempt, QuizQuestion, QuizSubmission,
+        QuizSubmissionsResponse,
+    },
+    utils,
+};
+
+#[derive(Deserialize)]
+struct QuizSubmissionQuestionsResponse {
+    #[serde(default)]
+    quiz_submission_questions: Vec<QuizQuestion>,
+}
+
+#[derive(Deserialize)]
+struct SubmissionWithHistory {
+    #[serde(default)]
+    submission_history: Vec<QuizAttempt>,
+}
+
+// Apis here are for canvas classic quizzes
+impl Client {
+    pub async fn list_quizzes(&self, course_id: i64, token: &str) -> Result<Vec<Quiz>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/quizzes",
+            self.base_url.read().await,
+            course_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    pub async fn get_quiz(&self, course_id: i64, quiz_id: i64, token: &str) -> Result<Quiz> {
+        let url = format!(
+            "{}/api/v1/courses/{}/quizzes/{}",
+            self.base_url.read().await,
+            course_id,
+            quiz_id
+        );
+        self.get_json_with_token(&url, None::<&str>, token).await
+    }
+
+    // Students only get their own submissions back
+    pub async fn list_my_quiz_submissions(
+        &self,
+        course_id: i64,
+        quiz_id: i64,
+        token: &str,
+    ) -> Result<Vec<QuizSubmission>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/quizzes/{}/submissions",
+            self.base_url.read().await,
+            course_id,
+            quiz_id
+        );
+        let response: QuizSubmissionsResponse =
+            self.get_json_with_token(&url, None::<&str>, token).await?;
+        Ok(response.quiz_submissions)
+    }
+
+    // Canvas keeps one quiz submission per user, so earlier attempts are read from the
+    // history of the backing assignment submission
+    pub async fn list_quiz_attempts(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Vec<QuizAttempt>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions/self?include[]=submission_history",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        let submission: SubmissionWithHistory =
+            self.get_json_with_token(&url, None::<&str>, token).await?;
+        Ok(submission
+            .submission_history
+            .into_iter()
+            .filter(|attempt| attempt.attempt.is_some())
+            .collect())
+    }
+
+    // Returns no questions when canvas does not allow the current user to see them,
+    // e.g. when the quiz hides results from students
+    pub async fn list_quiz_questions(
+        &self,
+        course_id: i64,
+        quiz_id: i64,
+        quiz_submission: Option<&QuizSubmission>,
+        token: &str,
+    ) -> Result<Vec<QuizQuestion>> {
+        let base_url = self.base_url.read().await.clone();
+        let mut url = format!("{base_url}/api/v1/courses/{course_id}/quizzes/{quiz_id}/questions");
+        if let Some(QuizSubmission {
+            id,
+            attempt: Some(attempt),
+            ..
+        }) = quiz_submission
+        {
+            url += &format!("?quiz_submission_id={id}&quiz_submission_attempt={attempt}");
+        }
+        let response = self
+            .get_request_with_token(&url, None::<&str>, token)
+            .await?;
+        if matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return self
+                .list_quiz_submission_questions(quiz_submission, token)
+                .await;
+        }
+        let bytes = response.error_for_status()?.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    // Questions of an attempt in progress are always visible to its owner
+    async fn list_quiz_submission_questions(
+        &self,
+        quiz_submission: Option<&QuizSubmission>,
+        token: &str,
+    ) -> Result<Vec<QuizQuestion>> {
+        let Some(quiz_submission) = quiz_submission else {
+            return Ok(vec![]);
+        };
+        let url = format!(
+            "{}/api/v1/quiz_submissions/{}/questions",
+            self.base_url.read().await,
+            quiz_submission.id
+        );
+        let response = self
+            .get_request_with_token(&url, None::<&str>, token)
+            .await?;
+        if !response.status().is_success() {
+            return Ok(vec![]);
+        }
+        let bytes = response.bytes().await?;
+        let response: QuizSubmissionQuestionsResponse = utils::json::parse_json(&bytes)?;
+        Ok(response.quiz_submission_questions)
+    }
+}
+
+fn is_in_range(time: &str, start_date: &str, end_date: &str) -> bool {
+    let (Ok(time), Ok(start), Ok(end)) = (
+        DateTime::parse_from_rfc3339(time),
+        DateTime::parse_from_rfc3339(start_date),
+        DateTime::parse_from_rfc3339(end_date),
+    ) else {
+        return false;
+    };
+    start <= time && time <= end
+}
+
+// Graded quizzes already appear in the calendar as assignments, so only ungraded
+// quizzes (practice quizzes and surveys) with a due date in range are turned into events
+pub fn get_quiz_calendar_events(
+    quizzes: &[Quiz],
+    course_id: i64,
+    context_name: &str,
+    start_date: &str,
+    end_date: &str,
+) -> Vec<CalendarEvent> {
+    quizzes
+        .iter()
+        .filter(|quiz| quiz.published && quiz.assignment_id.is_none())
+        .filter(|quiz| {
+            quiz.due_at
+                .as_ref()
+                .is_some_and(|due_at| is_in_range(due_at, start_date, end_date))
+        })
+        .map(|quiz| CalendarEvent {
+            title: quiz.title.clone(),
+            workflow_state: "published".to_owned(),
+            id: format!("quiz_{}", quiz.id),
+            type_field: "quiz".to_owned(),
+            assignment: Assignment {
+                id: quiz.id,
+                due_at: quiz.due_at.clone(),
+                unlock_at: quiz.unlock_at.clone(),
+                lock_at: quiz.lock_at.clone(),
+                points_possible: quiz.points_possible,
+                course_id,
+                name: quiz.title.clone(),
+                html_url: quiz.html_url.clone(),
+                submission_types: vec!["online_quiz".to_owned()],
+                published: quiz.published,
+                all_dates: quiz.all_dates.clone(),
+                quiz_id: Some(quiz.id),
+                ..Default::default()
+            },
+            html_url: quiz.html_url.clone(),
+            context_code: format!("course_{course_id}"),
+            context_name: context_name.to_owned(),
+            end_at: quiz.due_at.clone(),
+            start_at: quiz.due_at.clone(),
+            url: quiz.html_url.clone(),
+            important_dates: false,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quiz(id: i64, assignment_id: Option<i64>, due_at: Option<&str>) -> Quiz {
+        Quiz {
+            id,
+            title: format!("Quiz {id}"),
+            assignment_id,
+            due_at: due_at.map(ToOwned::to_owned),
+            published: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_quiz_calendar_events() {
+        let quizzes = vec![
+            quiz(1, None, Some("2024-03-10T15:59:00Z")),
+            // graded quizzes come from the calendar api
+            quiz(2, Some(20), Some("2024-03-10T15:59:00Z")),
+            quiz(3, None, None),
+            quiz(4, None, Some("2024-04-10T15:59:00Z")),
+        ];
+        let events = get_quiz_calendar_events(
+            &quizzes,
+            7,
+            "Calculus",
+            "2024-02-29T16:00:00.000Z",
+            "2024-03-31T15:59:59.999Z",
+        );
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, "quiz_1");
+        assert_eq!(events[0].type_field, "quiz");
+        assert_eq!(events[0].context_code, "course_7");
+        assert_eq!(events[0].assignment.quiz_id, Some(1));
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_list_quiz_questions_falls_back_to_submission_questions() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/courses/1/quizzes/2/questions");
+            then.status(401)
+                .json_body(json!({ "status": "unauthorized" }));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/quiz_submissions/3/questions");
+            then.status(200).json_body(json!({
+                "quiz_submission_questions": [{
+                    "id": 5,
+                    "quiz_id": 2,
+                    "question_type": "multiple_choice_question",
+                    "question_text": "<p>1 + 1 = ?</p>",
+                    "answers": [{ "id": 6, "text": "2" }, { "id": 7, "text": "3" }]
+                }]
+            }));
+        });
+
+        let client = create_test_client(&server.base_url());
+        let quiz_submission = QuizSubmission {
+            id: 3,
+            quiz_id: 2,
+            attempt: Some(1),
+            ..Default::default()
+        };
+        let questions = client
+            .list_quiz_questions(1, 2, Some(&quiz_submission), token)
+            .await
+            .unwrap();
+
+        assert_eq!(questions.len(), 1);
+        assert_eq!(
+            questions[0]
+                .answers
+                .iter()
+                .map(|answer| answer.text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["2", "3"]
+        );
+        assert!(client
+            .list_quiz_questions(1, 2, None, token)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Method, Request, Response, StatusCode,
+};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use uuid::Uuid;
+
+use super::{
+    constants::{
+        RATE_LIMIT_HIGH_REMAINING, RATE_LIMIT_LOW_REMAINING, REQUEST_MAX_RETRIES,
+        REQUEST_RETRY_BASE_DELAY_MS, REQUEST_RETRY_MAX_DELAY_MS,
+    },
+    Client,
+};
+
+const RATE_LIMIT_REMAINING_HEADER: &str = "x-rate-limit-remaining";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: REQUEST_MAX_RETRIES,
+            base_delay: Duration::from_millis(REQUEST_RETRY_BASE_DELAY_MS),
+            max_delay: Duration::from_millis(REQUEST_RETRY_MAX_DELAY_MS),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // Exponential backoff, jittered within its upper half so that parallel requests spread out
+    pub(super) fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_delay);
+        let jitter = (Uuid::new_v4().as_u128() % 1000) as f64 / 1000.0;
+        delay.mul_f64(0.5 + jitter / 2.0)
+    }
+}
+
+// Bounds the requests in flight. Every throttled or low quota response takes a permit out
+// of circulation, and every response with plenty of quota left puts one back.
+#[derive(Debug)]
+pub struct RequestThrottle {
+    semaphore: Semaphore,
+    limit: AtomicUsize,
+    max_limit: usize,
+}
+
+impl RequestThrottle {
+    pub fn new(max_limit: usize) -> Self {
+        Self {
+            semaphore: Semaphore::new(max_limit),
+            limit: AtomicUsize::new(max_limit),
+            max_limit,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.semaphore
+            .acquire()
+            .await
+            .expect("request throttle is never closed")
+    }
+
+    fn release(&self, permit: SemaphorePermit<'_>, remaining: Option<f64>, throttled: bool) {
+        let limit = self.limit();
+        let low =
+            throttled || remaining.is_some_and(|remaining| remaining < RATE_LIMIT_LOW_REMAINING);
+        let high = remaining.is_some_and(|remaining| remaining > RATE_LIMIT_HIGH_REMAINING);
+        if low && limit > 1 {
+            if self
+                .limit
+                .compare_exchange(limit, limit - 1, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                permit.forget();
+                tracing::warn!(
+                    "Canvas rate limit is low, concurrency reduced to {}",
+                    limit - 1
+                );
+            }
+        } else if high
+            && limit < self.max_limit
+            && self
+                .limit
+                .compare_exchange(limit, limit + 1, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            self.semaphore.add_permits(1);
+        }
+    }
+}
+
+fn rate_limit_remaining(headers: &HeaderMap) -> Option<f64> {
+    headers
+        .get(RATE_LIMIT_REMAINING_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds: u64 = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+// Canvas answers throttled requests with 403 and an exhausted quota, other 403s are real
+fn is_throttled(response: &Response) -> bool {
+    match response.status() {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::FORBIDDEN => {
+            rate_limit_remaining(response.headers()).is_some_and(|remaining| remaining < 1.0)
+        }
+        _ => false,
+    }
+}
+
+// Canvas treats some PUTs as creations, e.g. every PUT to a submission with a comment
+// posts the comment again, so only reads are safe to repeat after a server error
+fn is_safe(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD)
+}
+
+fn should_retry(method: &Method, result: &std::result::Result<Response, reqwest::Error>) -> bool {
+    match result {
+        // a throttled request was rejected before canvas processed it
+        Ok(response) if is_throttled(response) => true,
+        _ if !is_safe(method) => false,
+        Ok(response) => matches!(
+            response.status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        ),
+        Err(error) => error.is_timeout() || error.is_connect(),
+    }
+}
+
+impl Client {
+    #[cfg(test)]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    // Reads are retried on throttling and server errors, writes only when throttled.
+    // Requests whose body cannot be cloned, e.g. streamed uploads, are sent once.
+    pub(super) async fn execute_with_retry(
+        &self,
+        mut request: Request,
+    ) -> std::result::Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            let retry_request = if attempt < self.retry_policy.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+            let method = request.method().clone();
+            let url = request.url().clone();
+
+            let permit = self.throttle.acquire().await;
+            let result = self.debug_store.send(&self.cli, request).await;
+            match &result {
+                Ok(response) => self.throttle.release(
+                    permit,
+                    rate_limit_remaining(response.headers()),
+                    is_throttled(response),
+                ),
+                Err(_) => drop(permit),
+            }
+
+            let Some(next_request) = retry_request.filter(|_| should_retry(&method, &result))
+            else {
+                return result;
+            };
+            let delay = result
+                .as_ref()
+                .ok()
+                .and_then(|response| retry_after(response.headers()))
+                .map(|delay| delay.min(self.retry_policy.max_delay))
+                .unwrap_or_else(|| self.retry_policy.backoff(attempt));
+            tracing::warn!(
+                "Retrying {} {} in {:?}, attempt {}",
+                method,
+                url,
+                delay,
+                attempt + 1
+            );
+            tokio::time::sleep(delay).await;
+            request = next_request;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_throttle_adapts_limit() {
+        let throttle = RequestThrottle::new(2);
+        throttle.release(throttle.acquire().await, Some(0.0), true);
+        assert_eq!(throttle.limit(), 1);
+        // never drops below a single request
+        throttle.release(throttle.acquire().await, Some(50.0), false);
+        assert_eq!(throttle.limit(), 1);
+        throttle.release(throttle.acquire().await, Some(600.0), false);
+        throttle.release(throttle.acquire().await, Some(600.0), false);
+        assert_eq!(throttle.limit(), 2);
+        assert_eq!(throttle.semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_backoff_is_bounded() {
+        let policy = RetryPolicy::default();
+        for attempt in 0..40 {
+            let delay = policy.backoff(attempt);
+            assert!(delay <= policy.max_delay);
+            assert!(delay >= policy.base_delay / 2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    fn create_test_client(mock_server_url: &str) -> Client {
+        crate::client::create_test_client(mock_server_url).with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_retry_throttled_get() {
+        let server = MockServer::start();
+        let throttled_mock = server.mock(|when, then| {
+            when.method(GET).path("/api/v1/courses");
+            then.status(403)
+                .header("X-Rate-Limit-Remaining", "0.0")
+                .body("403 Forbidden (Rate Limit Exceeded)");
+        });
+
+        let client = create_test_client(&server.base_url());
+        let response = client
+            .get_request_with_token(&server.url("/api/v1/courses"), None::<&str>, "token")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        throttled_mock.assert_hits(3);
+        assert!(client.throttle.limit() < crate::client::constants::MAX_CONCURRENT_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_forbidden_or_post() {
+        let server = MockServer::start();
+        let forbidden_mock = server.mock(|when, then| {
+            when.method(GET).path("/api/v1/courses/1");
+            then.status(403).header("X-Rate-Limit-Remaining", "650.5");
+        });
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/courses/1/discussion_topics");
+            then.status(503);
+        });
+
+        let client = create_test_client(&server.base_url());
+        client
+            .get_request_with_token(&server.url("/api/v1/courses/1"), None::<&str>, "token")
+            .await
+            .unwrap();
+        client
+            .post_form_with_token(
+                &server.url("/api/v1/courses/1/discussion_topics"),
+                None::<&str>,
+                &[("title", "Hello")],
+                "token",
+            )
+            .await
+            .unwrap();
+        forbidden_mock.assert_hits(1);
+        unavailable_mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_server_error_get() {
+        let server = MockServer::start();
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(GET).path("/api/v1/courses/1");
+            then.status(502);
+        });
+
+        let client = create_test_client(&server.base_url());
+        let response = client
+            .get_request_with_token(&server.url("/api/v1/courses/1"), None::<&str>, "token")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        unavailable_mock.assert_hits(3);
+    }
+
+    #[tokio::test]
+    async fn test_put_retried_only_when_throttled() {
+        let server = MockServer::start();
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/v1/courses/1/assignments/2/submissions/3")
+                .x_www_form_urlencoded_tuple("comment[text_comment]", "Nice");
+            then.status(502);
+        });
+        let throttled_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/v1/courses/1/discussion_topics/2/read_all")
+                .x_www_form_urlencoded_tuple("read", "true");
+            then.status(429);
+        });
+
+        let client = create_test_client(&server.base_url());
+        let response = client
+            .put_form_with_token(
+                &server.url("/api/v1/courses/1/assignments/2/submissions/3"),
+                None::<&str>,
+                &[("comment[text_comment]", "Nice")],
+                "token",
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        let response = client
+            .put_form_with_token(
+                &server.url("/api/v1/courses/1/discussion_topics/2/read_all"),
+                None::<&str>,
+                &[("read", "true")],
+                "token",
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        unavailable_mock.assert_hits(1);
+        throttled_mock.assert_hits(3);
+    }
+}
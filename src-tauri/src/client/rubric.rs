@@ -0,0 +1,216 @@
+use super::Client;
+use crate::{
+    error::{AppError, Result},
+    model::{RubricAssessment, RubricCriterion, Submission},
+};
+
+// Apis here are for assignment rubrics
+impl Client {
+    pub async fn get_assignment_rubric(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Vec<RubricCriterion>> {
+        let assignment = self.get_assignment(course_id, assignment_id, token).await?;
+        Ok(assignment.rubric)
+    }
+
+    pub async fn list_rubric_assessments(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        token: &str,
+    ) -> Result<Vec<Submission>> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions?include[]=rubric_assessment&include[]=submission_comments",
+            self.base_url.read().await,
+            course_id,
+            assignment_id
+        );
+        self.list_items(&url, token).await
+    }
+
+    // `score` is posted along with the assessment, since canvas only derives the grade
+    // from the rubric when the rubric is used for grading
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_rubric_assessment(
+        &self,
+        course_id: i64,
+        assignment_id: i64,
+        student_id: i64,
+        assessment: &RubricAssessment,
+        score: f64,
+        comment: Option<&str>,
+        token: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/api/v1/courses/{}/assignments/{}/submissions/{}",
+            self.base_url.read().await,
+            course_id,
+            assignment_id,
+            student_id
+        );
+        let mut form = vec![("submission[posted_grade]".to_owned(), score.to_string())];
+        for (criterion_id, criterion_assessment) in assessment {
+            if let Some(points) = criterion_assessment.points {
+                form.push((
+                    format!("rubric_assessment[{criterion_id}][points]"),
+                    points.to_string(),
+                ));
+            }
+            if let Some(rating_id) = &criterion_assessment.rating_id {
+                form.push((
+                    format!("rubric_assessment[{criterion_id}][rating_id]"),
+                    rating_id.clone(),
+                ));
+            }
+            if let Some(comments) = &criterion_assessment.comments {
+                form.push((
+                    format!("rubric_assessment[{criterion_id}][comments]"),
+                    comments.clone(),
+                ));
+            }
+        }
+        if let Some(comment) = comment {
+            form.push(("comment[text_comment]".to_owned(), comment.to_owned()));
+        }
+        self.put_form_with_token(&url, None::<&str>, &form, token)
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+// Fills in the points of criteria assessed by rating only and returns the total score.
+// Criteria with neither points nor a rating (e.g. comment only) count as zero.
+pub fn score_rubric_assessment(
+    rubric: &[RubricCriterion],
+    assessment: &mut RubricAssessment,
+) -> Result<f64> {
+    let mut score = 0.0;
+    for (criterion_id, criterion_assessment) in assessment.iter_mut() {
+        let Some(criterion) = rubric
+            .iter()
+            .find(|criterion| &criterion.id == criterion_id)
+        else {
+            return Err(AppError::InvalidRubricAssessment(format!(
+                "unknown criterion {criterion_id}"
+            )));
+        };
+        if let Some(rating_id) = &criterion_assessment.rating_id {
+            let Some(rating) = criterion
+                .ratings
+                .iter()
+                .find(|rating| &rating.id == rating_id)
+            else {
+                return Err(AppError::InvalidRubricAssessment(format!(
+                    "unknown rating {rating_id} of criterion {criterion_id}"
+                )));
+            };
+            criterion_assessment.points.get_or_insert(rating.points);
+        }
+        let points = criterion_assessment.points.unwrap_or_default();
+        if points < 0.0 {
+            return Err(AppError::InvalidRubricAssessment(format!(
+                "negative points of criterion {criterion_id}"
+            )));
+        }
+        score += points;
+    }
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{RubricCriterionAssessment, RubricRating};
+
+    fn criterion(id: &str, ratings: &[(&str, f64)]) -> RubricCriterion {
+        RubricCriterion {
+            id: id.to_owned(),
+            points: ratings.first().map(|rating| rating.1).unwrap_or_default(),
+            ratings: ratings
+                .iter()
+                .map(|(id, points)| RubricRating {
+                    id: (*id).to_owned(),
+                    points: *points,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn criterion_assessment(
+        points: Option<f64>,
+        rating_id: Option<&str>,
+    ) -> RubricCriterionAssessment {
+        RubricCriterionAssessment {
+            points,
+            rating_id: rating_id.map(ToOwned::to_owned),
+            comments: None,
+        }
+    }
+
+    #[test]
+    fn test_score_rubric_assessment() {
+        let rubric = vec![
+            criterion("_1", &[("r1", 5.0), ("r2", 3.0)]),
+            criterion("_2", &[("r3", 10.0), ("r4", 0.0)]),
+            criterion("_3", &[("r5", 2.0)]),
+        ];
+        let mut assessment = RubricAssessment::from([
+            ("_1".to_owned(), criterion_assessment(None, Some("r2"))),
+            // points given explicitly win over the rating
+            ("_2".to_owned(), criterion_assessment(Some(7.5), Some("r3"))),
+            ("_3".to_owned(), criterion_assessment(None, None)),
+        ]);
+
+        let score = score_rubric_assessment(&rubric, &mut assessment).unwrap();
+        assert_eq!(score, 10.5);
+        assert_eq!(assessment["_1"].points, Some(3.0));
+
+        let mut unknown =
+            RubricAssessment::from([("_1".to_owned(), criterion_assessment(None, Some("r9")))]);
+        assert!(score_rubric_assessment(&rubric, &mut unknown).is_err());
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use crate::model::RubricCriterionAssessment;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_update_rubric_assessment() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let assessment_mock = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/v1/courses/1/assignments/2/submissions/10")
+                .x_www_form_urlencoded_tuple("submission[posted_grade]", "3")
+                .x_www_form_urlencoded_tuple("rubric_assessment[_1][points]", "3")
+                .x_www_form_urlencoded_tuple("rubric_assessment[_1][rating_id]", "r2")
+                .x_www_form_urlencoded_tuple("rubric_assessment[_1][comments]", "Missing proof");
+            then.status(200).body("{}");
+        });
+
+        let client = create_test_client(&server.base_url());
+        let assessment = RubricAssessment::from([(
+            "_1".to_owned(),
+            RubricCriterionAssessment {
+                points: Some(3.0),
+                rating_id: Some("r2".to_owned()),
+                comments: Some("Missing proof".to_owned()),
+            },
+        )]);
+        client
+            .update_rubric_assessment(1, 2, 10, &assessment, 3.0, None, token)
+            .await
+            .unwrap();
+        assessment_mock.assert();
+    }
+}
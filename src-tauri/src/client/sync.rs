@@ -0,0 +1,255 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use super::Client;
+use crate::{
+    error::Result,
+    model::{
+        CourseSyncDiff, File, Folder, FoldersAndFiles, SyncFileChange, SyncManifest,
+        SyncManifestEntry,
+    },
+};
+
+const COURSE_FILES_FOLDER_NAME: &str = "course files";
+
+// Apis here are for course file synchronization
+impl Client {
+    pub async fn get_folders_and_files(
+        &self,
+        course_id: i64,
+        token: &str,
+    ) -> Result<FoldersAndFiles> {
+        let folders = self.list_course_folders(course_id, token).await?;
+        let files = self.list_course_files(course_id, token).await?;
+        Ok(FoldersAndFiles::new(folders, files))
+    }
+}
+
+// Returns the folder path relative to the course directory, `None` if the folder is not
+// located under "course files".
+pub fn get_course_folder_path(folder: &Folder) -> Option<String> {
+    let folder_name = &folder.full_name;
+    if folder_name.len() < COURSE_FILES_FOLDER_NAME.len() {
+        return None;
+    }
+    if folder_name == COURSE_FILES_FOLDER_NAME {
+        return Some(String::new());
+    }
+    folder_name
+        .get(COURSE_FILES_FOLDER_NAME.len() + 1..)
+        .map(ToOwned::to_owned)
+}
+
+pub fn get_local_path(folder_path: &str, display_name: &str) -> String {
+    Path::new(folder_path)
+        .join(display_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn local_file_size(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .ok()
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+}
+
+// Compare remote course files against the manifest and what is on disk.
+// Files that exist locally but were downloaded before the manifest existed are adopted
+// into the manifest, and removed entries whose local copy is already gone are dropped.
+pub fn diff_course_files(
+    files: Vec<File>,
+    folders_map: &HashMap<i64, Folder>,
+    manifest: &mut SyncManifest,
+    course_dir: &Path,
+) -> CourseSyncDiff {
+    let mut diff = CourseSyncDiff::default();
+    let mut remote_file_ids = HashSet::new();
+    for file in files {
+        let folder_path = match folders_map.get(&file.folder_id) {
+            Some(folder) => match get_course_folder_path(folder) {
+                Some(folder_path) => folder_path,
+                None => continue,
+            },
+            None => String::new(),
+        };
+        remote_file_ids.insert(file.id);
+        let local_path = get_local_path(&folder_path, &file.display_name);
+        let local_size = local_file_size(&course_dir.join(&local_path));
+        let previous = manifest.entries.get(&file.id).cloned();
+        match (&previous, local_size) {
+            (Some(entry), _) if entry.local_path != local_path => {
+                diff.renamed_files.push(SyncFileChange {
+                    file,
+                    folder_path,
+                    previous,
+                });
+            }
+            (Some(entry), _) if entry.is_outdated_by(&file) => {
+                diff.modified_files.push(SyncFileChange {
+                    file,
+                    folder_path,
+                    previous,
+                });
+            }
+            (Some(_), Some(_)) => diff.unchanged_count += 1,
+            (None, Some(size)) if size == file.size => {
+                manifest
+                    .entries
+                    .insert(file.id, SyncManifestEntry::new(&file, local_path));
+                diff.unchanged_count += 1;
+            }
+            (None, Some(_)) => {
+                diff.modified_files.push(SyncFileChange {
+                    file,
+                    folder_path,
+                    previous,
+                });
+            }
+            (_, None) => {
+                diff.new_files.push(SyncFileChange {
+                    file,
+                    folder_path,
+                    previous,
+                });
+            }
+        }
+    }
+
+    manifest.entries.retain(|file_id, entry| {
+        if remote_file_ids.contains(file_id) {
+            return true;
+        }
+        if local_file_size(&course_dir.join(&entry.local_path)).is_none() {
+            return false;
+        }
+        diff.removed_files.push(entry.clone());
+        true
+    });
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn folder(id: i64, full_name: &str) -> Folder {
+        Folder {
+            id,
+            full_name: full_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn file(id: i64, folder_id: i64, display_name: &str, size: u64, updated_at: &str) -> File {
+        File {
+            id,
+            folder_id,
+            display_name: display_name.to_owned(),
+            size,
+            updated_at: Some(updated_at.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_course_folder_path() {
+        assert_eq!(
+            get_course_folder_path(&folder(1, "course files")),
+            Some("".to_owned())
+        );
+        assert_eq!(
+            get_course_folder_path(&folder(2, "course files/slides/week1")),
+            Some("slides/week1".to_owned())
+        );
+        assert_eq!(get_course_folder_path(&folder(3, "unfiled")), None);
+    }
+
+    #[test]
+    fn test_diff_course_files() -> Result<()> {
+        let course_dir = std::env::temp_dir().join(format!("sync_test_{}", Uuid::new_v4()));
+        fs::create_dir_all(course_dir.join("slides"))?;
+        fs::write(course_dir.join("slides").join("unchanged.pdf"), b"12345")?;
+        fs::write(course_dir.join("slides").join("modified.pdf"), b"12345")?;
+        fs::write(course_dir.join("legacy.pdf"), b"123")?;
+        fs::write(course_dir.join("old_name.pdf"), b"12345")?;
+        fs::write(course_dir.join("removed.pdf"), b"12345")?;
+
+        let folders_map = HashMap::from([
+            (1, folder(1, "course files")),
+            (2, folder(2, "course files/slides")),
+        ]);
+        let unchanged = file(10, 2, "unchanged.pdf", 5, "2024-03-01T00:00:00Z");
+        let modified = file(11, 2, "modified.pdf", 6, "2024-03-02T00:00:00Z");
+        let renamed = file(12, 1, "new_name.pdf", 5, "2024-03-01T00:00:00Z");
+        let legacy = file(13, 1, "legacy.pdf", 3, "2024-03-01T00:00:00Z");
+        let brand_new = file(14, 2, "brand_new.pdf", 7, "2024-03-01T00:00:00Z");
+
+        let mut manifest = SyncManifest {
+            course_id: 1,
+            entries: HashMap::from([
+                (
+                    10,
+                    SyncManifestEntry::new(&unchanged, get_local_path("slides", "unchanged.pdf")),
+                ),
+                (
+                    11,
+                    SyncManifestEntry::new(
+                        &file(11, 2, "modified.pdf", 5, "2024-03-01T00:00:00Z"),
+                        get_local_path("slides", "modified.pdf"),
+                    ),
+                ),
+                (
+                    12,
+                    SyncManifestEntry::new(
+                        &file(12, 1, "old_name.pdf", 5, "2024-03-01T00:00:00Z"),
+                        get_local_path("", "old_name.pdf"),
+                    ),
+                ),
+                (
+                    15,
+                    SyncManifestEntry::new(
+                        &file(15, 1, "removed.pdf", 5, "2024-03-01T00:00:00Z"),
+                        get_local_path("", "removed.pdf"),
+                    ),
+                ),
+                (
+                    16,
+                    SyncManifestEntry::new(
+                        &file(16, 1, "deleted_locally.pdf", 5, "2024-03-01T00:00:00Z"),
+                        get_local_path("", "deleted_locally.pdf"),
+                    ),
+                ),
+            ]),
+        };
+
+        let files = vec![unchanged, modified, renamed, legacy, brand_new];
+        let diff = diff_course_files(files, &folders_map, &mut manifest, &course_dir);
+        let _ = fs::remove_dir_all(&course_dir);
+
+        assert_eq!(diff.unchanged_count, 2);
+        assert_eq!(diff.modified_files.len(), 1);
+        assert_eq!(diff.modified_files[0].file.id, 11);
+        assert_eq!(diff.modified_files[0].folder_path, "slides");
+        assert_eq!(diff.renamed_files.len(), 1);
+        assert_eq!(diff.renamed_files[0].file.id, 12);
+        assert_eq!(
+            diff.renamed_files[0].previous.as_ref().unwrap().local_path,
+            "old_name.pdf"
+        );
+        assert_eq!(diff.new_files.len(), 1);
+        assert_eq!(diff.new_files[0].file.id, 14);
+        assert_eq!(diff.removed_files.len(), 1);
+        assert_eq!(diff.removed_files[0].file_id, 15);
+
+        // legacy file adopted, locally deleted entry dropped
+        assert!(manifest.entries.contains_key(&13));
+        assert!(!manifest.entries.contains_key(&16));
+        assert!(manifest.entries.contains_key(&15));
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use super::Client;
+use crate::{
+    error::Result,
+    model::{File, Folder, UploadConflictPolicy},
+    utils::{self, file::get_file_name},
+};
+
+// Apis here are for uploading files into course and personal folders
+impl Client {
+    pub async fn create_folder(
+        &self,
+        parent_folder_id: i64,
+        name: &str,
+        token: &str,
+    ) -> Result<Folder> {
+        let url = format!(
+            "{}/api/v1/folders/{}/folders",
+            self.base_url.read().await,
+            parent_folder_id
+        );
+        let response = self
+            .post_form_with_token(&url, None::<&str>, &[("name", name)], token)
+            .await?
+            .error_for_status()?;
+        let bytes = response.bytes().await?;
+        utils::json::parse_json(&bytes)
+    }
+
+    // Course folders and the user's own folders share the same endpoint
+    pub async fn upload_file_to_folder<F: Fn(u64) + Send + Sync + 'static>(
+        &self,
+        folder_id: i64,
+        file_path: &str,
+        policy: UploadConflictPolicy,
+        handler: F,
+        token: &str,
+    ) -> Result<File> {
+        let url = format!(
+            "{}/api/v1/folders/{}/files",
+            self.base_url.read().await,
+            folder_id
+        );
+        let file_name = get_file_name(file_path);
+        let params = self
+            .prepare_upload_file(
+                &url,
+                file_path,
+                &file_name,
+                &[("on_duplicate", policy.as_str())],
+                token,
+            )
+            .await?;
+        self.upload_file_with_progress(&params, file_path, handler)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::client::create_test_client;
+    use httpmock::prelude::*;
+    use std::{
+        fs,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_upload_file_to_folder() {
+        let server = MockServer::start();
+        let token = "test_token_12345";
+        let upload_url = format!("{}/upload", server.base_url());
+        let prepare_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/v1/folders/3/files")
+                .x_www_form_urlencoded_tuple("name", "notes.txt")
+                .x_www_form_urlencoded_tuple("size", "5")
+                .x_www_form_urlencoded_tuple("on_duplicate", "overwrite");
+            then.status(200).json_body(serde_json::json!({
+                "upload_url": upload_url,
+                "file_param": "file",
+            }));
+        });
+        let upload_mock = server.mock(|when, then| {
+            when.method(POST).path("/upload");
+            then.status(200).json_body(serde_json::json!({
+                "id": 7,
+                "uuid": "uuid",
+                "folder_id": 3,
+                "display_name": "notes.txt",
+                "filename": "notes.txt",
+                "url": "",
+                "size": 5,
+                "locked": false,
+            }));
+        });
+
+        let file_path = std::env::temp_dir().join(format!("upload_test_{}.txt", Uuid::new_v4()));
+        fs::write(&file_path, b"hello").unwrap();
+        let processed = Arc::new(AtomicU64::new(0));
+        let processed_clone = processed.clone();
+
+        let client = create_test_client(&server.base_url());
+        let file = client
+            .upload_file_to_folder(
+                3,
+                file_path.to_str().unwrap(),
+                UploadConflictPolicy::Overwrite,
+                move |bytes| processed_clone.store(bytes, Ordering::SeqCst),
+                token,
+            )
+            .await
+            .unwrap();
+        fs::remove_file(&file_path).unwrap();
+        prepare_mock.assert();
+        upload_mock.assert();
+        assert_eq!(file.id, 7);
+        assert_eq!(processed.load(Ordering::SeqCst), 5);
+    }
+}
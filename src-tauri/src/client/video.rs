@@ -485,6 +485,7 @@ impl Client {
             uuid: video.id.to_string(),
             processed: 0,
             total: size,
+            ..Default::default()
         };
         progress_handler(payload.clone());
         if size == 0 {
@@ -755,6 +756,7 @@ impl Client {
                 uuid: format!("ppt_{save_name}"),
                 processed: total_processed as u64,
                 total,
+                ..Default::default()
             });
         }
 
@@ -11,6 +11,8 @@ pub enum AppError {
     JsonParse(#[from] serde_json::Error),
     #[error("I/O error: {0}")]
     IO(#[from] io::Error),
+    #[error("Zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
     #[error("Excel error: {0}")]
     Excel(#[from] rust_xlsxwriter::XlsxError),
     #[error("Base64 decode error: {0}")]
@@ -46,6 +48,16 @@ pub enum AppError {
     OpenStderrError,
     #[error("Failed to download video {0}")]
     VideoDownloadError(String),
+    #[error("Failed to download file {0}")]
+    FileDownloadError(String),
+    #[error("Version {1} of file {0} not found")]
+    FileVersionNotFound(i64, u32),
+    #[error("Download job {0} not found")]
+    DownloadJobNotFound(String),
+    #[error("Download job {0} cancelled")]
+    DownloadCancelled(String),
+    #[error("Download job error: {0}")]
+    DownloadJobError(String),
     #[error("Unsupported file extension {0}")]
     UnsupportedFileExtensionError(String),
     #[error("PDF extract output error: {0}")]
@@ -54,6 +66,22 @@ pub enum AppError {
     DocxReaderError(#[from] docx_rs::ReaderError),
     #[error("LLM error: {0}")]
     LLMError(String),
+    #[error("Invalid rubric assessment: {0}")]
+    InvalidRubricAssessment(String),
+    #[error("Spreadsheet error: {0}")]
+    Spreadsheet(#[from] calamine::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Invalid grade import: {0}")]
+    InvalidGradeImport(String),
+    #[error("Canvas job {0} failed: {1}")]
+    CanvasJobFailed(i64, String),
+    #[error("Invalid deadline operation: {0}")]
+    InvalidDeadlineOperation(String),
+    #[error("Deadline batch {0} not found")]
+    DeadlineBatchNotFound(String),
+    #[error("Invalid submission: {0}")]
+    InvalidSubmission(String),
 }
 
 impl serde::Serialize for AppError {
@@ -6,17 +6,18 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use error::{AppError, Result};
 use model::{
-    Account, AccountInfo, AnnualReport, AppConfig, Assignment, CalendarEvent, CanvasVideo, Colors,
-    Course, DiscussionTopic, File, FileChatStreamChunkPayload, FileChatStreamDonePayload,
-    FileChatStreamErrorPayload, Folder, FullDiscussion, LLMChatMessage, LogLevel, ModuleItem,
-    NetworkRequestLog, QRCodeScanResult, RelationshipTopo, Subject, Submission, User,
+    Account, AccountInfo, AnnouncementFeed, AnnualReport, AppConfig, Assignment, CalendarEvent, CanvasVideo, Colors,
+    Conversation, Course, CourseSyncDiff, DiscussionEntry, DiscussionTopic, DiscussionTopicInput, DownloadJob, DownloadState, FeedbackFileMatch, FeedbackUploadPlan, NewSubmission, SubmissionValidation, NewSubmissionComment, DownloadTarget, File, FileVersion, FileChatStreamChunkPayload,
+    FileChatStreamDonePayload, FileChatStreamErrorPayload, Folder, FolderFilesPagePayload, FullDiscussion, GradeImportPreview, Group, GroupCategory, GroupMembership, LLMChatMessage, LogLevel, ModuleItem,
+    NetworkRequestLog, NewConversation, Page, QRCodeScanResult, Quiz, QuizQuestion, QuizSubmissionHistory, RelationshipTopo, RubricAssessment, RubricCriterion, SearchResult, SimilarityReport, DeadlineBatch, DeadlineOperation, AssignmentGroup, AssignmentInput, UploadConflictPolicy, Subject, Submission, SubmissionDownloadOptions,
+    User,
     UserSubmissions,
     VideoAggregateParams, VideoCourse, VideoInfo, VideoPlayInfo,
 };
 
 use dirs::config_dir;
 
-use tauri::{Emitter, Runtime, Window};
+use tauri::{AppHandle, Emitter, Runtime, Window};
 use tracing::Level;
 use tracing_subscriber::{
     fmt::{self, writer::MakeWriterExt},
@@ -280,10 +281,206 @@ async fn get_full_discussion(course_id: i64, topic_id: i64) -> Result<FullDiscus
 }
 
 #[tauri::command]
-async fn sync_course_files(course: Course) -> Result<Vec<File>> {
+async fn create_discussion_topic(
+    course_id: i64,
+    input: DiscussionTopicInput,
+    attachment: Option<String>,
+) -> Result<DiscussionTopic> {
+    APP.create_discussion_topic(course_id, &input, attachment.as_deref())
+        .await
+}
+
+#[tauri::command]
+async fn post_discussion_reply(
+    course_id: i64,
+    topic_id: i64,
+    entry_id: Option<i64>,
+    message: String,
+    attachment: Option<String>,
+) -> Result<DiscussionEntry> {
+    APP.post_discussion_reply(
+        course_id,
+        topic_id,
+        entry_id,
+        &message,
+        attachment.as_deref(),
+    )
+    .await
+}
+
+#[tauri::command]
+async fn rate_discussion_entry(
+    course_id: i64,
+    topic_id: i64,
+    entry_id: i64,
+    liked: bool,
+) -> Result<()> {
+    APP.rate_discussion_entry(course_id, topic_id, entry_id, liked)
+        .await
+}
+
+#[tauri::command]
+async fn mark_discussion_entry_read(
+    course_id: i64,
+    topic_id: i64,
+    entry_id: i64,
+    read: bool,
+) -> Result<()> {
+    APP.mark_discussion_entry_read(course_id, topic_id, entry_id, read)
+        .await
+}
+
+#[tauri::command]
+async fn mark_discussion_topic_read(course_id: i64, topic_id: i64) -> Result<()> {
+    APP.mark_discussion_topic_read(course_id, topic_id).await
+}
+
+#[tauri::command]
+async fn list_course_pages(course_id: i64) -> Result<Vec<Page>> {
+    APP.list_course_pages(course_id).await
+}
+
+#[tauri::command]
+async fn get_course_page(course_id: i64, page_url: String) -> Result<Page> {
+    APP.get_course_page(course_id, &page_url).await
+}
+
+#[tauri::command]
+async fn export_page_markdown(course: Course, page_url: String) -> Result<String> {
+    APP.export_page_markdown(&course, &page_url).await
+}
+
+#[tauri::command]
+async fn list_course_quizzes(course_id: i64) -> Result<Vec<Quiz>> {
+    APP.list_course_quizzes(course_id).await
+}
+
+#[tauri::command]
+async fn get_quiz(course_id: i64, quiz_id: i64) -> Result<Quiz> {
+    APP.get_quiz(course_id, quiz_id).await
+}
+
+#[tauri::command]
+async fn get_my_quiz_submissions(course_id: i64, quiz_id: i64) -> Result<QuizSubmissionHistory> {
+    APP.get_my_quiz_submissions(course_id, quiz_id).await
+}
+
+#[tauri::command]
+async fn list_quiz_questions(course_id: i64, quiz_id: i64) -> Result<Vec<QuizQuestion>> {
+    APP.list_quiz_questions(course_id, quiz_id).await
+}
+
+#[tauri::command]
+async fn list_conversations(scope: Option<String>) -> Result<Vec<Conversation>> {
+    APP.list_conversations(scope.as_deref()).await
+}
+
+#[tauri::command]
+async fn get_conversation(conversation_id: i64) -> Result<Conversation> {
+    APP.get_conversation(conversation_id).await
+}
+
+#[tauri::command]
+async fn get_unread_conversation_count() -> Result<i64> {
+    APP.get_unread_conversation_count().await
+}
+
+#[tauri::command]
+async fn create_conversation(conversation: NewConversation) -> Result<Vec<Conversation>> {
+    APP.create_conversation(&conversation).await
+}
+
+#[tauri::command]
+async fn reply_conversation(
+    conversation_id: i64,
+    body: String,
+    attachment_paths: Vec<String>,
+) -> Result<Conversation> {
+    APP.reply_conversation(conversation_id, &body, &attachment_paths)
+        .await
+}
+
+#[tauri::command]
+async fn message_course_students(
+    course_id: i64,
+    subject: String,
+    body: String,
+    attachment_paths: Vec<String>,
+) -> Result<Vec<Conversation>> {
+    APP.message_course_students(course_id, &subject, &body, &attachment_paths)
+        .await
+}
+
+#[tauri::command]
+async fn list_course_groups(course_id: i64) -> Result<Vec<Group>> {
+    APP.list_course_groups(course_id).await
+}
+
+#[tauri::command]
+async fn list_group_categories(course_id: i64) -> Result<Vec<GroupCategory>> {
+    APP.list_group_categories(course_id).await
+}
+
+#[tauri::command]
+async fn list_group_category_groups(group_category_id: i64) -> Result<Vec<Group>> {
+    APP.list_group_category_groups(group_category_id).await
+}
+
+#[tauri::command]
+async fn list_group_memberships(group_id: i64) -> Result<Vec<GroupMembership>> {
+    APP.list_group_memberships(group_id).await
+}
+
+#[tauri::command]
+async fn list_group_users(group_id: i64) -> Result<Vec<User>> {
+    APP.list_group_users(group_id).await
+}
+
+#[tauri::command]
+async fn list_group_assignment_submissions(
+    course_id: i64,
+    assignment_id: i64,
+) -> Result<Vec<Submission>> {
+    APP.list_group_assignment_submissions(course_id, assignment_id)
+        .await
+}
+
+#[tauri::command]
+async fn sync_course_files(course: Course) -> Result<CourseSyncDiff> {
     APP.sync_course_files(&course).await
 }
 
+#[tauri::command]
+async fn remove_synced_course_files(course: Course, file_ids: Vec<i64>) -> Result<()> {
+    APP.remove_synced_course_files(&course, &file_ids).await
+}
+
+#[tauri::command]
+async fn list_file_versions(course: Course, file_id: i64) -> Result<Vec<FileVersion>> {
+    APP.list_file_versions(&course, file_id).await
+}
+
+#[tauri::command]
+async fn restore_file_version(course: Course, file_id: i64, version: u32) -> Result<()> {
+    APP.restore_file_version(&course, file_id, version).await
+}
+
+#[tauri::command]
+async fn diff_file_version(course: Course, file_id: i64, version: u32) -> Result<String> {
+    APP.diff_file_version(&course, file_id, version).await
+}
+
+#[tauri::command]
+async fn search_course_materials(query: String, limit: Option<usize>) -> Result<Vec<SearchResult>> {
+    APP.search_course_materials(&query, limit.unwrap_or(20))
+        .await
+}
+
+#[tauri::command]
+async fn refresh_search_index() -> Result<usize> {
+    APP.refresh_search_index().await
+}
+
 #[tauri::command]
 async fn list_course_files(course_id: i64) -> Result<Vec<File>> {
     APP.list_course_files(course_id).await
@@ -320,8 +517,17 @@ async fn filter_course_qrcode_images(course_id: i64) -> Result<Vec<QRCodeScanRes
 }
 
 #[tauri::command]
-async fn list_folder_files(folder_id: i64) -> Result<Vec<File>> {
-    APP.list_folder_files(folder_id).await
+async fn list_folder_files(window: Window, folder_id: i64) -> Result<Vec<File>> {
+    APP.list_folder_files_by_page(folder_id, |files| {
+        let _ = window.emit(
+            "folder_files://page",
+            FolderFilesPagePayload {
+                folder_id,
+                files: files.to_vec(),
+            },
+        );
+    })
+    .await
 }
 
 #[tauri::command]
@@ -576,6 +782,51 @@ async fn save_path_file(path: String, content: Vec<u8>) -> Result<()> {
     Ok(())
 }
 
+#[tauri::command]
+async fn preview_grade_import(
+    course_id: i64,
+    assignment_id: i64,
+    file_path: String,
+) -> Result<GradeImportPreview> {
+    APP.preview_grade_import(course_id, assignment_id, &file_path)
+        .await
+}
+
+#[tauri::command]
+async fn apply_grade_import(
+    course_id: i64,
+    assignment_id: i64,
+    preview: GradeImportPreview,
+) -> Result<GradeImportPreview> {
+    APP.apply_grade_import(course_id, assignment_id, preview)
+        .await
+}
+
+#[tauri::command]
+async fn export_gradebook(course_id: i64, file_name: String, folder_path: String) -> Result<()> {
+    APP.export_gradebook(course_id, &file_name, &folder_path)
+        .await
+}
+
+#[tauri::command]
+async fn detect_submission_similarity(
+    course_id: i64,
+    assignment_id: i64,
+    min_similarity: Option<f64>,
+) -> Result<SimilarityReport> {
+    APP.detect_submission_similarity(course_id, assignment_id, min_similarity)
+        .await
+}
+
+#[tauri::command]
+fn export_similarity_report(
+    report: SimilarityReport,
+    file_name: String,
+    folder_path: String,
+) -> Result<()> {
+    APP.export_similarity_report(&report, &file_name, &folder_path)
+}
+
 #[tauri::command]
 async fn export_excel(
     data: Vec<Vec<String>>,
@@ -586,38 +837,114 @@ async fn export_excel(
 }
 
 #[tauri::command]
-async fn download_file<R: Runtime>(window: Window<R>, file: File) -> Result<()> {
-    APP.download_file(&file, &|progress| {
-        let _ = window.emit("download://progress", progress);
-    })
-    .await
+async fn download_file(file: File, priority: Option<i64>) -> Result<DownloadState> {
+    APP.download_file(&file, priority.unwrap_or_default()).await
 }
 
 #[tauri::command]
-async fn download_course_file<R: Runtime>(
-    window: Window<R>,
+async fn download_course_file(
     file: File,
     course: Course,
     folder_path: String,
-) -> Result<()> {
-    APP.download_course_file(&file, &course, &folder_path, &|progress| {
-        let _ = window.emit("download://progress", progress);
-    })
-    .await
+    priority: Option<i64>,
+) -> Result<DownloadState> {
+    APP.download_course_file(&file, &course, &folder_path, priority.unwrap_or_default())
+        .await
 }
 
 #[tauri::command]
-async fn download_my_file<R: Runtime>(
-    window: Window<R>,
+async fn download_my_file(
     file: File,
     folder_path: String,
+    priority: Option<i64>,
+) -> Result<DownloadState> {
+    APP.download_my_file(&file, &folder_path, priority.unwrap_or_default())
+        .await
+}
+
+#[tauri::command]
+async fn download_folder_as_zip<R: Runtime>(
+    window: Window<R>,
+    folder_id: i64,
+    save_name: String,
 ) -> Result<()> {
-    APP.download_my_file(&file, &folder_path, &|progress| {
-        let _ = window.emit("download://progress", progress);
+    APP.download_folder_as_zip(folder_id, &save_name, |progress| {
+        let _ = window.emit("folder_zip://progress", progress);
     })
     .await
 }
 
+#[tauri::command]
+async fn download_submission_attachments<R: Runtime>(
+    window: Window<R>,
+    course_id: i64,
+    assignment_id: i64,
+    save_name: String,
+    options: SubmissionDownloadOptions,
+) -> Result<String> {
+    APP.download_submission_attachments(
+        course_id,
+        assignment_id,
+        &save_name,
+        &options,
+        |progress| {
+            let _ = window.emit("submission_download://progress", progress);
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+async fn create_folder(parent_folder_id: i64, name: String) -> Result<Folder> {
+    APP.create_folder(parent_folder_id, &name).await
+}
+
+#[tauri::command]
+async fn upload_files_to_folder<R: Runtime>(
+    window: Window<R>,
+    folder_id: i64,
+    file_paths: Vec<String>,
+    policy: Option<UploadConflictPolicy>,
+) -> Result<Vec<File>> {
+    APP.upload_files_to_folder(
+        folder_id,
+        &file_paths,
+        policy.unwrap_or_default(),
+        move |progress| {
+            let _ = window.emit("folder_upload://progress", progress);
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+fn list_download_jobs() -> Result<Vec<DownloadJob>> {
+    APP.list_download_jobs()
+}
+
+#[tauri::command]
+async fn pause_download(id: String) -> Result<()> {
+    APP.pause_download(&id).await
+}
+
+#[tauri::command]
+async fn resume_download(id: String) -> Result<()> {
+    APP.resume_download(&id).await
+}
+
+#[tauri::command]
+async fn cancel_download(id: String) -> Result<()> {
+    APP.cancel_download(&id).await
+}
+
+fn emit_download_progress<R: Runtime>(handle: &AppHandle<R>, job: &DownloadJob) {
+    let event = match job.target {
+        DownloadTarget::File { .. } => "download://progress",
+        DownloadTarget::Video { .. } => "video_download://progress",
+    };
+    let _ = handle.emit(event, job.progress());
+}
+
 #[tauri::command]
 async fn save_file_content(content: Vec<u8>, file_name: String) -> Result<()> {
     APP.save_file_content(&content, &file_name).await
@@ -645,6 +972,20 @@ async fn list_calendar_events(
         .await
 }
 
+#[tauri::command]
+async fn list_announcements(
+    start_date: Option<String>,
+    end_date: Option<String>,
+) -> Result<AnnouncementFeed> {
+    APP.list_announcements(start_date.as_deref(), end_date.as_deref())
+        .await
+}
+
+#[tauri::command]
+fn mark_announcements_read(announcement_ids: Vec<i64>, read: bool) -> Result<()> {
+    APP.mark_announcements_read(&announcement_ids, read)
+}
+
 #[tauri::command]
 async fn get_folder_by_id(folder_id: i64) -> Result<Folder> {
     APP.get_folder_by_id(folder_id).await
@@ -676,10 +1017,20 @@ async fn upload_submission_file(
 async fn submit_assignment(
     course_id: i64,
     assignment_id: i64,
-    file_paths: Vec<String>,
+    submission: NewSubmission,
     comment: Option<String>,
-) -> Result<()> {
-    APP.submit_assignment(course_id, assignment_id, &file_paths, comment.as_deref())
+) -> Result<Submission> {
+    APP.submit_assignment(course_id, assignment_id, &submission, comment.as_deref())
+        .await
+}
+
+#[tauri::command]
+async fn validate_submission(
+    course_id: i64,
+    assignment_id: i64,
+    submission: NewSubmission,
+) -> Result<SubmissionValidation> {
+    APP.validate_submission(course_id, assignment_id, &submission)
         .await
 }
 
@@ -695,6 +1046,7 @@ async fn update_grade(
     student_id: i64,
     grade: String,
     comment: Option<String>,
+    group_id: Option<i64>,
 ) -> Result<()> {
     APP.update_grade(
         course_id,
@@ -702,6 +1054,35 @@ async fn update_grade(
         student_id,
         &grade,
         comment.as_deref(),
+        group_id,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn get_assignment_rubric(course_id: i64, assignment_id: i64) -> Result<Vec<RubricCriterion>> {
+    APP.get_assignment_rubric(course_id, assignment_id).await
+}
+
+#[tauri::command]
+async fn list_rubric_assessments(course_id: i64, assignment_id: i64) -> Result<Vec<Submission>> {
+    APP.list_rubric_assessments(course_id, assignment_id).await
+}
+
+#[tauri::command]
+async fn submit_rubric_assessment(
+    course_id: i64,
+    assignment_id: i64,
+    student_id: i64,
+    assessment: RubricAssessment,
+    comment: Option<String>,
+) -> Result<f64> {
+    APP.submit_rubric_assessment(
+        course_id,
+        assignment_id,
+        student_id,
+        assessment,
+        comment.as_deref(),
     )
     .await
 }
@@ -732,6 +1113,97 @@ async fn delete_my_submission_comment(
         .await
 }
 
+#[tauri::command]
+async fn add_submission_comment(
+    course_id: i64,
+    assignment_id: i64,
+    student_id: i64,
+    comment: NewSubmissionComment,
+) -> Result<Submission> {
+    APP.add_submission_comment(course_id, assignment_id, &student_id.to_string(), &comment)
+        .await
+}
+
+#[tauri::command]
+async fn add_my_submission_comment(
+    course_id: i64,
+    assignment_id: i64,
+    comment: NewSubmissionComment,
+) -> Result<Submission> {
+    APP.add_submission_comment(course_id, assignment_id, "self", &comment)
+        .await
+}
+
+#[tauri::command]
+async fn plan_feedback_upload(course_id: i64, dir: String) -> Result<FeedbackUploadPlan> {
+    APP.plan_feedback_upload(course_id, &dir).await
+}
+
+#[tauri::command]
+async fn upload_feedback_files<R: Runtime>(
+    window: Window<R>,
+    course_id: i64,
+    assignment_id: i64,
+    matches: Vec<FeedbackFileMatch>,
+    text: Option<String>,
+) -> Result<Vec<FeedbackFileMatch>> {
+    APP.upload_feedback_files(
+        course_id,
+        assignment_id,
+        matches,
+        text.as_deref(),
+        |progress| {
+            let _ = window.emit("feedback_upload://progress", progress);
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+async fn list_assignment_groups(course_id: i64) -> Result<Vec<AssignmentGroup>> {
+    APP.list_assignment_groups(course_id).await
+}
+
+#[tauri::command]
+async fn create_assignment(course_id: i64, input: AssignmentInput) -> Result<Assignment> {
+    APP.create_assignment(course_id, &input).await
+}
+
+#[tauri::command]
+async fn update_assignment(
+    course_id: i64,
+    assignment_id: i64,
+    input: AssignmentInput,
+) -> Result<Assignment> {
+    APP.update_assignment(course_id, assignment_id, &input)
+        .await
+}
+
+#[tauri::command]
+async fn set_assignment_published(
+    course_id: i64,
+    assignment_id: i64,
+    published: bool,
+) -> Result<Assignment> {
+    APP.set_assignment_published(course_id, assignment_id, published)
+        .await
+}
+
+#[tauri::command]
+async fn delete_assignment(course_id: i64, assignment_id: i64) -> Result<()> {
+    APP.delete_assignment(course_id, assignment_id).await
+}
+
+#[tauri::command]
+async fn clone_assignment(
+    course_id: i64,
+    assignment_id: i64,
+    target_course_id: Option<i64>,
+) -> Result<Assignment> {
+    APP.clone_assignment(course_id, assignment_id, target_course_id)
+        .await
+}
+
 #[tauri::command]
 async fn modify_assignment_ddl(
     course_id: i64,
@@ -766,6 +1238,29 @@ async fn modify_assignment_ddl_override(
     .await
 }
 
+#[tauri::command]
+async fn preview_deadline_operation(
+    course_id: i64,
+    operation: DeadlineOperation,
+) -> Result<DeadlineBatch> {
+    APP.preview_deadline_operation(course_id, &operation).await
+}
+
+#[tauri::command]
+async fn apply_deadline_batch(batch: DeadlineBatch) -> Result<DeadlineBatch> {
+    APP.apply_deadline_batch(batch).await
+}
+
+#[tauri::command]
+fn list_deadline_batches(course_id: i64) -> Result<Vec<DeadlineBatch>> {
+    APP.list_deadline_batches(course_id)
+}
+
+#[tauri::command]
+async fn revert_deadline_batch(batch_id: String) -> Result<DeadlineBatch> {
+    APP.revert_deadline_batch(&batch_id).await
+}
+
 #[tauri::command]
 async fn delete_assignment_ddl_override(
     course_id: i64,
@@ -858,16 +1353,13 @@ async fn stop_proxy() {
 }
 
 #[tauri::command]
-async fn download_video<R: Runtime>(
-    window: Window<R>,
+async fn download_video(
     video: VideoPlayInfo,
     save_name: String,
-) -> Result<()> {
-    let window = Arc::new(window);
-    APP.download_video(&video, &save_name, move |progress| {
-        let _ = window.clone().emit("video_download://progress", progress);
-    })
-    .await
+    priority: Option<i64>,
+) -> Result<DownloadState> {
+    APP.download_video(&video, &save_name, priority.unwrap_or_default())
+        .await
 }
 
 #[tauri::command]
@@ -983,6 +1475,28 @@ async fn main() -> Result<()> {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
+        .setup(|app| {
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let sink = Arc::new(move |job: &DownloadJob| emit_download_progress(&handle, job));
+                if let Err(e) = APP.start_download_manager(sink).await {
+                    tracing::error!("Failed to start download manager: {}", e);
+                }
+            });
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = APP.refresh_search_index().await {
+                    tracing::error!("Failed to refresh search index: {}", e);
+                }
+            });
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                APP.poll_unread_conversations(|count| {
+                    let _ = handle.emit("conversation://unread", count);
+                })
+                .await;
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             read_log_content,
             console_log,
@@ -999,7 +1513,37 @@ async fn main() -> Result<()> {
             list_user_submissions,
             get_full_discussion,
             list_discussion_topics,
+            create_discussion_topic,
+            post_discussion_reply,
+            rate_discussion_entry,
+            mark_discussion_entry_read,
+            mark_discussion_topic_read,
+            list_course_pages,
+            get_course_page,
+            export_page_markdown,
+            list_course_quizzes,
+            get_quiz,
+            get_my_quiz_submissions,
+            list_quiz_questions,
+            list_conversations,
+            get_conversation,
+            get_unread_conversation_count,
+            create_conversation,
+            reply_conversation,
+            message_course_students,
+            list_course_groups,
+            list_group_categories,
+            list_group_category_groups,
+            list_group_memberships,
+            list_group_users,
+            list_group_assignment_submissions,
             sync_course_files,
+            remove_synced_course_files,
+            list_file_versions,
+            restore_file_version,
+            diff_file_version,
+            search_course_materials,
+            refresh_search_index,
             list_course_files,
             list_course_images,
             list_course_users,
@@ -1009,14 +1553,22 @@ async fn main() -> Result<()> {
             filter_course_qrcode_images,
             get_single_course_assignment_submission,
             export_excel,
+            export_gradebook,
+            detect_submission_similarity,
+            export_similarity_report,
+            preview_grade_import,
+            apply_grade_import,
             list_folder_files,
             list_course_folders,
             list_my_folders,
             list_folder_folders,
             list_calendar_events,
+            list_announcements,
+            mark_announcements_read,
             test_token,
             upload_submission_file,
             submit_assignment,
+            validate_submission,
             get_me,
             get_folder_by_id,
             get_colors,
@@ -1040,15 +1592,40 @@ async fn main() -> Result<()> {
             download_file,
             download_course_file,
             download_my_file,
+            download_folder_as_zip,
+            download_submission_attachments,
+            create_folder,
+            upload_files_to_folder,
+            list_download_jobs,
+            pause_download,
+            resume_download,
+            cancel_download,
             check_path,
             export_users,
             update_grade,
+            get_assignment_rubric,
+            list_rubric_assessments,
+            submit_rubric_assessment,
             delete_submission_comment,
+            add_submission_comment,
+            add_my_submission_comment,
+            plan_feedback_upload,
+            upload_feedback_files,
             delete_my_submission_comment,
+            list_assignment_groups,
+            create_assignment,
+            update_assignment,
+            set_assignment_published,
+            delete_assignment,
+            clone_assignment,
             modify_assignment_ddl,
             modify_assignment_ddl_override,
             add_assignment_ddl_override,
             delete_assignment_ddl_override,
+            preview_deadline_operation,
+            apply_deadline_batch,
+            list_deadline_batches,
+            revert_deadline_batch,
             get_my_single_submission,
             // Utils
             convert_pptx_to_pdf,
@@ -15,7 +15,11 @@ use tokio_util::sync::CancellationToken;
 use serde::Serialize;
 use std::net::SocketAddr;
 
-use crate::{model::File, APP};
+use crate::{
+    client::{page::get_page_markdown, pagination::ListOptions},
+    model::{DiscussionTopicInput, File},
+    APP,
+};
 
 #[derive(Clone)]
 struct McpServer;
@@ -42,6 +46,25 @@ fn get_string(params: &Map<String, Value>, key: &str) -> Result<String, ErrorDat
         .ok_or_else(|| invalid(format!("Missing or invalid {key}")))
 }
 
+fn get_bool(params: &Map<String, Value>, key: &str) -> Result<bool, ErrorData> {
+    params
+        .get(key)
+        .and_then(|v| v.as_bool())
+        .ok_or_else(|| invalid(format!("Missing or invalid {key}")))
+}
+
+// Tools that change data on Canvas must be enabled by the user in settings
+async fn ensure_write_allowed() -> Result<(), ErrorData> {
+    if APP.mcp_allow_write().await {
+        Ok(())
+    } else {
+        Err(ErrorData::invalid_request(
+            "Write tools are disabled, enable them in the MCP settings of the app",
+            None,
+        ))
+    }
+}
+
 fn get_vec<T>(params: &Map<String, Value>, key: &str) -> Result<Vec<T>, ErrorData>
 where
     T: serde::de::DeserializeOwned,
@@ -84,13 +107,16 @@ impl McpServer {
             .map_err(internal)
     }
 
-    #[tool(description = "List all files in a course")]
+    #[tool(description = "List files in a course, at most `limit` of them when given")]
     async fn list_course_files(
         &self,
         Parameters(params): Parameters<Map<String, Value>>,
     ) -> Result<CallToolResult, ErrorData> {
         let course_id = get_i64(&params, "course_id")?;
-        APP.list_course_files(course_id)
+        let options = get_i64(&params, "limit")
+            .map(|limit| ListOptions::with_max_items(limit.max(0) as usize))
+            .unwrap_or_default();
+        APP.list_course_files_with_options(course_id, &options)
             .await
             .map(tool_json)
             .map_err(internal)
@@ -195,6 +221,21 @@ impl McpServer {
             .map_err(internal)
     }
 
+    #[tool(
+        description = "List announcements of all current-term courses with local read state and unread counts. Dates are optional ISO-8601 strings, Canvas defaults to the last 14 days"
+    )]
+    async fn list_announcements(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let start_date = get_string(&params, "start_date").ok();
+        let end_date = get_string(&params, "end_date").ok();
+        APP.list_announcements(start_date.as_deref(), end_date.as_deref())
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
     #[tool(description = "List all discussion topics for a course")]
     async fn list_discussion_topics(
         &self,
@@ -220,6 +261,201 @@ impl McpServer {
             .map_err(internal)
     }
 
+    #[tool(
+        description = "Create a discussion topic in a course. Requires write tools to be enabled"
+    )]
+    async fn create_discussion_topic(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        ensure_write_allowed().await?;
+        let course_id = get_i64(&params, "course_id")?;
+        let input = DiscussionTopicInput {
+            title: get_string(&params, "title")?,
+            message: get_string(&params, "message").unwrap_or_default(),
+            threaded: get_bool(&params, "threaded").unwrap_or_default(),
+            published: get_bool(&params, "published").unwrap_or(true),
+        };
+        // local files are never uploaded on behalf of an mcp client
+        APP.create_discussion_topic(course_id, &input, None)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(
+        description = "Post a reply to a discussion topic, or to one of its entries or replies when entry_id is given. Requires write tools to be enabled"
+    )]
+    async fn post_discussion_reply(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        ensure_write_allowed().await?;
+        let course_id = get_i64(&params, "course_id")?;
+        let topic_id = get_i64(&params, "topic_id")?;
+        let entry_id = get_i64(&params, "entry_id").ok();
+        let message = get_string(&params, "message")?;
+        APP.post_discussion_reply(course_id, topic_id, entry_id, &message, None)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "Like or unlike a discussion entry. Requires write tools to be enabled")]
+    async fn rate_discussion_entry(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        ensure_write_allowed().await?;
+        let course_id = get_i64(&params, "course_id")?;
+        let topic_id = get_i64(&params, "topic_id")?;
+        let entry_id = get_i64(&params, "entry_id")?;
+        let liked = get_bool(&params, "liked").unwrap_or(true);
+        APP.rate_discussion_entry(course_id, topic_id, entry_id, liked)
+            .await
+            .map_err(internal)?;
+        Ok(tool_json(json!({ "entry_id": entry_id, "liked": liked })))
+    }
+
+    #[tool(
+        description = "Mark a discussion entry as read or unread, or the whole topic as read when entry_id is omitted. Requires write tools to be enabled"
+    )]
+    async fn mark_discussion_read(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        ensure_write_allowed().await?;
+        let course_id = get_i64(&params, "course_id")?;
+        let topic_id = get_i64(&params, "topic_id")?;
+        let result = match get_i64(&params, "entry_id") {
+            Ok(entry_id) => {
+                let read = get_bool(&params, "read").unwrap_or(true);
+                APP.mark_discussion_entry_read(course_id, topic_id, entry_id, read)
+                    .await
+            }
+            Err(_) => APP.mark_discussion_topic_read(course_id, topic_id).await,
+        };
+        result.map_err(internal)?;
+        Ok(tool_json(json!({ "topic_id": topic_id, "success": true })))
+    }
+
+    #[tool(description = "List all pages (wiki pages) of a course")]
+    async fn list_course_pages(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        APP.list_course_pages(course_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "Get a course page by its url slug, with the body converted to Markdown")]
+    async fn get_course_page(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        let page_url = get_string(&params, "page_url")?;
+        APP.get_course_page(course_id, &page_url)
+            .await
+            .map(|page| CallToolResult::success(vec![Content::text(get_page_markdown(&page))]))
+            .map_err(internal)
+    }
+
+    #[tool(description = "List all quizzes of a course")]
+    async fn list_course_quizzes(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        APP.list_course_quizzes(course_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "Get details of a quiz")]
+    async fn get_quiz(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        let quiz_id = get_i64(&params, "quiz_id")?;
+        APP.get_quiz(course_id, quiz_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(
+        description = "Get the current user's submissions of a quiz with score and attempt history"
+    )]
+    async fn get_my_quiz_submissions(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        let quiz_id = get_i64(&params, "quiz_id")?;
+        APP.get_my_quiz_submissions(course_id, quiz_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(
+        description = "List questions and answers of a quiz, empty when Canvas does not reveal them"
+    )]
+    async fn list_quiz_questions(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        let quiz_id = get_i64(&params, "quiz_id")?;
+        APP.list_quiz_questions(course_id, quiz_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "List all student groups of a course")]
+    async fn list_course_groups(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        APP.list_course_groups(course_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "List the members of a group")]
+    async fn list_group_users(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let group_id = get_i64(&params, "group_id")?;
+        APP.list_group_users(group_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
+    #[tool(description = "Get the rubric criteria and ratings of an assignment")]
+    async fn get_assignment_rubric(
+        &self,
+        Parameters(params): Parameters<Map<String, Value>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let course_id = get_i64(&params, "course_id")?;
+        let assignment_id = get_i64(&params, "assignment_id")?;
+        APP.get_assignment_rubric(course_id, assignment_id)
+            .await
+            .map(tool_json)
+            .map_err(internal)
+    }
+
     #[tool(description = "List all image files in a course")]
     async fn list_course_images(
         &self,
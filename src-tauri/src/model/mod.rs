@@ -65,6 +65,8 @@ pub struct File {
     pub mime_class: String,
     #[serde(default, rename = "content-type")]
     pub content_type: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -87,6 +89,24 @@ pub struct Folder {
     pub folders_count: i64,
 }
 
+// What canvas does when a file with the same name already exists in the folder
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadConflictPolicy {
+    Overwrite,
+    #[default]
+    Rename,
+}
+
+impl UploadConflictPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UploadConflictPolicy::Overwrite => "overwrite",
+            UploadConflictPolicy::Rename => "rename",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum Account {
     #[default]
@@ -191,14 +211,37 @@ pub struct AppConfig {
     pub mcp_enabled: bool,
     #[serde(default = "default_mcp_port")]
     pub mcp_port: u16,
+    // tools that post or change data on canvas are always listed, but fail unless enabled
+    #[serde(default)]
+    pub mcp_allow_write: bool,
     #[serde(default)]
     pub debug_mode: bool,
+    #[serde(default = "default_max_concurrent_downloads")]
+    pub max_concurrent_downloads: usize,
+    // seconds between unread inbox checks, 0 disables polling
+    #[serde(default = "default_conversation_poll_interval")]
+    pub conversation_poll_interval: u64,
+    // supports {login_id}, {name}, {user_id} and {late}
+    #[serde(default = "default_submission_folder_template")]
+    pub submission_folder_template: String,
 }
 
 fn default_mcp_port() -> u16 {
     3100
 }
 
+fn default_max_concurrent_downloads() -> usize {
+    3
+}
+
+fn default_conversation_poll_interval() -> u64 {
+    60
+}
+
+fn default_submission_folder_template() -> String {
+    "{login_id}_{name}{late}".to_owned()
+}
+
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
@@ -222,9 +265,13 @@ impl Default for AppConfig {
             color_primary: Default::default(),
             mcp_enabled: Default::default(),
             mcp_port: 3100,
+            mcp_allow_write: Default::default(),
             debug_mode: Default::default(),
             llm_api_keys: Default::default(),
             llm_active_api_key: Default::default(),
+            max_concurrent_downloads: default_max_concurrent_downloads(),
+            conversation_poll_interval: default_conversation_poll_interval(),
+            submission_folder_template: default_submission_folder_template(),
         }
     }
 }
@@ -238,6 +285,77 @@ pub struct ProgressPayload {
     pub uuid: String,
     pub processed: u64,
     pub total: u64,
+    #[serde(default)]
+    pub state: DownloadState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadState {
+    Queued,
+    #[default]
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl DownloadState {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            DownloadState::Completed | DownloadState::Failed | DownloadState::Cancelled
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum DownloadTarget {
+    File {
+        file: File,
+        save_dir: String,
+        // Set for course files so that the sync manifest gets updated on completion
+        #[serde(default)]
+        course_sync: Option<CourseSyncTarget>,
+    },
+    Video {
+        video: VideoPlayInfo,
+        save_path: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CourseSyncTarget {
+    pub course_id: i64,
+    pub course_dir: String,
+    pub folder_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadJob {
+    pub id: String,
+    pub target: DownloadTarget,
+    pub priority: i64,
+    pub seq: u64,
+    pub state: DownloadState,
+    pub processed: u64,
+    pub total: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl DownloadJob {
+    pub fn progress(&self) -> ProgressPayload {
+        ProgressPayload {
+            uuid: self.id.clone(),
+            processed: self.processed,
+            total: self.total,
+            state: self.state,
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -277,6 +395,13 @@ pub struct FileChatStreamErrorPayload {
     pub error: String,
 }
 
+// One page of a folder listing, emitted while the rest is still loading
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderFilesPagePayload {
+    pub folder_id: i64,
+    pub files: Vec<File>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Colors {
     pub custom_colors: HashMap<String, String>,
@@ -347,6 +472,173 @@ pub struct Assignment {
     pub score_statistics: Option<ScoreStatistics>,
     #[serde(default)]
     pub grading_type: GradingType,
+    #[serde(default)]
+    pub group_category_id: Option<i64>,
+    #[serde(default)]
+    pub grade_group_students_individually: bool,
+    #[serde(default)]
+    pub is_quiz_assignment: bool,
+    #[serde(default)]
+    pub quiz_id: Option<i64>,
+    #[serde(default)]
+    pub rubric: Vec<RubricCriterion>,
+    #[serde(default)]
+    pub rubric_settings: Option<RubricSettings>,
+    #[serde(default)]
+    pub use_rubric_for_grading: bool,
+    #[serde(default)]
+    pub assignment_group_id: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentGroup {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub position: i64,
+    #[serde(default)]
+    pub group_weight: Option<f64>,
+}
+
+// Fields of an assignment to create or update, dates left empty are cleared
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentInput {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub points_possible: Option<f64>,
+    #[serde(default)]
+    pub grading_type: Option<GradingType>,
+    #[serde(default)]
+    pub submission_types: Vec<String>,
+    #[serde(default)]
+    pub allowed_extensions: Vec<String>,
+    #[serde(default)]
+    pub due_at: Option<String>,
+    #[serde(default)]
+    pub unlock_at: Option<String>,
+    #[serde(default)]
+    pub lock_at: Option<String>,
+    #[serde(default)]
+    pub assignment_group_id: Option<i64>,
+    #[serde(default)]
+    pub published: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricRating {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub long_description: Option<String>,
+    #[serde(default)]
+    pub points: f64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricCriterion {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub long_description: Option<String>,
+    #[serde(default)]
+    pub points: f64,
+    #[serde(default)]
+    pub criterion_use_range: bool,
+    #[serde(default)]
+    pub ratings: Vec<RubricRating>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricSettings {
+    #[serde(default)]
+    pub id: Option<i64>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub points_possible: f64,
+    #[serde(default)]
+    pub free_form_criterion_comments: bool,
+    #[serde(default)]
+    pub hide_score_total: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricCriterionAssessment {
+    #[serde(default)]
+    pub points: Option<f64>,
+    #[serde(default)]
+    pub rating_id: Option<String>,
+    #[serde(default)]
+    pub comments: Option<String>,
+}
+
+// Keyed by criterion id
+pub type RubricAssessment = HashMap<String, RubricCriterionAssessment>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeUpdate {
+    pub student_id: i64,
+    pub grade: String,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+// Canvas' progress of an asynchronous job, e.g. grading through `update_grades`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanvasProgress {
+    pub id: i64,
+    // queued, running, completed or failed
+    #[serde(default)]
+    pub workflow_state: String,
+    #[serde(default)]
+    pub completion: Option<f64>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl CanvasProgress {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.workflow_state.as_str(), "completed" | "failed")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GradeImportStatus {
+    #[default]
+    Update,
+    Unchanged,
+    // rows without a grade are left alone
+    Skipped,
+    Invalid,
+    // set by `apply_grade_import` on the rows it sent
+    Applied,
+    Failed,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeImportRow {
+    // 1-based row number in the spreadsheet
+    pub row: usize,
+    // the student id or login_id as written in the spreadsheet
+    pub student: String,
+    pub student_id: Option<i64>,
+    pub student_name: Option<String>,
+    pub grade: String,
+    pub comment: Option<String>,
+    pub current_grade: Option<String>,
+    pub status: GradeImportStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeImportPreview {
+    pub rows: Vec<GradeImportRow>,
+    pub applied: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -393,6 +685,84 @@ pub struct AssignmentOverride {
     pub lock_at: Option<String>,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadlineDates {
+    #[serde(default)]
+    pub due_at: Option<String>,
+    #[serde(default)]
+    pub unlock_at: Option<String>,
+    #[serde(default)]
+    pub lock_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeadlineOperation {
+    // moves the dates of the assignments and their overrides, every assignment when empty
+    Shift {
+        assignment_ids: Vec<i64>,
+        days: i64,
+    },
+    // gives the student `days` more than their current dates on each assignment
+    Extend {
+        student_id: i64,
+        assignment_ids: Vec<i64>,
+        days: i64,
+    },
+    CopyOverrides {
+        source_assignment_id: i64,
+        target_assignment_ids: Vec<i64>,
+    },
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadlineChangeKind {
+    #[default]
+    UpdateAssignment,
+    UpdateOverride,
+    CreateOverride,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadlineChange {
+    pub kind: DeadlineChangeKind,
+    pub assignment_id: i64,
+    pub assignment_name: String,
+    // the updated override, or the created one once applied
+    #[serde(default)]
+    pub override_id: Option<i64>,
+    // who a created override applies to, same as `AssignmentOverride`
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub student_ids: Vec<i64>,
+    #[serde(default)]
+    pub group_id: i64,
+    #[serde(default)]
+    pub course_section_id: i64,
+    pub before: DeadlineDates,
+    pub after: DeadlineDates,
+    // set once the change is reverted, a failed revert is retried from the first unset one
+    #[serde(default)]
+    pub reverted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadlineBatch {
+    pub id: String,
+    pub course_id: i64,
+    pub operation: DeadlineOperation,
+    pub changes: Vec<DeadlineChange>,
+    // what could not be planned, e.g. students already in another override
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    #[serde(default)]
+    pub applied_at: Option<String>,
+    #[serde(default)]
+    pub reverted_at: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum WorkflowState {
@@ -410,6 +780,8 @@ pub struct Submission {
     pub submitted_at: Option<String>,
     #[serde(default)]
     pub grade: Option<String>,
+    #[serde(default)]
+    pub score: Option<f64>,
     pub assignment_id: i64,
     pub user_id: i64,
     pub late: bool,
@@ -418,6 +790,59 @@ pub struct Submission {
     #[serde(default)]
     pub submission_comments: Vec<SubmissionComment>,
     pub workflow_state: WorkflowState,
+    // only present when requested with `include[]=group`
+    #[serde(default)]
+    pub group: Option<SubmissionGroup>,
+    // only present when requested with `include[]=rubric_assessment`
+    #[serde(default)]
+    pub rubric_assessment: Option<RubricAssessment>,
+    #[serde(default)]
+    pub attempt: Option<i64>,
+    // every attempt including the latest one, only present when requested with
+    // `include[]=submission_history`
+    #[serde(default)]
+    pub submission_history: Vec<Submission>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarityPair {
+    pub user_id: i64,
+    pub user_name: String,
+    pub login_id: String,
+    pub other_user_id: i64,
+    pub other_user_name: String,
+    pub other_login_id: String,
+    // jaccard similarity of the word shingles, between 0 and 1
+    pub similarity: f64,
+    pub matched_passages: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarityReport {
+    pub assignment_id: i64,
+    pub compared_count: usize,
+    // students whose attachments have no readable text
+    pub skipped_user_ids: Vec<i64>,
+    // most similar first
+    pub pairs: Vec<SimilarityPair>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionDownloadOptions {
+    // keeps every attempt in its own sub directory instead of only the latest one
+    #[serde(default)]
+    pub all_attempts: bool,
+    #[serde(default)]
+    pub zip: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionGroup {
+    // both are null for students outside of any group
+    #[serde(default)]
+    pub id: Option<i64>,
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -752,6 +1177,97 @@ pub struct SubmissionComment {
     pub attachments: Vec<Attachment>,
 }
 
+// tagged with the matching canvas submission type, e.g. "online_upload"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum NewSubmission {
+    #[serde(rename = "online_upload")]
+    Upload { file_paths: Vec<String> },
+    #[serde(rename = "online_text_entry")]
+    TextEntry { body: String },
+    #[serde(rename = "online_url")]
+    Url { url: String },
+}
+
+impl NewSubmission {
+    // The value canvas uses in `Assignment.submission_types`
+    pub fn submission_type(&self) -> &'static str {
+        match self {
+            NewSubmission::Upload { .. } => "online_upload",
+            NewSubmission::TextEntry { .. } => "online_text_entry",
+            NewSubmission::Url { .. } => "online_url",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionIssueKind {
+    SubmissionType,
+    NotUnlocked,
+    Locked,
+    Late,
+    MissingFile,
+    Extension,
+    EmptyFile,
+    LargeFile,
+    EmptyContent,
+    InvalidUrl,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionIssue {
+    pub kind: SubmissionIssueKind,
+    pub message: String,
+    #[serde(default)]
+    pub file_path: Option<String>,
+}
+
+// Errors block the submission, warnings only need the student's confirmation
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionValidation {
+    pub errors: Vec<SubmissionIssue>,
+    pub warnings: Vec<SubmissionIssue>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewSubmissionComment {
+    #[serde(default)]
+    pub text: String,
+    // local files uploaded and attached to the comment
+    #[serde(default)]
+    pub file_paths: Vec<String>,
+    // an existing canvas media object, e.g. from another `MediaComment`
+    #[serde(default)]
+    pub media_comment_id: Option<String>,
+    // "audio" or "video"
+    #[serde(default)]
+    pub media_comment_type: Option<String>,
+    // sends the comment to every member of the student's group
+    #[serde(default)]
+    pub group_comment: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackFileMatch {
+    pub student_id: i64,
+    pub login_id: String,
+    pub name: String,
+    pub file_paths: Vec<String>,
+    // set once the feedback of this student is posted, a retry skips it
+    #[serde(default)]
+    pub uploaded: bool,
+    // set once uploading the feedback of this student failed
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackUploadPlan {
+    pub matches: Vec<FeedbackFileMatch>,
+    pub unmatched_files: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
@@ -893,15 +1409,326 @@ pub struct Permissions {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct FullDiscussion {
+pub struct Group {
+    pub id: i64,
     #[serde(default)]
-    pub unread_entries: Vec<i64>,
+    pub name: String,
     #[serde(default)]
-    pub new_entries: Vec<i64>,
+    pub description: Option<String>,
     #[serde(default)]
-    pub participants: Vec<Participant>,
+    pub group_category_id: Option<i64>,
     #[serde(default)]
-    pub view: Vec<DiscussionView>,
+    pub course_id: Option<i64>,
+    #[serde(default)]
+    pub members_count: i64,
+    #[serde(default)]
+    pub max_membership: Option<i64>,
+    #[serde(default)]
+    pub join_level: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupCategory {
+    pub id: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub self_signup: Option<String>,
+    #[serde(default)]
+    pub group_limit: Option<i64>,
+    #[serde(default)]
+    pub course_id: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupMembership {
+    pub id: i64,
+    pub group_id: i64,
+    pub user_id: i64,
+    // "accepted", "invited" or "requested"
+    #[serde(default)]
+    pub workflow_state: String,
+    #[serde(default)]
+    pub moderator: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationParticipant {
+    pub id: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub full_name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationMessage {
+    pub id: i64,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub author_id: i64,
+    #[serde(default)]
+    pub generated: bool,
+    #[serde(default)]
+    pub participating_user_ids: Vec<i64>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conversation {
+    pub id: i64,
+    #[serde(default)]
+    pub subject: Option<String>,
+    // "read", "unread" or "archived"
+    #[serde(default)]
+    pub workflow_state: String,
+    #[serde(default)]
+    pub last_message: Option<String>,
+    #[serde(default)]
+    pub last_message_at: Option<String>,
+    #[serde(default)]
+    pub message_count: i64,
+    #[serde(default)]
+    pub subscribed: bool,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub starred: bool,
+    #[serde(default)]
+    pub audience: Vec<i64>,
+    #[serde(default)]
+    pub participants: Vec<ConversationParticipant>,
+    #[serde(default)]
+    pub context_name: Option<String>,
+    #[serde(default)]
+    pub context_code: Option<String>,
+    // only returned when fetching a single conversation
+    #[serde(default)]
+    pub messages: Vec<ConversationMessage>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewConversation {
+    // user ids, or context recipients such as "course_123_students"
+    pub recipients: Vec<String>,
+    #[serde(default)]
+    pub subject: String,
+    pub body: String,
+    #[serde(default)]
+    pub attachment_paths: Vec<String>,
+    #[serde(default)]
+    pub context_code: Option<String>,
+    // send a private copy to every recipient instead of one group conversation
+    #[serde(default)]
+    pub bulk_message: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quiz {
+    pub id: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    // "assignment", "practice_quiz", "graded_survey" or "survey"
+    #[serde(default)]
+    pub quiz_type: String,
+    // only graded quizzes are backed by an assignment
+    #[serde(default)]
+    pub assignment_id: Option<i64>,
+    #[serde(default)]
+    pub time_limit: Option<i64>,
+    // -1 means unlimited
+    #[serde(default)]
+    pub allowed_attempts: i64,
+    #[serde(default)]
+    pub question_count: i64,
+    #[serde(default)]
+    pub points_possible: Option<f64>,
+    #[serde(default)]
+    pub due_at: Option<String>,
+    #[serde(default)]
+    pub lock_at: Option<String>,
+    #[serde(default)]
+    pub unlock_at: Option<String>,
+    #[serde(default)]
+    pub published: bool,
+    #[serde(default)]
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub hide_results: Option<String>,
+    #[serde(default)]
+    pub all_dates: Vec<AssignmentDate>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizSubmission {
+    pub id: i64,
+    pub quiz_id: i64,
+    #[serde(default)]
+    pub user_id: i64,
+    #[serde(default)]
+    pub submission_id: Option<i64>,
+    #[serde(default)]
+    pub attempt: Option<i64>,
+    #[serde(default)]
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub kept_score: Option<f64>,
+    #[serde(default)]
+    pub quiz_points_possible: Option<f64>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(default)]
+    pub finished_at: Option<String>,
+    #[serde(default)]
+    pub end_at: Option<String>,
+    #[serde(default)]
+    pub time_spent: Option<i64>,
+    #[serde(default)]
+    pub attempts_left: Option<i64>,
+    // "untaken", "pending_review", "complete", ...
+    #[serde(default)]
+    pub workflow_state: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizSubmissionsResponse {
+    #[serde(default)]
+    pub quiz_submissions: Vec<QuizSubmission>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizAttempt {
+    #[serde(default)]
+    pub attempt: Option<i64>,
+    #[serde(default)]
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub submitted_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizSubmissionHistory {
+    pub quiz_submissions: Vec<QuizSubmission>,
+    // every graded attempt, empty for quizzes without an assignment
+    pub attempts: Vec<QuizAttempt>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizAnswer {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default, alias = "answer_text")]
+    pub text: String,
+    #[serde(default)]
+    pub html: Option<String>,
+    // 100 for correct answers, only visible when canvas reveals them
+    #[serde(default)]
+    pub weight: Option<f64>,
+    #[serde(default)]
+    pub comments: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizQuestion {
+    pub id: i64,
+    #[serde(default)]
+    pub quiz_id: i64,
+    #[serde(default)]
+    pub position: Option<i64>,
+    #[serde(default)]
+    pub question_name: String,
+    #[serde(default)]
+    pub question_type: String,
+    #[serde(default)]
+    pub question_text: String,
+    #[serde(default)]
+    pub points_possible: Option<f64>,
+    #[serde(default)]
+    pub correct_comments: Option<String>,
+    #[serde(default)]
+    pub incorrect_comments: Option<String>,
+    #[serde(default)]
+    pub answers: Vec<QuizAnswer>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page {
+    pub page_id: i64,
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub published: bool,
+    #[serde(default)]
+    pub front_page: bool,
+    #[serde(default)]
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Announcement {
+    pub id: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub posted_at: Option<String>,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    // e.g. "course_123"
+    #[serde(default)]
+    pub context_code: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    // tracked locally, not reported by canvas
+    #[serde(default)]
+    pub read: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnouncementFeed {
+    pub announcements: Vec<Announcement>,
+    pub unread_count: usize,
+    // context_code -> unread announcements
+    pub unread_counts: HashMap<String, usize>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FullDiscussion {
+    #[serde(default)]
+    pub unread_entries: Vec<i64>,
+    #[serde(default)]
+    pub new_entries: Vec<i64>,
+    #[serde(default)]
+    pub participants: Vec<Participant>,
+    #[serde(default)]
+    pub view: Vec<DiscussionView>,
+    // entry_id -> 1 for entries liked by the current user
+    #[serde(default)]
+    pub entry_ratings: HashMap<i64, i64>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -961,6 +1788,36 @@ pub struct Reply {
     pub message: Option<String>,
 }
 
+// An entry or reply as returned when posting to a discussion
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscussionEntry {
+    pub id: i64,
+    #[serde(default)]
+    pub user_id: i64,
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscussionTopicInput {
+    pub title: String,
+    #[serde(default)]
+    pub message: String,
+    // threaded topics allow replies to replies, otherwise replies are one level deep
+    #[serde(default)]
+    pub threaded: bool,
+    #[serde(default)]
+    pub published: bool,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoreStatistics {
     pub min: f64,
@@ -985,6 +1842,84 @@ impl FoldersAndFiles {
     }
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncManifestEntry {
+    pub file_id: i64,
+    pub display_name: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    pub size: u64,
+    // relative to the course directory
+    pub local_path: String,
+    #[serde(default)]
+    pub versions: Vec<FileVersion>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileVersion {
+    pub version: u32,
+    pub display_name: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    pub size: u64,
+    // relative to the course directory
+    pub local_path: String,
+    pub archived_at: String,
+}
+
+impl SyncManifestEntry {
+    pub fn new(file: &File, local_path: String) -> Self {
+        Self {
+            file_id: file.id,
+            display_name: file.display_name.clone(),
+            updated_at: file.updated_at.clone(),
+            size: file.size,
+            local_path,
+            versions: vec![],
+        }
+    }
+
+    pub fn is_outdated_by(&self, file: &File) -> bool {
+        self.size != file.size || self.updated_at != file.updated_at
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub path: String,
+    pub file_name: String,
+    // course directory name under the save path, empty for files outside of courses
+    pub course: String,
+    pub snippet: String,
+    pub score: f64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncManifest {
+    pub course_id: i64,
+    // file_id -> entry
+    #[serde(default)]
+    pub entries: HashMap<i64, SyncManifestEntry>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncFileChange {
+    pub file: File,
+    // relative to the course directory, empty for the root folder
+    pub folder_path: String,
+    #[serde(default)]
+    pub previous: Option<SyncManifestEntry>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CourseSyncDiff {
+    pub new_files: Vec<SyncFileChange>,
+    pub modified_files: Vec<SyncFileChange>,
+    pub renamed_files: Vec<SyncFileChange>,
+    pub removed_files: Vec<SyncManifestEntry>,
+    pub unchanged_count: usize,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CanvasVideoResponse {
@@ -1107,6 +2042,14 @@ pub struct AnnualCourseStatistic {
     pub total_points_possible: f64,
     pub total_score: f64,
     pub submit_time_list: Vec<String>,
+    #[serde(default)]
+    pub quiz_count: i32,
+    #[serde(default)]
+    pub quiz_submitted_count: i32,
+    #[serde(default)]
+    pub quiz_points_possible: f64,
+    #[serde(default)]
+    pub quiz_total_score: f64,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -2,6 +2,7 @@ use crate::error::Result;
 use std::fs;
 use std::fs::File;
 use std::io::{Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 pub fn write_file_at_offset(file: &mut File, data: &[u8], offset: u64) -> std::io::Result<()> {
@@ -19,6 +20,27 @@ pub fn get_file_name(file_path: &str) -> String {
         .to_owned()
 }
 
+// Replaces characters that are not allowed in file names on any platform
+pub fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+// Temporary path an in-progress download is written to
+pub fn get_part_path(path: &Path) -> PathBuf {
+    let mut part_path = path.as_os_str().to_owned();
+    part_path.push(".part");
+    PathBuf::from(part_path)
+}
+
 // RAII temp file
 pub struct TempFile {
     path: String,
@@ -67,6 +89,12 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name(" a/b\\c:d? "), "a_b_c_d_");
+        assert_eq!(sanitize_file_name("report."), "report");
+    }
+
     #[test]
     fn test_temp_file() -> Result<()> {
         let path: String;
@@ -0,0 +1,218 @@
+use select::{document::Document, node::Node, predicate::Name};
+
+// Converts a Canvas rich content body to Markdown. Unknown tags are unwrapped so that
+// their text is kept.
+pub fn html_to_markdown(html: &str) -> String {
+    let document = Document::from(html);
+    let Some(body) = document.find(Name("body")).next() else {
+        return String::new();
+    };
+    let mut markdown = String::new();
+    convert_children(&body, &mut markdown);
+    normalize_blank_lines(&markdown)
+}
+
+pub fn extract_image_urls(html: &str) -> Vec<String> {
+    let document = Document::from(html);
+    let mut urls = vec![];
+    for src in document.find(Name("img")).filter_map(|img| img.attr("src")) {
+        if !urls.iter().any(|url| url == src) {
+            urls.push(src.to_owned());
+        }
+    }
+    urls
+}
+
+fn convert_children(node: &Node, out: &mut String) {
+    for child in node.children() {
+        convert_node(&child, out);
+    }
+}
+
+fn inline_text(node: &Node) -> String {
+    let mut text = String::new();
+    convert_children(node, &mut text);
+    text.trim().to_owned()
+}
+
+// Collapse whitespace like a browser would, keeping a single space between words
+fn push_text(out: &mut String, text: &str) {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let leading_space = text.starts_with(char::is_whitespace);
+    if leading_space && !out.is_empty() && !out.ends_with([' ', '\n']) {
+        out.push(' ');
+    }
+    if words.is_empty() {
+        return;
+    }
+    out.push_str(&words.join(" "));
+    if text.ends_with(char::is_whitespace) {
+        out.push(' ');
+    }
+}
+
+fn convert_node(node: &Node, out: &mut String) {
+    if let Some(text) = node.as_text() {
+        push_text(out, text);
+        return;
+    }
+    let Some(name) = node.name() else {
+        return;
+    };
+    match name {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = name[1..].parse().unwrap_or(1);
+            out.push_str(&format!(
+                "\n\n{} {}\n\n",
+                "#".repeat(level),
+                inline_text(node)
+            ));
+        }
+        "p" | "div" | "section" | "article" => {
+            out.push_str("\n\n");
+            convert_children(node, out);
+            out.push_str("\n\n");
+        }
+        "br" => out.push_str("  \n"),
+        "hr" => out.push_str("\n\n---\n\n"),
+        "strong" | "b" => wrap_inline(node, out, "**"),
+        "em" | "i" => wrap_inline(node, out, "*"),
+        "del" | "s" => wrap_inline(node, out, "~~"),
+        "code" => wrap_inline(node, out, "`"),
+        "a" => {
+            let text = inline_text(node);
+            match node.attr("href") {
+                Some(href) if !text.is_empty() => out.push_str(&format!("[{text}]({href})")),
+                _ => out.push_str(&text),
+            }
+        }
+        "img" => {
+            let alt = node.attr("alt").unwrap_or_default();
+            if let Some(src) = node.attr("src") {
+                out.push_str(&format!("![{alt}]({src})"));
+            }
+        }
+        "pre" => {
+            out.push_str(&format!("\n\n```\n{}\n```\n\n", node.text().trim_end()));
+        }
+        "blockquote" => {
+            let mut inner = String::new();
+            convert_children(node, &mut inner);
+            out.push_str("\n\n");
+            for line in normalize_blank_lines(&inner).lines() {
+                out.push_str(&format!("> {line}\n"));
+            }
+            out.push('\n');
+        }
+        "ul" | "ol" => {
+            out.push('\n');
+            let ordered = name == "ol";
+            let items = node.children().filter(|child| child.name() == Some("li"));
+            for (index, item) in (1..).zip(items) {
+                let marker = if ordered {
+                    format!("{index}.")
+                } else {
+                    "-".to_owned()
+                };
+                let mut inner = String::new();
+                convert_children(&item, &mut inner);
+                let inner = normalize_blank_lines(&inner);
+                for (i, line) in inner.lines().filter(|line| !line.is_empty()).enumerate() {
+                    if i == 0 {
+                        out.push_str(&format!("{marker} {}\n", line.trim_start()));
+                    } else {
+                        out.push_str(&format!("  {line}\n"));
+                    }
+                }
+            }
+            out.push('\n');
+        }
+        "table" => convert_table(node, out),
+        "script" | "style" | "head" => {}
+        _ => convert_children(node, out),
+    }
+}
+
+fn wrap_inline(node: &Node, out: &mut String, marker: &str) {
+    let text = inline_text(node);
+    if !text.is_empty() {
+        out.push_str(&format!("{marker}{text}{marker}"));
+    }
+}
+
+fn convert_table(node: &Node, out: &mut String) {
+    let rows: Vec<Vec<String>> = node
+        .find(Name("tr"))
+        .map(|row| {
+            row.children()
+                .filter(|cell| matches!(cell.name(), Some("td" | "th")))
+                .map(|cell| inline_text(&cell).replace('|', "\\|").replace('\n', " "))
+                .collect()
+        })
+        .filter(|row: &Vec<String>| !row.is_empty())
+        .collect();
+    let Some(columns) = rows.iter().map(Vec::len).max() else {
+        return;
+    };
+    out.push_str("\n\n");
+    for (i, row) in rows.iter().enumerate() {
+        let mut cells = row.clone();
+        cells.resize(columns, String::new());
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        if i == 0 {
+            out.push_str(&format!("|{}\n", " --- |".repeat(columns)));
+        }
+    }
+    out.push('\n');
+}
+
+// Collapse runs of blank lines and trailing spaces left by block elements
+fn normalize_blank_lines(markdown: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    for line in markdown.lines() {
+        let is_hard_break = line.ends_with("  ") && !line.trim().is_empty();
+        let line = if is_hard_break { line } else { line.trim_end() };
+        if line.trim().is_empty() && lines.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|last| last.trim().is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_markdown() {
+        let html = r#"<h2>Week 1</h2>
+<p>Read the <strong>syllabus</strong> and <a href="https://oc.sjtu.edu.cn/courses/1/files/2">slides</a>.</p>
+<ul><li>Install <code>gcc</code></li><li>Join the group<ol><li>Step one</li></ol></li></ul>
+<p><img src="https://oc.sjtu.edu.cn/courses/1/files/3/preview" alt="diagram"></p>
+<table><tr><th>Date</th><th>Topic</th></tr><tr><td>3/1</td><td>Intro</td></tr></table>"#;
+        let expected = "## Week 1
+
+Read the **syllabus** and [slides](https://oc.sjtu.edu.cn/courses/1/files/2).
+
+- Install `gcc`
+- Join the group
+  1. Step one
+
+![diagram](https://oc.sjtu.edu.cn/courses/1/files/3/preview)
+
+| Date | Topic |
+| --- | --- |
+| 3/1 | Intro |";
+        assert_eq!(html_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn test_extract_image_urls() {
+        let html = r#"<p><img src="a.png"><img src="b.png"><img src="a.png"></p>"#;
+        assert_eq!(extract_image_urls(html), vec!["a.png", "b.png"]);
+    }
+}
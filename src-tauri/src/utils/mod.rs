@@ -1,3 +1,4 @@
-pub mod json;
 pub mod file;
+pub mod html;
+pub mod json;
 pub mod time;
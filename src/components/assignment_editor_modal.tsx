@@ -0,0 +1,307 @@
+import { invoke } from "@tauri-apps/api/core";
+import {
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  FormControlLabel,
+  MenuItem,
+  Stack,
+  Switch,
+  TextField,
+  Typography,
+} from "@mui/material";
+import dayjs from "dayjs";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  AssignmentGroup,
+  AssignmentInput,
+  LOG_LEVEL_ERROR,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const SUBMISSION_TYPES: Record<string, string> = {
+  online_upload: "文件上传",
+  online_text_entry: "文本输入",
+  online_url: "网址",
+  on_paper: "纸质提交",
+  none: "无需提交",
+};
+
+const GRADING_TYPES: Record<string, string> = {
+  points: "分数",
+  percent: "百分比",
+  pass_fail: "通过/未通过",
+  letter_grade: "等级",
+  gpa_scale: "GPA",
+  not_graded: "不评分",
+};
+
+function toDateTimeLocal(value?: string | null) {
+  if (!value) {
+    return "";
+  }
+  return dayjs(value).format("YYYY-MM-DDTHH:mm");
+}
+
+function fromDateTimeLocal(value: string) {
+  return value ? dayjs(value).toISOString() : undefined;
+}
+
+function toInput(assignment?: Assignment): AssignmentInput {
+  if (!assignment) {
+    return {
+      name: "",
+      points_possible: 100,
+      grading_type: "points",
+      submission_types: ["online_upload"],
+      allowed_extensions: [],
+      published: false,
+    };
+  }
+  return {
+    name: assignment.name,
+    description: assignment.description ?? undefined,
+    points_possible: assignment.points_possible ?? undefined,
+    grading_type: assignment.grading_type,
+    submission_types: assignment.submission_types,
+    allowed_extensions: assignment.allowed_extensions,
+    due_at: assignment.due_at ?? undefined,
+    unlock_at: assignment.unlock_at ?? undefined,
+    lock_at: assignment.lock_at ?? undefined,
+    assignment_group_id: assignment.assignment_group_id ?? undefined,
+    published: assignment.published,
+  };
+}
+
+export default function AssignmentEditorModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  // creates a new assignment when not given
+  assignment?: Assignment;
+  handleCancel?: () => void;
+  onSuccess?: (assignment: Assignment) => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [input, setInput] = useState<AssignmentInput>(toInput(assignment));
+  const [extensions, setExtensions] = useState("");
+  const [groups, setGroups] = useState<AssignmentGroup[]>([]);
+  const [submitting, setSubmitting] = useState(false);
+
+  useEffect(() => {
+    if (!open) {
+      return;
+    }
+    const nextInput = toInput(assignment);
+    setInput(nextInput);
+    setExtensions(nextInput.allowed_extensions.join(", "));
+    const handleGetGroups = async () => {
+      try {
+        const nextGroups = (await invoke("list_assignment_groups", {
+          courseId,
+        })) as AssignmentGroup[];
+        setGroups(nextGroups);
+      } catch (error) {
+        consoleLog(LOG_LEVEL_ERROR, error);
+      }
+    };
+    void handleGetGroups();
+  }, [open, courseId, assignment]);
+
+  const updateInput = (update: Partial<AssignmentInput>) => {
+    setInput((prev) => ({ ...prev, ...update }));
+  };
+
+  const handleSubmit = async () => {
+    if (!input.name.trim()) {
+      messageApi.error("请输入作业名称");
+      return;
+    }
+    const nextInput = {
+      ...input,
+      allowed_extensions: extensions
+        .split(/[,，\s]+/)
+        .map((extension) => extension.replace(/^\./, "").trim())
+        .filter((extension) => extension),
+    };
+    try {
+      setSubmitting(true);
+      const saved = (await (assignment
+        ? invoke("update_assignment", {
+            courseId,
+            assignmentId: assignment.id,
+            input: nextInput,
+          })
+        : invoke("create_assignment", { courseId, input: nextInput }))) as Assignment;
+      messageApi.success("保存成功", 0.5);
+      onSuccess?.(saved);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`保存失败：${error}`);
+    } finally {
+      setSubmitting(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="md">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Typography variant="h6" sx={{ fontWeight: 800 }}>
+          {assignment ? "编辑作业" : "新建作业"}
+        </Typography>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2} sx={{ pt: 1 }}>
+          <TextField
+            label="作业名称"
+            required
+            value={input.name}
+            onChange={(event) => updateInput({ name: event.target.value })}
+          />
+          <TextField
+            label="作业说明（HTML）"
+            multiline
+            minRows={4}
+            value={input.description ?? ""}
+            onChange={(event) =>
+              updateInput({ description: event.target.value })
+            }
+          />
+          <Stack direction={{ xs: "column", sm: "row" }} spacing={1.5}>
+            <TextField
+              label="满分"
+              type="number"
+              value={input.points_possible ?? ""}
+              onChange={(event) =>
+                updateInput({
+                  points_possible:
+                    event.target.value === ""
+                      ? undefined
+                      : Number(event.target.value),
+                })
+              }
+            />
+            <TextField
+              select
+              label="评分方式"
+              sx={{ minWidth: 160 }}
+              value={input.grading_type ?? "points"}
+              onChange={(event) =>
+                updateInput({ grading_type: event.target.value })
+              }
+            >
+              {Object.entries(GRADING_TYPES).map(([value, label]) => (
+                <MenuItem key={value} value={value}>
+                  {label}
+                </MenuItem>
+              ))}
+            </TextField>
+            <TextField
+              select
+              fullWidth
+              label="作业组"
+              value={input.assignment_group_id ?? ""}
+              onChange={(event) =>
+                updateInput({
+                  assignment_group_id:
+                    event.target.value === ""
+                      ? undefined
+                      : Number(event.target.value),
+                })
+              }
+            >
+              <MenuItem value="">默认</MenuItem>
+              {groups.map((group) => (
+                <MenuItem key={group.id} value={group.id}>
+                  {group.name}
+                </MenuItem>
+              ))}
+            </TextField>
+          </Stack>
+          <Stack direction={{ xs: "column", sm: "row" }} spacing={1.5}>
+            <TextField
+              select
+              fullWidth
+              label="提交方式"
+              value={input.submission_types}
+              slotProps={{ select: { multiple: true } }}
+              onChange={(event) =>
+                updateInput({
+                  submission_types: event.target.value as unknown as string[],
+                })
+              }
+            >
+              {Object.entries(SUBMISSION_TYPES).map(([value, label]) => (
+                <MenuItem key={value} value={value}>
+                  {label}
+                </MenuItem>
+              ))}
+            </TextField>
+            <TextField
+              fullWidth
+              label="允许的文件类型"
+              placeholder="pdf, zip"
+              value={extensions}
+              onChange={(event) => setExtensions(event.target.value)}
+            />
+          </Stack>
+          <Stack direction={{ xs: "column", sm: "row" }} spacing={1.5}>
+            {(
+              [
+                ["unlock_at", "开放时间"],
+                ["due_at", "截止时间"],
+                ["lock_at", "关闭时间"],
+              ] as const
+            ).map(([key, label]) => (
+              <TextField
+                key={key}
+                fullWidth
+                label={label}
+                type="datetime-local"
+                InputLabelProps={{ shrink: true }}
+                value={toDateTimeLocal(input[key])}
+                onChange={(event) =>
+                  updateInput({ [key]: fromDateTimeLocal(event.target.value) })
+                }
+              />
+            ))}
+          </Stack>
+          <FormControlLabel
+            control={
+              <Switch
+                checked={input.published ?? false}
+                onChange={(event) =>
+                  updateInput({ published: event.target.checked })
+                }
+              />
+            }
+            label="发布"
+          />
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>取消</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleSubmit()}
+          disabled={submitting}
+        >
+          保存
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
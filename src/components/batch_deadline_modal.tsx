@@ -0,0 +1,358 @@
+import { invoke } from "@tauri-apps/api/core";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  MenuItem,
+  Stack,
+  Tab,
+  Table,
+  TableBody,
+  TableCell,
+  TableContainer,
+  TableHead,
+  TableRow,
+  Tabs,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useStudents } from "../lib/hooks";
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  DeadlineBatch,
+  DeadlineChangeKind,
+  DeadlineOperation,
+  LOG_LEVEL_ERROR,
+} from "../lib/model";
+import { consoleLog, formatDate } from "../lib/utils";
+
+type OperationType = DeadlineOperation["type"];
+
+const OPERATION_LABELS: Record<OperationType, string> = {
+  shift: "整体平移",
+  extend: "学生延期",
+  copy_overrides: "复制例外设置",
+};
+
+const CHANGE_LABELS: Record<DeadlineChangeKind, string> = {
+  update_assignment: "修改作业",
+  update_override: "修改例外",
+  create_override: "新建例外",
+};
+
+export default function BatchDeadlineModal({
+  open,
+  courseId,
+  assignments,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  assignments: Assignment[];
+  handleCancel?: () => void;
+  onSuccess?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const students = useStudents(open ? courseId : undefined);
+  const [type, setType] = useState<OperationType>("shift");
+  const [assignmentIds, setAssignmentIds] = useState<number[]>([]);
+  const [days, setDays] = useState(7);
+  const [studentId, setStudentId] = useState<number | "">("");
+  const [sourceAssignmentId, setSourceAssignmentId] = useState<number | "">(
+    ""
+  );
+  const [preview, setPreview] = useState<DeadlineBatch | undefined>();
+  const [history, setHistory] = useState<DeadlineBatch[]>([]);
+  const [operating, setOperating] = useState(false);
+
+  const handleGetHistory = async () => {
+    try {
+      const batches = (await invoke("list_deadline_batches", {
+        courseId,
+      })) as DeadlineBatch[];
+      setHistory(batches);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+    }
+  };
+
+  useEffect(() => {
+    if (open) {
+      setPreview(undefined);
+      void handleGetHistory();
+    }
+  }, [open, courseId]);
+
+  useEffect(() => {
+    setPreview(undefined);
+  }, [type, assignmentIds, days, studentId, sourceAssignmentId]);
+
+  const getOperation = (): DeadlineOperation | undefined => {
+    switch (type) {
+      case "shift":
+        return { type, assignment_ids: assignmentIds, days };
+      case "extend":
+        return studentId === ""
+          ? undefined
+          : { type, student_id: studentId, assignment_ids: assignmentIds, days };
+      case "copy_overrides":
+        return sourceAssignmentId === ""
+          ? undefined
+          : {
+              type,
+              source_assignment_id: sourceAssignmentId,
+              target_assignment_ids: assignmentIds,
+            };
+    }
+  };
+
+  const handlePreview = async () => {
+    const operation = getOperation();
+    if (!operation) {
+      messageApi.error("请完善操作参数");
+      return;
+    }
+    try {
+      setOperating(true);
+      const nextPreview = (await invoke("preview_deadline_operation", {
+        courseId,
+        operation,
+      })) as DeadlineBatch;
+      setPreview(nextPreview);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`预览失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const handleApply = async () => {
+    if (!preview) {
+      return;
+    }
+    try {
+      setOperating(true);
+      const batch = (await invoke("apply_deadline_batch", {
+        batch: preview,
+      })) as DeadlineBatch;
+      messageApi.success(`已修改 ${batch.changes.length} 项日期`, 0.5);
+      setPreview(undefined);
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`修改失败：${error}`);
+    } finally {
+      setOperating(false);
+      void handleGetHistory();
+    }
+  };
+
+  const handleRevert = async (batchId: string) => {
+    try {
+      setOperating(true);
+      await invoke("revert_deadline_batch", { batchId });
+      messageApi.success("撤销成功", 0.5);
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`撤销失败：${error}`);
+    } finally {
+      setOperating(false);
+      void handleGetHistory();
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="lg">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            批量修改日期
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            修改前可预览所有变更，每次批量修改都会记录在历史中，可随时撤销。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Tabs value={type} onChange={(_, value) => setType(value)}>
+            {Object.entries(OPERATION_LABELS).map(([value, label]) => (
+              <Tab key={value} value={value} label={label} />
+            ))}
+          </Tabs>
+
+          <Stack direction={{ xs: "column", md: "row" }} spacing={1.5}>
+            {type === "extend" && (
+              <TextField
+                select
+                label="学生"
+                sx={{ minWidth: 200 }}
+                value={studentId}
+                onChange={(event) => setStudentId(Number(event.target.value))}
+              >
+                {students.data.map((student) => (
+                  <MenuItem key={student.id} value={student.id}>
+                    {student.name}（{student.login_id}）
+                  </MenuItem>
+                ))}
+              </TextField>
+            )}
+            {type === "copy_overrides" && (
+              <TextField
+                select
+                label="源作业"
+                sx={{ minWidth: 240 }}
+                value={sourceAssignmentId}
+                onChange={(event) =>
+                  setSourceAssignmentId(Number(event.target.value))
+                }
+              >
+                {assignments.map((assignment) => (
+                  <MenuItem key={assignment.id} value={assignment.id}>
+                    {assignment.name}
+                  </MenuItem>
+                ))}
+              </TextField>
+            )}
+            <TextField
+              select
+              fullWidth
+              label={
+                type === "copy_overrides"
+                  ? "目标作业"
+                  : type === "shift"
+                  ? "作业（不选则为全部作业）"
+                  : "作业"
+              }
+              value={assignmentIds}
+              slotProps={{ select: { multiple: true } }}
+              onChange={(event) =>
+                setAssignmentIds(event.target.value as unknown as number[])
+              }
+            >
+              {assignments.map((assignment) => (
+                <MenuItem key={assignment.id} value={assignment.id}>
+                  {assignment.name}
+                </MenuItem>
+              ))}
+            </TextField>
+            {type !== "copy_overrides" && (
+              <TextField
+                label="天数"
+                type="number"
+                sx={{ minWidth: 120 }}
+                value={days}
+                onChange={(event) => setDays(Number(event.target.value))}
+              />
+            )}
+          </Stack>
+
+          {preview && (
+            <>
+              {preview.warnings.map((warning) => (
+                <Alert
+                  key={warning}
+                  severity="warning"
+                  sx={{ borderRadius: "18px" }}
+                >
+                  {warning}
+                </Alert>
+              ))}
+              <TableContainer sx={{ maxHeight: 360 }}>
+                <Table size="small" stickyHeader>
+                  <TableHead>
+                    <TableRow>
+                      <TableCell>作业</TableCell>
+                      <TableCell>操作</TableCell>
+                      <TableCell>对象</TableCell>
+                      <TableCell>截止时间</TableCell>
+                      <TableCell>关闭时间</TableCell>
+                    </TableRow>
+                  </TableHead>
+                  <TableBody>
+                    {preview.changes.map((change, index) => (
+                      <TableRow key={index}>
+                        <TableCell>{change.assignment_name}</TableCell>
+                        <TableCell>{CHANGE_LABELS[change.kind]}</TableCell>
+                        <TableCell>
+                          {change.kind === "update_assignment"
+                            ? "所有人"
+                            : change.title}
+                        </TableCell>
+                        <TableCell>
+                          {formatDate(change.before.due_at) || "-"} →{" "}
+                          {formatDate(change.after.due_at) || "-"}
+                        </TableCell>
+                        <TableCell>
+                          {formatDate(change.before.lock_at) || "-"} →{" "}
+                          {formatDate(change.after.lock_at) || "-"}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              </TableContainer>
+            </>
+          )}
+
+          {history.length > 0 && (
+            <Stack spacing={1}>
+              <Typography variant="subtitle2" sx={{ fontWeight: 700 }}>
+                历史记录
+              </Typography>
+              {history.map((batch) => (
+                <Stack
+                  key={batch.id}
+                  direction="row"
+                  spacing={1.5}
+                  alignItems="center"
+                >
+                  <Typography variant="body2" sx={{ flex: 1 }}>
+                    {formatDate(batch.applied_at)} ·{" "}
+                    {OPERATION_LABELS[batch.operation.type]} ·{" "}
+                    {batch.changes.length} 项变更
+                    {batch.reverted_at &&
+                      ` · 已于 ${formatDate(batch.reverted_at)} 撤销`}
+                  </Typography>
+                  <Button
+                    size="small"
+                    color="error"
+                    disabled={operating || !!batch.reverted_at}
+                    onClick={() => void handleRevert(batch.id)}
+                  >
+                    撤销
+                  </Button>
+                </Stack>
+              ))}
+            </Stack>
+          )}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>关闭</Button>
+        <Button disabled={operating} onClick={() => void handlePreview()}>
+          预览
+        </Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleApply()}
+          disabled={operating || !preview || preview.changes.length === 0}
+        >
+          应用 {preview?.changes.length ?? 0} 项变更
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
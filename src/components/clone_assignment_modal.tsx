@@ -0,0 +1,103 @@
+import { invoke } from "@tauri-apps/api/core";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  MenuItem,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useTAOrTeacherCourses } from "../lib/hooks";
+import { useAppMessage } from "../lib/message";
+import { Assignment, LOG_LEVEL_ERROR } from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+export default function CloneAssignmentModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  handleCancel?: () => void;
+  onSuccess?: (cloned: Assignment) => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const courses = useTAOrTeacherCourses();
+  const [targetCourseId, setTargetCourseId] = useState(courseId);
+  const [cloning, setCloning] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setTargetCourseId(courseId);
+    }
+  }, [open, courseId]);
+
+  const handleClone = async () => {
+    try {
+      setCloning(true);
+      const cloned = (await invoke("clone_assignment", {
+        courseId,
+        assignmentId: assignment.id,
+        targetCourseId,
+      })) as Assignment;
+      messageApi.success(`已复制为「${cloned.name}」`, 0.5);
+      onSuccess?.(cloned);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`复制失败：${error}`);
+    } finally {
+      setCloning(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="sm">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Typography variant="h6" sx={{ fontWeight: 800 }}>
+          复制作业
+        </Typography>
+      </DialogTitle>
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2} sx={{ pt: 1 }}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            复制到其他课程的作业默认不发布，作业说明中引用的文件仍指向原课程。
+          </Alert>
+          <TextField
+            select
+            label="目标课程"
+            value={targetCourseId}
+            onChange={(event) => setTargetCourseId(Number(event.target.value))}
+          >
+            {courses.data.map((course) => (
+              <MenuItem key={course.id} value={course.id}>
+                {course.name}
+                {course.id === courseId ? "（当前课程）" : ""}
+              </MenuItem>
+            ))}
+          </TextField>
+        </Stack>
+      </DialogContent>
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>取消</Button>
+        <Button
+          variant="contained"
+          disabled={cloning}
+          onClick={() => void handleClone()}
+        >
+          复制「{assignment.name}」
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
@@ -10,16 +10,15 @@ import {
   ListItemAvatar,
   ListItemText,
   Stack,
-  TextField,
   Typography,
 } from "@mui/material";
 import { invoke } from "@tauri-apps/api/core";
-import { useRef } from "react";
 
 import { useBaseURL } from "../lib/hooks";
 import { AppMessageApi } from "../lib/message";
 import { Attachment, Entry, LOG_LEVEL_ERROR, User } from "../lib/model";
 import { attachmentToFile, consoleLog, formatDate } from "../lib/utils";
+import SubmissionCommentInput from "./submission_comment_input";
 
 export default function CommentPanel({
   attachment,
@@ -47,36 +46,8 @@ export default function CommentPanel({
   onPreviewEntry?: (entry: Entry) => void;
   messageApi: AppMessageApi;
 }) {
-  const commentInputRef = useRef<HTMLInputElement | null>(null);
   const baseURL = useBaseURL();
 
-  const handleCommentSubmission = async (currentAttachment: Attachment) => {
-    const comment = commentInputRef.current?.value;
-    if (!comment) {
-      messageApi.warning("评论不得为空！");
-      return;
-    }
-    try {
-      await invoke("update_grade", {
-        courseId,
-        assignmentId,
-        studentId: currentAttachment.user_id,
-        grade: currentAttachment.grade ?? "",
-        comment,
-      });
-      messageApi.success("评论成功！🎉", 0.5);
-      if (commentInputRef.current) {
-        commentInputRef.current.value = "";
-      }
-      if (currentAttachment.user_id != null) {
-        await onRefresh?.(currentAttachment.user_id);
-      }
-    } catch (e) {
-      consoleLog(LOG_LEVEL_ERROR, e);
-      messageApi.error(e as string);
-    }
-  };
-
   const handleDeleteComment = async (commentId: number, currentAttachment: Attachment) => {
     try {
       await invoke("delete_submission_comment", {
@@ -186,20 +157,18 @@ export default function CommentPanel({
         </>
       ) : null}
 
-      {showInput ? (
+      {showInput && attachment.user_id != null ? (
         <>
           <Divider />
-          <TextField
-            multiline
-            minRows={3}
-            placeholder="请输入评论"
-            inputRef={commentInputRef}
+          <SubmissionCommentInput
+            courseId={courseId}
+            assignmentId={assignmentId}
+            studentId={attachment.user_id}
+            messageApi={messageApi}
+            onSuccess={() => onRefresh?.(attachment.user_id!)}
             onFocus={onFocus}
             onBlur={onBlur}
           />
-          <Button variant="contained" onClick={() => void handleCommentSubmission(attachment)}>
-            发表评论
-          </Button>
         </>
       ) : null}
     </Stack>
@@ -0,0 +1,161 @@
+import { invoke } from "@tauri-apps/api/core";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import {
+  Button,
+  Chip,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  FormControlLabel,
+  Stack,
+  Switch,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  DiscussionTopic,
+  DiscussionTopicInput,
+  LOG_LEVEL_ERROR,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const EMPTY_INPUT: DiscussionTopicInput = {
+  title: "",
+  message: "",
+  threaded: true,
+  published: true,
+};
+
+export default function DiscussionTopicModal({
+  open,
+  courseId,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  handleCancel?: () => void;
+  onSuccess?: (topic: DiscussionTopic) => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [input, setInput] = useState<DiscussionTopicInput>(EMPTY_INPUT);
+  const [attachment, setAttachment] = useState<string | undefined>();
+  const [operating, setOperating] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setInput(EMPTY_INPUT);
+      setAttachment(undefined);
+    }
+  }, [open]);
+
+  const handleSelectAttachment = async () => {
+    const selected = await openDialog({ multiple: false, directory: false });
+    if (typeof selected === "string") {
+      setAttachment(selected);
+    }
+  };
+
+  const handleCreate = async () => {
+    try {
+      setOperating(true);
+      const topic = (await invoke("create_discussion_topic", {
+        courseId,
+        input,
+        attachment,
+      })) as DiscussionTopic;
+      messageApi.success("发布成功", 0.5);
+      onSuccess?.(topic);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`发布失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="md">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Typography variant="h6" sx={{ fontWeight: 800 }}>
+          新建讨论话题
+        </Typography>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2} sx={{ pt: 1 }}>
+          <TextField
+            fullWidth
+            required
+            label="标题"
+            value={input.title}
+            onChange={(event) =>
+              setInput({ ...input, title: event.target.value })
+            }
+          />
+          <TextField
+            fullWidth
+            multiline
+            minRows={6}
+            label="内容（支持 HTML）"
+            value={input.message}
+            onChange={(event) =>
+              setInput({ ...input, message: event.target.value })
+            }
+          />
+          <Stack direction="row" spacing={2} alignItems="center">
+            <FormControlLabel
+              control={
+                <Switch
+                  checked={input.threaded}
+                  onChange={(event) =>
+                    setInput({ ...input, threaded: event.target.checked })
+                  }
+                />
+              }
+              label="允许楼中楼回复"
+            />
+            <FormControlLabel
+              control={
+                <Switch
+                  checked={input.published}
+                  onChange={(event) =>
+                    setInput({ ...input, published: event.target.checked })
+                  }
+                />
+              }
+              label="立即发布"
+            />
+          </Stack>
+          <Stack direction="row" spacing={1.5} alignItems="center">
+            <Button variant="outlined" onClick={() => void handleSelectAttachment()}>
+              添加附件
+            </Button>
+            {attachment && (
+              <Chip
+                label={attachment.split(/[\\/]/).pop()}
+                onDelete={() => setAttachment(undefined)}
+              />
+            )}
+          </Stack>
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>取消</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleCreate()}
+          disabled={operating || !input.title.trim()}
+        >
+          发布
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
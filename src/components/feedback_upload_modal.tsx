@@ -0,0 +1,233 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  LinearProgress,
+  Stack,
+  Table,
+  TableBody,
+  TableCell,
+  TableContainer,
+  TableHead,
+  TableRow,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  FeedbackFileMatch,
+  FeedbackUploadPlan,
+  LOG_LEVEL_ERROR,
+  ProgressPayload,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const fileName = (path: string) => path.split(/[\\/]/).pop() ?? path;
+
+export default function FeedbackUploadModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  handleCancel?: () => void;
+  onSuccess?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [dir, setDir] = useState("");
+  const [text, setText] = useState("");
+  const [plan, setPlan] = useState<FeedbackUploadPlan | undefined>();
+  const [results, setResults] = useState<FeedbackFileMatch[] | undefined>();
+  const [progress, setProgress] = useState<ProgressPayload | undefined>();
+  const [operating, setOperating] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setPlan(undefined);
+      setResults(undefined);
+      setProgress(undefined);
+    }
+  }, [open]);
+
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+    const uuid = `feedback_${assignment.id}`;
+    void listen<ProgressPayload>("feedback_upload://progress", (event) => {
+      if (event.payload.uuid === uuid) {
+        setProgress(event.payload);
+      }
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      unlisten?.();
+    };
+  }, [assignment.id]);
+
+  const handleSelectDir = async () => {
+    const selected = await openDialog({ directory: true, multiple: false });
+    if (typeof selected !== "string") {
+      return;
+    }
+    setDir(selected);
+    setResults(undefined);
+    try {
+      setOperating(true);
+      const nextPlan = (await invoke("plan_feedback_upload", {
+        courseId,
+        dir: selected,
+      })) as FeedbackUploadPlan;
+      setPlan(nextPlan);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`读取目录失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  // The previewed matches are uploaded as they are, a retry passes the previous results
+  // back so that only the failed students are sent again
+  const handleUpload = async () => {
+    const matches = results ?? plan?.matches;
+    if (!matches) {
+      return;
+    }
+    try {
+      setOperating(true);
+      setProgress(undefined);
+      const nextResults = (await invoke("upload_feedback_files", {
+        courseId,
+        assignmentId: assignment.id,
+        matches,
+        text: text || null,
+      })) as FeedbackFileMatch[];
+      setResults(nextResults);
+      const failed = nextResults.filter((result) => !result.uploaded).length;
+      if (failed > 0) {
+        messageApi.warning(`${failed} 名学生的反馈上传失败，可重试`);
+      } else {
+        messageApi.success(`已为 ${nextResults.length} 名学生上传反馈`, 0.5);
+      }
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`上传失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const rows = results ?? plan?.matches ?? [];
+  const pendingCount = rows.filter((row) => !row.uploaded).length;
+  const percent =
+    progress && progress.total > 0
+      ? (progress.processed / progress.total) * 100
+      : 0;
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="md">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            批量上传反馈
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            以学号命名的文件（如 520030910001.pdf、520030910001_批注.pdf）会作为评论附件发送给对应学生。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            当前作业：{assignment.name}
+          </Alert>
+          <Stack direction="row" spacing={1.5} alignItems="center">
+            <Button
+              variant="outlined"
+              disabled={operating}
+              onClick={() => void handleSelectDir()}
+            >
+              选择目录
+            </Button>
+            <Typography variant="body2" color="text.secondary" noWrap>
+              {dir || "尚未选择目录"}
+            </Typography>
+          </Stack>
+          <TextField
+            multiline
+            minRows={2}
+            label="评论内容（可选）"
+            value={text}
+            onChange={(event) => setText(event.target.value)}
+          />
+          {plan && plan.unmatched_files.length > 0 && (
+            <Alert severity="warning" sx={{ borderRadius: "18px" }}>
+              {plan.unmatched_files.length} 个文件未匹配到学生：
+              {plan.unmatched_files.map(fileName).join("、")}
+            </Alert>
+          )}
+          {rows.length > 0 && (
+            <TableContainer sx={{ maxHeight: 320 }}>
+              <Table size="small" stickyHeader>
+                <TableHead>
+                  <TableRow>
+                    <TableCell>学号</TableCell>
+                    <TableCell>姓名</TableCell>
+                    <TableCell>文件</TableCell>
+                    <TableCell>状态</TableCell>
+                  </TableRow>
+                </TableHead>
+                <TableBody>
+                  {rows.map((row) => (
+                    <TableRow key={row.student_id}>
+                      <TableCell>{row.login_id}</TableCell>
+                      <TableCell>{row.name}</TableCell>
+                      <TableCell>{row.file_paths.map(fileName).join("、")}</TableCell>
+                      <TableCell>
+                        {row.uploaded
+                          ? "已上传"
+                          : row.error
+                            ? `失败：${row.error}`
+                            : "待上传"}
+                      </TableCell>
+                    </TableRow>
+                  ))}
+                </TableBody>
+              </Table>
+            </TableContainer>
+          )}
+          {operating && progress && (
+            <LinearProgress variant="determinate" value={percent} />
+          )}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>关闭</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleUpload()}
+          disabled={operating || pendingCount === 0}
+        >
+          {results ? "重试" : "上传"} {pendingCount} 名学生的反馈
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
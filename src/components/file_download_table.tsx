@@ -22,6 +22,7 @@ import { alpha, useTheme } from "@mui/material/styles";
 import { useEffect, useMemo, useState } from "react";
 
 import {
+  DownloadJobState,
   DownloadState,
   File,
   FileDownloadTask,
@@ -54,7 +55,7 @@ export default function FileDownloadTable({
 }: {
   tasks: FileDownloadTask[];
   handleRemoveTask: (task: FileDownloadTask) => void;
-  handleDownloadFile: (file: File) => Promise<void>;
+  handleDownloadFile: (file: File) => Promise<DownloadJobState>;
   handleOpenTaskFile: (task: FileDownloadTask) => Promise<void>;
 }) {
   const theme = useTheme();
@@ -106,8 +107,11 @@ export default function FileDownloadTable({
     let backoffCoef = 1;
     while (retries < maxRetries) {
       try {
-        await handleDownloadFile(file);
-        updateTaskProgress(file.uuid, 100);
+        // A paused download keeps its progress and reports the rest through events
+        const state = await handleDownloadFile(file);
+        if (state === "completed") {
+          updateTaskProgress(file.uuid, 100);
+        }
         break;
       } catch (error) {
         updateTaskProgress(file.uuid, undefined, error as string);
@@ -129,6 +133,11 @@ export default function FileDownloadTable({
 
   const handleRemoveTasks = () => {
     for (const task of selectedTasks) {
+      if (task.state === "downloading") {
+        invoke("cancel_download", { id: task.file.uuid }).catch((error) =>
+          consoleLog(LOG_LEVEL_ERROR, error)
+        );
+      }
       handleRemoveTask(task);
     }
     setSelectedTaskKeys([]);
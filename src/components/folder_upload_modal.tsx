@@ -0,0 +1,210 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  LinearProgress,
+  MenuItem,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  File,
+  Folder,
+  LOG_LEVEL_ERROR,
+  ProgressPayload,
+  UploadConflictPolicy,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const fileName = (path: string) => path.split(/[\\/]/).pop() ?? path;
+
+export default function FolderUploadModal({
+  open,
+  folderId,
+  folderName,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  folderId: number;
+  folderName: string;
+  handleCancel?: () => void;
+  onSuccess?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [filePaths, setFilePaths] = useState<string[]>([]);
+  const [policy, setPolicy] = useState<UploadConflictPolicy>("rename");
+  const [newFolderName, setNewFolderName] = useState("");
+  const [progress, setProgress] = useState<Record<string, ProgressPayload>>(
+    {}
+  );
+  const [operating, setOperating] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setFilePaths([]);
+      setProgress({});
+      setNewFolderName("");
+    }
+  }, [open]);
+
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+    void listen<ProgressPayload>("folder_upload://progress", (event) => {
+      setProgress((prev) => ({ ...prev, [event.payload.uuid]: event.payload }));
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      unlisten?.();
+    };
+  }, []);
+
+  const handleSelectFiles = async () => {
+    const selected = await openDialog({ multiple: true, directory: false });
+    if (selected) {
+      setFilePaths(Array.isArray(selected) ? selected : [selected]);
+      setProgress({});
+    }
+  };
+
+  const handleCreateFolder = async () => {
+    if (!newFolderName.trim()) {
+      return;
+    }
+    try {
+      setOperating(true);
+      const folder = (await invoke("create_folder", {
+        parentFolderId: folderId,
+        name: newFolderName.trim(),
+      })) as Folder;
+      messageApi.success(`已新建文件夹 ${folder.name}`, 0.5);
+      setNewFolderName("");
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`新建文件夹失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const handleUpload = async () => {
+    try {
+      setOperating(true);
+      const files = (await invoke("upload_files_to_folder", {
+        folderId,
+        filePaths,
+        policy,
+      })) as File[];
+      messageApi.success(`已上传 ${files.length} 个文件`, 0.5);
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`上传失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="sm">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            上传文件
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            将本地文件上传到 Canvas 文件夹，或在其中新建子文件夹。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            目标文件夹：{folderName}
+          </Alert>
+          <Stack direction="row" spacing={1.5}>
+            <TextField
+              fullWidth
+              size="small"
+              label="新文件夹名称"
+              value={newFolderName}
+              onChange={(event) => setNewFolderName(event.target.value)}
+            />
+            <Button
+              disabled={operating || !newFolderName.trim()}
+              onClick={() => void handleCreateFolder()}
+            >
+              新建
+            </Button>
+          </Stack>
+          <Stack direction="row" spacing={1.5} alignItems="center">
+            <Button
+              variant="outlined"
+              disabled={operating}
+              onClick={() => void handleSelectFiles()}
+            >
+              选择文件
+            </Button>
+            <TextField
+              select
+              size="small"
+              label="同名文件"
+              sx={{ minWidth: 160 }}
+              value={policy}
+              onChange={(event) =>
+                setPolicy(event.target.value as UploadConflictPolicy)
+              }
+            >
+              <MenuItem value="rename">自动重命名</MenuItem>
+              <MenuItem value="overwrite">覆盖</MenuItem>
+            </TextField>
+          </Stack>
+          {filePaths.map((path) => {
+            const item = progress[path];
+            const percent =
+              item && item.total > 0 ? (item.processed / item.total) * 100 : 0;
+            return (
+              <Stack key={path} spacing={0.5}>
+                <Typography variant="body2">
+                  {fileName(path)}
+                  {item?.state === "completed" && " · 已完成"}
+                  {item?.state === "failed" && " · 失败"}
+                </Typography>
+                <LinearProgress
+                  variant="determinate"
+                  value={percent}
+                  color={item?.state === "failed" ? "error" : "primary"}
+                />
+              </Stack>
+            );
+          })}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>关闭</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleUpload()}
+          disabled={operating || filePaths.length === 0}
+        >
+          上传 {filePaths.length} 个文件
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
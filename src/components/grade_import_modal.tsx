@@ -0,0 +1,232 @@
+import { invoke } from "@tauri-apps/api/core";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import UploadFileRoundedIcon from "@mui/icons-material/UploadFileRounded";
+import {
+  Alert,
+  Button,
+  Chip,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  Stack,
+  Table,
+  TableBody,
+  TableCell,
+  TableContainer,
+  TableHead,
+  TableRow,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  GradeImportPreview,
+  GradeImportStatus,
+  LOG_LEVEL_ERROR,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const STATUS_LABELS: Record<
+  GradeImportStatus,
+  {
+    label: string;
+    color: "primary" | "default" | "success" | "warning" | "error";
+  }
+> = {
+  update: { label: "将更新", color: "primary" },
+  unchanged: { label: "无变化", color: "default" },
+  skipped: { label: "跳过", color: "warning" },
+  invalid: { label: "无效", color: "error" },
+  applied: { label: "已导入", color: "success" },
+  failed: { label: "失败", color: "error" },
+};
+
+export default function GradeImportModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  handleCancel?: () => void;
+  onSuccess?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [filePath, setFilePath] = useState<string | undefined>();
+  const [preview, setPreview] = useState<GradeImportPreview | undefined>();
+  const [operating, setOperating] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setFilePath(undefined);
+      setPreview(undefined);
+    }
+  }, [open]);
+
+  const handleSelectFile = async () => {
+    const selected = await openDialog({
+      multiple: false,
+      filters: [
+        { name: "Spreadsheet", extensions: ["xlsx", "xls", "csv"] },
+      ],
+    });
+    if (!selected || Array.isArray(selected)) {
+      return;
+    }
+    setFilePath(selected);
+    try {
+      setOperating(true);
+      const nextPreview = (await invoke("preview_grade_import", {
+        courseId,
+        assignmentId: assignment.id,
+        filePath: selected,
+      })) as GradeImportPreview;
+      setPreview(nextPreview);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      setPreview(undefined);
+      messageApi.error(`读取成绩表失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const handleApply = async () => {
+    if (!preview) {
+      return;
+    }
+    try {
+      setOperating(true);
+      const appliedPreview = (await invoke("apply_grade_import", {
+        courseId,
+        assignmentId: assignment.id,
+        preview,
+      })) as GradeImportPreview;
+      setPreview(appliedPreview);
+      const count = appliedPreview.rows.filter(
+        (row) => row.status === "applied"
+      ).length;
+      const failedCount = appliedPreview.rows.filter(
+        (row) => row.status === "failed"
+      ).length;
+      if (failedCount > 0) {
+        messageApi.error(`已导入 ${count} 条成绩，${failedCount} 条失败`);
+        return;
+      }
+      messageApi.success(`已导入 ${count} 条成绩`, 0.5);
+      onSuccess?.();
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`导入失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const updateCount =
+    preview?.rows.filter((row) => row.status === "update").length ?? 0;
+  const invalidCount =
+    preview?.rows.filter((row) => row.status === "invalid").length ?? 0;
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="md">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            导入成绩
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            从 Excel 或 CSV 批量导入成绩，表头需包含 id 或 login_id（学号）、grade，可选
+            comment。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            当前作业：{assignment.name}
+          </Alert>
+          <Stack direction="row" spacing={1.5} alignItems="center">
+            <Button
+              variant="outlined"
+              startIcon={<UploadFileRoundedIcon />}
+              disabled={operating}
+              onClick={() => void handleSelectFile()}
+            >
+              选择成绩表
+            </Button>
+            <Typography
+              variant="body2"
+              color="text.secondary"
+              sx={{ overflow: "hidden", textOverflow: "ellipsis" }}
+            >
+              {filePath ?? "未选择文件"}
+            </Typography>
+          </Stack>
+
+          {preview && (
+            <>
+              {invalidCount > 0 && (
+                <Alert severity="warning" sx={{ borderRadius: "18px" }}>
+                  有 {invalidCount} 行无效，导入时将被忽略。
+                </Alert>
+              )}
+              <TableContainer sx={{ maxHeight: 420 }}>
+                <Table size="small" stickyHeader>
+                  <TableHead>
+                    <TableRow>
+                      <TableCell>行</TableCell>
+                      <TableCell>学生</TableCell>
+                      <TableCell>当前成绩</TableCell>
+                      <TableCell>导入成绩</TableCell>
+                      <TableCell>评语</TableCell>
+                      <TableCell>状态</TableCell>
+                    </TableRow>
+                  </TableHead>
+                  <TableBody>
+                    {preview.rows.map((row) => (
+                      <TableRow key={row.row}>
+                        <TableCell>{row.row}</TableCell>
+                        <TableCell>{row.student_name ?? row.student}</TableCell>
+                        <TableCell>{row.current_grade ?? "-"}</TableCell>
+                        <TableCell>{row.grade || "-"}</TableCell>
+                        <TableCell>{row.comment ?? ""}</TableCell>
+                        <TableCell>
+                          <Chip
+                            size="small"
+                            variant="outlined"
+                            label={row.message ?? STATUS_LABELS[row.status].label}
+                            color={STATUS_LABELS[row.status].color}
+                          />
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              </TableContainer>
+            </>
+          )}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>取消</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleApply()}
+          disabled={operating || updateCount === 0}
+        >
+          导入 {updateCount} 条成绩
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
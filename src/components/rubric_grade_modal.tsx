@@ -0,0 +1,218 @@
+import { invoke } from "@tauri-apps/api/core";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  MenuItem,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useMemo, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  Attachment,
+  LOG_LEVEL_ERROR,
+  RubricAssessment,
+  Submission,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+export default function RubricGradeModal({
+  open,
+  courseId,
+  assignment,
+  attachment,
+  handleCancel,
+  onSuccess,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  attachment: Attachment;
+  handleCancel?: () => void;
+  onSuccess?: (userId: number) => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [assessment, setAssessment] = useState<RubricAssessment>({});
+  const [comment, setComment] = useState("");
+  const [submitting, setSubmitting] = useState(false);
+
+  useEffect(() => {
+    if (!open || attachment.user_id == null) {
+      return;
+    }
+    setComment("");
+    setAssessment({});
+    const handleInitAssessment = async () => {
+      try {
+        const submission = (await invoke(
+          "get_single_course_assignment_submission",
+          {
+            courseId,
+            assignmentId: assignment.id,
+            studentId: attachment.user_id,
+          }
+        )) as Submission;
+        setAssessment(submission.rubric_assessment ?? {});
+      } catch (error) {
+        consoleLog(LOG_LEVEL_ERROR, error);
+      }
+    };
+    void handleInitAssessment();
+  }, [open, courseId, assignment.id, attachment.user_id]);
+
+  // mirrors the backend: explicit points win over the selected rating
+  const score = useMemo(
+    () =>
+      assignment.rubric.reduce((total, criterion) => {
+        const criterionAssessment = assessment[criterion.id];
+        const rating = criterion.ratings.find(
+          (rating) => rating.id === criterionAssessment?.rating_id
+        );
+        return total + (criterionAssessment?.points ?? rating?.points ?? 0);
+      }, 0),
+    [assessment, assignment.rubric]
+  );
+
+  const updateCriterion = (
+    criterionId: string,
+    update: RubricAssessment[string]
+  ) => {
+    setAssessment((prev) => ({
+      ...prev,
+      [criterionId]: { ...prev[criterionId], ...update },
+    }));
+  };
+
+  const handleSubmit = async () => {
+    if (attachment.user_id == null) {
+      return;
+    }
+    try {
+      setSubmitting(true);
+      const submittedScore = (await invoke("submit_rubric_assessment", {
+        courseId,
+        assignmentId: assignment.id,
+        studentId: attachment.user_id,
+        assessment,
+        comment: comment || undefined,
+      })) as number;
+      messageApi.success(`评分成功，总分 ${submittedScore}`, 0.5);
+      onSuccess?.(attachment.user_id);
+    } catch (error) {
+      messageApi.error(`评分失败：${error}`);
+    } finally {
+      setSubmitting(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="md">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            量规评分
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            按评分标准逐项打分，总分由量规自动计算。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={3}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            {attachment.user ?? `学生 ${attachment.user_id}`} · {assignment.name}
+          </Alert>
+
+          {assignment.rubric.map((criterion) => {
+            const criterionAssessment = assessment[criterion.id];
+            return (
+              <Stack key={criterion.id} spacing={1.5}>
+                <Typography variant="subtitle2" sx={{ fontWeight: 700 }}>
+                  {criterion.description}（{criterion.points} 分）
+                </Typography>
+                {criterion.long_description && (
+                  <Typography variant="body2" color="text.secondary">
+                    {criterion.long_description}
+                  </Typography>
+                )}
+                <Stack direction={{ xs: "column", sm: "row" }} spacing={1.5}>
+                  <TextField
+                    select
+                    label="等级"
+                    sx={{ minWidth: 240 }}
+                    value={criterionAssessment?.rating_id ?? ""}
+                    onChange={(event) =>
+                      updateCriterion(criterion.id, {
+                        rating_id: event.target.value || undefined,
+                        points: undefined,
+                      })
+                    }
+                  >
+                    <MenuItem value="">未评分</MenuItem>
+                    {criterion.ratings.map((rating) => (
+                      <MenuItem key={rating.id} value={rating.id}>
+                        {rating.description}（{rating.points} 分）
+                      </MenuItem>
+                    ))}
+                  </TextField>
+                  <TextField
+                    label="分数"
+                    type="number"
+                    value={criterionAssessment?.points ?? ""}
+                    onChange={(event) =>
+                      updateCriterion(criterion.id, {
+                        points:
+                          event.target.value === ""
+                            ? undefined
+                            : Number(event.target.value),
+                      })
+                    }
+                  />
+                  <TextField
+                    label="评语"
+                    fullWidth
+                    value={criterionAssessment?.comments ?? ""}
+                    onChange={(event) =>
+                      updateCriterion(criterion.id, {
+                        comments: event.target.value || undefined,
+                      })
+                    }
+                  />
+                </Stack>
+              </Stack>
+            );
+          })}
+
+          <TextField
+            label="总评"
+            multiline
+            minRows={2}
+            value={comment}
+            onChange={(event) => setComment(event.target.value)}
+          />
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Typography sx={{ mr: "auto", fontWeight: 700 }}>总分：{score}</Typography>
+        <Button onClick={handleCancel}>取消</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleSubmit()}
+          disabled={submitting}
+        >
+          提交评分
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
@@ -0,0 +1,195 @@
+import { invoke } from "@tauri-apps/api/core";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  LinearProgress,
+  Stack,
+  Table,
+  TableBody,
+  TableCell,
+  TableContainer,
+  TableHead,
+  TableRow,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { useAppMessage } from "../lib/message";
+import { Assignment, LOG_LEVEL_ERROR, SimilarityReport } from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+const DEFAULT_MIN_SIMILARITY = 30;
+
+const formatSimilarity = (similarity: number) =>
+  `${(similarity * 100).toFixed(1)}%`;
+
+export default function SimilarityReportModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  handleCancel?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [minSimilarity, setMinSimilarity] = useState(DEFAULT_MIN_SIMILARITY);
+  const [report, setReport] = useState<SimilarityReport | undefined>();
+  const [operating, setOperating] = useState(false);
+
+  useEffect(() => {
+    if (open) {
+      setReport(undefined);
+    }
+  }, [open, assignment.id]);
+
+  const handleDetect = async () => {
+    try {
+      setOperating(true);
+      const nextReport = (await invoke("detect_submission_similarity", {
+        courseId,
+        assignmentId: assignment.id,
+        minSimilarity: minSimilarity / 100,
+      })) as SimilarityReport;
+      setReport(nextReport);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`检测失败：${error}`);
+    } finally {
+      setOperating(false);
+    }
+  };
+
+  const handleExport = async () => {
+    if (!report) {
+      return;
+    }
+    const selected = await openDialog({ directory: true });
+    const folderPath = Array.isArray(selected) ? selected[0] : selected;
+    if (!folderPath) {
+      return;
+    }
+    try {
+      await invoke("export_similarity_report", {
+        report,
+        fileName: `${assignment.name}_相似度报告.xlsx`,
+        folderPath,
+      });
+      messageApi.success("导出成功", 0.5);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`导出失败：${error}`);
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="lg">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            相似度检测
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            提取学生提交附件（PDF、DOCX、文本与源代码）中的文字，两两比较并列出相似度较高的提交。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            当前作业：{assignment.name}
+          </Alert>
+          <Stack direction="row" spacing={1.5} alignItems="center">
+            <TextField
+              label="相似度阈值（%）"
+              type="number"
+              size="small"
+              value={minSimilarity}
+              inputProps={{ min: 0, max: 100 }}
+              onChange={(event) =>
+                setMinSimilarity(
+                  Math.min(100, Math.max(0, Number(event.target.value)))
+                )
+              }
+            />
+            <Button
+              variant="outlined"
+              disabled={operating}
+              onClick={() => void handleDetect()}
+            >
+              开始检测
+            </Button>
+          </Stack>
+          {operating && <LinearProgress />}
+
+          {report && (
+            <>
+              <Typography variant="body2" color="text.secondary">
+                共比较 {report.compared_count} 份提交，发现 {report.pairs.length}{" "}
+                对相似提交
+                {report.skipped_user_ids.length > 0 &&
+                  `，${report.skipped_user_ids.length} 份提交无法读取文字`}
+                。
+              </Typography>
+              <TableContainer sx={{ maxHeight: 480 }}>
+                <Table size="small" stickyHeader>
+                  <TableHead>
+                    <TableRow>
+                      <TableCell>学生</TableCell>
+                      <TableCell>学生</TableCell>
+                      <TableCell>相似度</TableCell>
+                      <TableCell>相同片段</TableCell>
+                    </TableRow>
+                  </TableHead>
+                  <TableBody>
+                    {report.pairs.map((pair) => (
+                      <TableRow key={`${pair.user_id}_${pair.other_user_id}`}>
+                        <TableCell>
+                          {pair.user_name}（{pair.login_id}）
+                        </TableCell>
+                        <TableCell>
+                          {pair.other_user_name}（{pair.other_login_id}）
+                        </TableCell>
+                        <TableCell>{formatSimilarity(pair.similarity)}</TableCell>
+                        <TableCell>
+                          <Stack spacing={0.5}>
+                            {pair.matched_passages.map((passage, index) => (
+                              <Typography key={index} variant="body2">
+                                {passage}
+                              </Typography>
+                            ))}
+                          </Stack>
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              </TableContainer>
+            </>
+          )}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>关闭</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleExport()}
+          disabled={!report || report.pairs.length === 0}
+        >
+          导出 XLSX
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
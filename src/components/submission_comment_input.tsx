@@ -0,0 +1,118 @@
+import AttachFileRoundedIcon from "@mui/icons-material/AttachFileRounded";
+import { Button, Chip, Stack, TextField } from "@mui/material";
+import { invoke } from "@tauri-apps/api/core";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import { useState } from "react";
+
+import { AppMessageApi } from "../lib/message";
+import { LOG_LEVEL_ERROR, NewSubmissionComment } from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+// Comments on the student's submission, or on your own one when `studentId` is omitted
+export default function SubmissionCommentInput({
+  courseId,
+  assignmentId,
+  studentId,
+  messageApi,
+  onSuccess,
+  onFocus,
+  onBlur,
+}: {
+  courseId: number;
+  assignmentId: number;
+  studentId?: number;
+  messageApi: AppMessageApi;
+  onSuccess?: () => Promise<void> | void;
+  onFocus?: () => void;
+  onBlur?: () => void;
+}) {
+  const [text, setText] = useState("");
+  const [filePaths, setFilePaths] = useState<string[]>([]);
+  const [sending, setSending] = useState(false);
+
+  const handleSelectFiles = async () => {
+    const selected = await openDialog({ multiple: true, directory: false });
+    if (selected) {
+      const paths = Array.isArray(selected) ? selected : [selected];
+      setFilePaths((prev) => [...prev, ...paths.filter((path) => !prev.includes(path))]);
+    }
+  };
+
+  const handleSend = async () => {
+    if (!text.trim() && filePaths.length === 0) {
+      messageApi.warning("评论不得为空！");
+      return;
+    }
+    const comment: NewSubmissionComment = {
+      text,
+      file_paths: filePaths,
+      media_comment_id: null,
+      media_comment_type: null,
+      group_comment: false,
+    };
+    try {
+      setSending(true);
+      if (studentId === undefined) {
+        await invoke("add_my_submission_comment", { courseId, assignmentId, comment });
+      } else {
+        await invoke("add_submission_comment", {
+          courseId,
+          assignmentId,
+          studentId,
+          comment,
+        });
+      }
+      messageApi.success("评论成功！🎉", 0.5);
+      setText("");
+      setFilePaths([]);
+      await onSuccess?.();
+    } catch (e) {
+      consoleLog(LOG_LEVEL_ERROR, e);
+      messageApi.error(e as string);
+    } finally {
+      setSending(false);
+    }
+  };
+
+  return (
+    <Stack spacing={1.25}>
+      <TextField
+        multiline
+        minRows={3}
+        placeholder="请输入评论"
+        value={text}
+        onChange={(event) => setText(event.target.value)}
+        onFocus={onFocus}
+        onBlur={onBlur}
+      />
+      {filePaths.length > 0 ? (
+        <Stack direction="row" spacing={1} flexWrap="wrap" useFlexGap>
+          {filePaths.map((path) => (
+            <Chip
+              key={path}
+              label={path.split(/[\\/]/).pop()}
+              onDelete={() => setFilePaths((prev) => prev.filter((item) => item !== path))}
+            />
+          ))}
+        </Stack>
+      ) : null}
+      <Stack direction="row" spacing={1}>
+        <Button
+          startIcon={<AttachFileRoundedIcon />}
+          disabled={sending}
+          onClick={() => void handleSelectFiles()}
+        >
+          添加附件
+        </Button>
+        <Button
+          variant="contained"
+          sx={{ flex: 1 }}
+          disabled={sending}
+          onClick={() => void handleSend()}
+        >
+          发表评论
+        </Button>
+      </Stack>
+    </Stack>
+  );
+}
@@ -0,0 +1,173 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import {
+  Alert,
+  Button,
+  Checkbox,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  FormControlLabel,
+  LinearProgress,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { useEffect, useState } from "react";
+
+import { getConfig, saveConfig } from "../lib/config";
+import { useAppMessage } from "../lib/message";
+import {
+  Assignment,
+  LOG_LEVEL_ERROR,
+  ProgressPayload,
+  SubmissionDownloadOptions,
+} from "../lib/model";
+import { consoleLog } from "../lib/utils";
+
+export default function SubmissionDownloadModal({
+  open,
+  courseId,
+  assignment,
+  handleCancel,
+}: {
+  open: boolean;
+  courseId: number;
+  assignment: Assignment;
+  handleCancel?: () => void;
+}) {
+  const [messageApi, contextHolder] = useAppMessage();
+  const [template, setTemplate] = useState("");
+  const [options, setOptions] = useState<SubmissionDownloadOptions>({
+    all_attempts: false,
+    zip: true,
+  });
+  const [progress, setProgress] = useState<ProgressPayload | undefined>();
+  const [downloading, setDownloading] = useState(false);
+
+  useEffect(() => {
+    if (!open) {
+      return;
+    }
+    setProgress(undefined);
+    void getConfig().then((config) =>
+      setTemplate(config.submission_folder_template)
+    );
+  }, [open]);
+
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+    const uuid = `submissions_${assignment.id}`;
+    void listen<ProgressPayload>("submission_download://progress", (event) => {
+      if (event.payload.uuid === uuid) {
+        setProgress(event.payload);
+      }
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      unlisten?.();
+    };
+  }, [assignment.id]);
+
+  const handleDownload = async () => {
+    try {
+      setDownloading(true);
+      const config = await getConfig(true);
+      if (template && config.submission_folder_template !== template) {
+        config.submission_folder_template = template;
+        await saveConfig(config);
+      }
+      const savePath = (await invoke("download_submission_attachments", {
+        courseId,
+        assignmentId: assignment.id,
+        saveName: `${assignment.name}_submissions`,
+        options,
+      })) as string;
+      messageApi.success(`已下载到 ${savePath}`);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`下载失败：${error}`);
+    } finally {
+      setDownloading(false);
+    }
+  };
+
+  const percent =
+    progress && progress.total > 0
+      ? (progress.processed / progress.total) * 100
+      : 0;
+
+  return (
+    <Dialog open={open} onClose={handleCancel} fullWidth maxWidth="sm">
+      {contextHolder}
+      <DialogTitle sx={{ pb: 1 }}>
+        <Stack spacing={0.75}>
+          <Typography variant="h6" sx={{ fontWeight: 800 }}>
+            批量下载提交
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            将所有学生的附件按学生分目录保存到下载目录。
+          </Typography>
+        </Stack>
+      </DialogTitle>
+
+      <DialogContent sx={{ pt: 2 }}>
+        <Stack spacing={2}>
+          <Alert severity="info" sx={{ borderRadius: "18px" }}>
+            当前作业：{assignment.name}
+          </Alert>
+          <TextField
+            label="学生目录命名"
+            value={template}
+            onChange={(event) => setTemplate(event.target.value)}
+            helperText="可用变量：{login_id} 学号、{name} 姓名、{user_id} 用户 ID、{late} 迟交标记"
+          />
+          <FormControlLabel
+            control={
+              <Checkbox
+                checked={options.all_attempts}
+                onChange={(event) =>
+                  setOptions((prev) => ({
+                    ...prev,
+                    all_attempts: event.target.checked,
+                  }))
+                }
+              />
+            }
+            label="包含所有提交记录（否则仅最新一次）"
+          />
+          <FormControlLabel
+            control={
+              <Checkbox
+                checked={options.zip}
+                onChange={(event) =>
+                  setOptions((prev) => ({ ...prev, zip: event.target.checked }))
+                }
+              />
+            }
+            label="打包为 ZIP"
+          />
+          {downloading && (
+            <LinearProgress
+              variant={progress ? "determinate" : "indeterminate"}
+              value={percent}
+            />
+          )}
+        </Stack>
+      </DialogContent>
+
+      <DialogActions sx={{ px: 3, pb: 3 }}>
+        <Button onClick={handleCancel}>关闭</Button>
+        <Button
+          variant="contained"
+          onClick={() => void handleDownload()}
+          disabled={downloading}
+        >
+          开始下载
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+}
@@ -1,5 +1,6 @@
 import UploadRoundedIcon from "@mui/icons-material/UploadRounded";
 import {
+  Alert,
   Button,
   Dialog,
   DialogActions,
@@ -9,6 +10,8 @@ import {
   ListItem,
   ListItemText,
   Stack,
+  Tab,
+  Tabs,
   TextField,
   Typography,
 } from "@mui/material";
@@ -18,12 +21,64 @@ import { DialogFilter, open } from "@tauri-apps/plugin-dialog";
 import { useEffect, useState } from "react";
 
 import { useAppMessage } from "../lib/message";
+import { NewSubmission, Submission, SubmissionIssue, SubmissionValidation } from "../lib/model";
+import { formatDate } from "../lib/utils";
+
+type SubmissionType = NewSubmission["type"];
+
+const SUBMISSION_TYPE_LABELS: Record<SubmissionType, string> = {
+  online_upload: "上传文件",
+  online_text_entry: "文本输入",
+  online_url: "网址",
+};
 
 interface SubmitParam {
   filePaths: string[];
+  body: string;
+  url: string;
   comment?: string;
 }
 
+const SUBMISSION_ISSUE_LABELS: Record<SubmissionIssue["kind"], string> = {
+  submission_type: "提交类型不被接受",
+  not_unlocked: "作业尚未开放",
+  locked: "作业已截止锁定",
+  late: "已过截止时间，将被标记为迟交",
+  missing_file: "文件缺失",
+  extension: "文件类型不被接受",
+  empty_file: "文件为空",
+  large_file: "文件较大",
+  empty_content: "提交内容为空",
+  invalid_url: "网址无效",
+};
+
+function IssueList({
+  severity,
+  title,
+  issues,
+}: {
+  severity: "error" | "warning";
+  title: string;
+  issues: SubmissionIssue[];
+}) {
+  if (issues.length === 0) {
+    return null;
+  }
+  return (
+    <Alert severity={severity}>
+      <Typography variant="subtitle2">{title}</Typography>
+      {issues.map((issue, index) => (
+        <Typography key={index} variant="body2">
+          {SUBMISSION_ISSUE_LABELS[issue.kind]}
+          {issue.file_path ? `（${issue.file_path}）` : ""}：{issue.message}
+        </Typography>
+      ))}
+    </Alert>
+  );
+}
+
+const EMPTY_PARAM: SubmitParam = { filePaths: [], body: "", url: "", comment: "" };
+
 function FilesSelector({
   value,
   onChange,
@@ -112,49 +167,95 @@ export function SubmitModal({
   onCancel,
   onSubmit,
   allowed_extensions,
+  submission_types,
   courseId,
   assignmentId,
 }: {
   open: boolean;
   allowed_extensions: string[];
+  submission_types: string[];
   courseId: number;
   assignmentId: number;
   onCancel?: () => void;
-  onSubmit?: () => void;
+  onSubmit?: (submission: Submission) => void;
 }) {
   const [messageApi] = useAppMessage();
-  const [formData, setFormData] = useState<SubmitParam>({ filePaths: [], comment: "" });
+  const [formData, setFormData] = useState<SubmitParam>(EMPTY_PARAM);
   const [submitting, setSubmitting] = useState(false);
+  const [validation, setValidation] = useState<SubmissionValidation>();
+  const supportedTypes = (Object.keys(SUBMISSION_TYPE_LABELS) as SubmissionType[]).filter(
+    (type) => submission_types.includes(type)
+  );
+  const [type, setType] = useState<SubmissionType>(supportedTypes[0] ?? "online_upload");
 
   useEffect(() => {
     if (!visible) {
-      setFormData({ filePaths: [], comment: "" });
+      setFormData(EMPTY_PARAM);
       setSubmitting(false);
+      setValidation(undefined);
+    } else {
+      setType(supportedTypes[0] ?? "online_upload");
     }
   }, [visible]);
 
+  // Warnings only need to be confirmed once, any change to the form validates again
+  useEffect(() => {
+    setValidation(undefined);
+  }, [type, formData.filePaths, formData.body, formData.url]);
+
+  const getSubmission = (): NewSubmission | undefined => {
+    switch (type) {
+      case "online_upload":
+        return formData.filePaths.length > 0
+          ? { type, file_paths: formData.filePaths }
+          : undefined;
+      case "online_text_entry":
+        return formData.body.trim() ? { type, body: formData.body } : undefined;
+      case "online_url":
+        return formData.url.trim() ? { type, url: formData.url.trim() } : undefined;
+    }
+  };
+
   const handleSubmit = async () => {
-    if (formData.filePaths.length === 0) {
-      messageApi.warning("请至少上传一个文件");
+    const submission = getSubmission();
+    if (!submission) {
+      messageApi.warning(
+        type === "online_upload" ? "请至少上传一个文件" : "提交内容不得为空"
+      );
       return;
     }
 
     try {
       setSubmitting(true);
+      if (!validation) {
+        const result = (await invoke("validate_submission", {
+          courseId,
+          assignmentId,
+          submission,
+        })) as SubmissionValidation;
+        setValidation(result);
+        if (result.errors.length > 0 || result.warnings.length > 0) {
+          return;
+        }
+      } else if (validation.errors.length > 0) {
+        return;
+      }
       messageApi.open({
         key: "submitting",
         type: "loading",
         content: "正在提交中，请耐心等待...",
       });
-      await invoke("submit_assignment", {
+      const result = (await invoke("submit_assignment", {
         courseId,
         assignmentId,
-        filePaths: formData.filePaths,
+        submission,
         comment: formData.comment || undefined,
-      });
+      })) as Submission;
       messageApi.destroy("submitting");
-      messageApi.success("提交成功");
-      onSubmit?.();
+      messageApi.success(
+        `提交成功：第 ${result.attempt ?? 1} 次提交，Canvas 记录时间 ${formatDate(result.submitted_at)}`
+      );
+      onSubmit?.(result);
     } catch (e) {
       messageApi.destroy("submitting");
       messageApi.error(`提交失败：${e}`);
@@ -168,14 +269,59 @@ export function SubmitModal({
       <DialogTitle>提交作业</DialogTitle>
       <DialogContent dividers>
         <Stack spacing={3} sx={{ pt: 1 }}>
-          <Stack spacing={1}>
-            <Typography variant="subtitle2">文件</Typography>
-            <FilesSelector
-              allowed_extensions={allowed_extensions}
-              value={formData.filePaths}
-              onChange={(filePaths) => setFormData((prev) => ({ ...prev, filePaths }))}
+          {supportedTypes.length > 1 && (
+            <Tabs value={type} onChange={(_, value) => setType(value)}>
+              {supportedTypes.map((value) => (
+                <Tab key={value} value={value} label={SUBMISSION_TYPE_LABELS[value]} />
+              ))}
+            </Tabs>
+          )}
+          {supportedTypes.length === 0 && (
+            <Typography variant="body2" color="error">
+              该作业不支持在线提交。
+            </Typography>
+          )}
+          {type === "online_upload" && (
+            <Stack spacing={1}>
+              <Typography variant="subtitle2">文件</Typography>
+              <FilesSelector
+                allowed_extensions={allowed_extensions}
+                value={formData.filePaths}
+                onChange={(filePaths) => setFormData((prev) => ({ ...prev, filePaths }))}
+              />
+            </Stack>
+          )}
+          {type === "online_text_entry" && (
+            <TextField
+              label="作答内容（支持 HTML）"
+              multiline
+              minRows={8}
+              value={formData.body}
+              onChange={(event) =>
+                setFormData((prev) => ({ ...prev, body: event.target.value }))
+              }
+            />
+          )}
+          {type === "online_url" && (
+            <TextField
+              label="网址"
+              placeholder="https://"
+              value={formData.url}
+              onChange={(event) =>
+                setFormData((prev) => ({ ...prev, url: event.target.value }))
+              }
             />
-          </Stack>
+          )}
+          {validation && (
+            <>
+              <IssueList severity="error" title="请修正以下问题后再提交" issues={validation.errors} />
+              <IssueList
+                severity="warning"
+                title="请确认以下问题，确认无误后可继续提交"
+                issues={validation.warnings}
+              />
+            </>
+          )}
           <TextField
             label="评论"
             placeholder="输入评论"
@@ -190,8 +336,14 @@ export function SubmitModal({
       </DialogContent>
       <DialogActions sx={{ px: 3, py: 2 }}>
         <Button onClick={onCancel}>取消</Button>
-        <Button variant="contained" onClick={() => void handleSubmit()} disabled={submitting}>
-          提交
+        <Button
+          variant="contained"
+          onClick={() => void handleSubmit()}
+          disabled={
+            submitting || supportedTypes.length === 0 || (validation?.errors.length ?? 0) > 0
+          }
+        >
+          {validation?.warnings.length ? "仍然提交" : "提交"}
         </Button>
       </DialogActions>
     </Dialog>
@@ -21,6 +21,7 @@ import { alpha, useTheme } from "@mui/material/styles";
 import { useEffect, useMemo, useState } from "react";
 
 import {
+  DownloadJobState,
   DownloadState,
   ProgressPayload,
   VideoDownloadTask,
@@ -94,8 +95,13 @@ export default function VideoDownloadTable({
     const maxRetries = 3;
     while (retries < maxRetries) {
       try {
-        await invoke("download_video", { video, saveName: task.video.name });
-        updateTaskProgress(uuid, 100);
+        const state = await invoke<DownloadJobState>("download_video", {
+          video,
+          saveName: task.video.name,
+        });
+        if (state === "completed") {
+          updateTaskProgress(uuid, 100);
+        }
         break;
       } catch (error) {
         appMessage().error(error as string);
@@ -68,6 +68,39 @@ export interface File {
     size: number;
     external_type?: "File" | "Link";
     external_title?: string;
+    updated_at?: string | null;
+}
+
+export interface SyncManifestEntry {
+    file_id: number;
+    display_name: string;
+    updated_at?: string | null;
+    size: number;
+    local_path: string;
+    versions?: FileVersion[];
+}
+
+export interface FileVersion {
+    version: number;
+    display_name: string;
+    updated_at?: string | null;
+    size: number;
+    local_path: string;
+    archived_at: string;
+}
+
+export interface SyncFileChange {
+    file: File;
+    folder_path: string;
+    previous?: SyncManifestEntry | null;
+}
+
+export interface CourseSyncDiff {
+    new_files: SyncFileChange[];
+    modified_files: SyncFileChange[];
+    renamed_files: SyncFileChange[];
+    removed_files: SyncManifestEntry[];
+    unchanged_count: number;
 }
 
 export interface Folder {
@@ -83,6 +116,8 @@ export interface Folder {
     folders_count: number;
 }
 
+export type UploadConflictPolicy = "overwrite" | "rename";
+
 export type Entry = File | Folder;
 export function isFile(entry: Entry) {
     return 'display_name' in entry;
@@ -136,6 +171,11 @@ export interface FileChatStreamErrorPayload {
     error: string;
 }
 
+export interface FolderFilesPagePayload {
+    folder_id: number;
+    files: File[];
+}
+
 export interface UserSubmissions {
     user_id: number;
     submissions: Submission[]
@@ -182,6 +222,60 @@ export interface Assignment {
     all_dates: AssignmentDate[];
     score_statistics: Option<ScoreStatistic>;
     grading_type: string;
+    group_category_id: Option<number>;
+    grade_group_students_individually: boolean;
+    is_quiz_assignment: boolean;
+    quiz_id: Option<number>;
+    rubric: RubricCriterion[];
+    rubric_settings: Option<RubricSettings>;
+    use_rubric_for_grading: boolean;
+    assignment_group_id: Option<number>;
+}
+
+export interface AssignmentGroup {
+    id: number;
+    name: string;
+    position: number;
+    group_weight: Option<number>;
+}
+
+export interface AssignmentInput {
+    name: string;
+    description?: string;
+    points_possible?: number;
+    grading_type?: string;
+    submission_types: string[];
+    allowed_extensions: string[];
+    due_at?: string;
+    unlock_at?: string;
+    lock_at?: string;
+    assignment_group_id?: number;
+    published?: boolean;
+}
+
+export type GradeImportStatus =
+    | "update"
+    | "unchanged"
+    | "skipped"
+    | "invalid"
+    | "applied"
+    | "failed";
+
+export interface GradeImportRow {
+    row: number;
+    student: string;
+    student_id: Option<number>;
+    student_name: Option<string>;
+    grade: string;
+    comment: Option<string>;
+    current_grade: Option<string>;
+    status: GradeImportStatus;
+    message: Option<string>;
+}
+
+export interface GradeImportPreview {
+    rows: GradeImportRow[];
+    applied: boolean;
 }
 
 export interface AssignmentDate {
@@ -209,12 +303,50 @@ export interface AssignmentOverride {
     lock_at: Option<string>;
 }
 
+export interface DeadlineDates {
+    due_at: Option<string>;
+    unlock_at: Option<string>;
+    lock_at: Option<string>;
+}
+
+export type DeadlineOperation =
+    | { type: "shift"; assignment_ids: number[]; days: number }
+    | { type: "extend"; student_id: number; assignment_ids: number[]; days: number }
+    | { type: "copy_overrides"; source_assignment_id: number; target_assignment_ids: number[] };
+
+export type DeadlineChangeKind = "update_assignment" | "update_override" | "create_override";
+
+export interface DeadlineChange {
+    kind: DeadlineChangeKind;
+    assignment_id: number;
+    assignment_name: string;
+    override_id: Option<number>;
+    title: string;
+    student_ids: number[];
+    group_id: number;
+    course_section_id: number;
+    before: DeadlineDates;
+    after: DeadlineDates;
+    reverted: boolean;
+}
+
+export interface DeadlineBatch {
+    id: string;
+    course_id: number;
+    operation: DeadlineOperation;
+    changes: DeadlineChange[];
+    warnings: string[];
+    applied_at: Option<string>;
+    reverted_at: Option<string>;
+}
+
 export type WorkflowState = "submitted" | "unsubmitted" | "graded" | "pending_review";
 
 export interface Submission {
     id: number;
     key: number;
     grade: Option<string>;
+    score: Option<number>;
     submitted_at: Option<string>;
     assignment_id: number;
     user_id: number;
@@ -222,6 +354,71 @@ export interface Submission {
     attachments: Attachment[];
     submission_comments: SubmissionComment[];
     workflow_state: WorkflowState;
+    group?: SubmissionGroup;
+    rubric_assessment?: RubricAssessment;
+    attempt?: Option<number>;
+    submission_history?: Submission[];
+}
+
+export interface SimilarityPair {
+    user_id: number;
+    user_name: string;
+    login_id: string;
+    other_user_id: number;
+    other_user_name: string;
+    other_login_id: string;
+    similarity: number;
+    matched_passages: string[];
+}
+
+export interface SimilarityReport {
+    assignment_id: number;
+    compared_count: number;
+    skipped_user_ids: number[];
+    pairs: SimilarityPair[];
+}
+
+export interface SubmissionDownloadOptions {
+    all_attempts: boolean;
+    zip: boolean;
+}
+
+export interface RubricRating {
+    id: string;
+    description: string;
+    long_description?: string;
+    points: number;
+}
+
+export interface RubricCriterion {
+    id: string;
+    description: string;
+    long_description?: string;
+    points: number;
+    criterion_use_range: boolean;
+    ratings: RubricRating[];
+}
+
+export interface RubricSettings {
+    id?: number;
+    title: string;
+    points_possible: number;
+    free_form_criterion_comments: boolean;
+    hide_score_total: boolean;
+}
+
+export interface RubricCriterionAssessment {
+    points?: number;
+    rating_id?: string;
+    comments?: string;
+}
+
+// keyed by criterion id
+export type RubricAssessment = Record<string, RubricCriterionAssessment>;
+
+export interface SubmissionGroup {
+    id: Option<number>;
+    name: Option<string>;
 }
 
 export interface GradeStatistic {
@@ -254,6 +451,7 @@ export interface Attachment {
     late: Option<boolean>;
     comments: Option<SubmissionComment[]>;
     preview_url: Option<string>;
+    group_id: Option<number>;
 }
 
 export interface FileDownloadTask {
@@ -310,7 +508,11 @@ export interface AppConfig {
     color_primary: Option<string>;
     mcp_enabled: boolean;
     mcp_port: number;
+    mcp_allow_write: boolean;
     debug_mode: boolean;
+    max_concurrent_downloads: number;
+    conversation_poll_interval: number;
+    submission_folder_template: string;
 }
 
 export interface DebugHttpHeader {
@@ -345,10 +547,23 @@ export interface ExportUsersConfig {
     save_name: string;
 }
 
+export type DownloadJobState = "queued" | "running" | "paused" | "completed" | "failed" | "cancelled";
+
 export interface ProgressPayload {
     uuid: string;
     processed: number;
     total: number;
+    state?: DownloadJobState;
+}
+
+export interface DownloadJob {
+    id: string;
+    priority: number;
+    seq: number;
+    state: DownloadJobState;
+    processed: number;
+    total: number;
+    error?: string;
 }
 
 export interface Payload {
@@ -503,6 +718,56 @@ export interface SubmissionComment {
     attachments: Attachment[]
 }
 
+export type NewSubmission =
+    | { type: "online_upload"; file_paths: string[] }
+    | { type: "online_text_entry"; body: string }
+    | { type: "online_url"; url: string };
+
+export type SubmissionIssueKind =
+    | "submission_type"
+    | "not_unlocked"
+    | "locked"
+    | "late"
+    | "missing_file"
+    | "extension"
+    | "empty_file"
+    | "large_file"
+    | "empty_content"
+    | "invalid_url";
+
+export interface SubmissionIssue {
+    kind: SubmissionIssueKind;
+    message: string;
+    file_path: Option<string>;
+}
+
+export interface SubmissionValidation {
+    errors: SubmissionIssue[];
+    warnings: SubmissionIssue[];
+}
+
+export interface NewSubmissionComment {
+    text: string;
+    file_paths: string[];
+    media_comment_id: Option<string>;
+    media_comment_type: Option<string>;
+    group_comment: boolean;
+}
+
+export interface FeedbackFileMatch {
+    student_id: number;
+    login_id: string;
+    name: string;
+    file_paths: string[];
+    uploaded: boolean;
+    error: Option<string>;
+}
+
+export interface FeedbackUploadPlan {
+    matches: FeedbackFileMatch[];
+    unmatched_files: string[];
+}
+
 export interface GradeStatus {
     assignmetName: string;
     maxGrade: number;
@@ -561,11 +826,183 @@ export interface Permissions {
     delete: boolean;
 }
 
+export interface Group {
+    id: number;
+    name: string;
+    description?: string;
+    group_category_id?: number;
+    course_id?: number;
+    members_count: number;
+    max_membership?: number;
+    join_level?: string;
+}
+
+export interface GroupCategory {
+    id: number;
+    name: string;
+    role?: string;
+    self_signup?: string;
+    group_limit?: number;
+    course_id?: number;
+}
+
+export interface GroupMembership {
+    id: number;
+    group_id: number;
+    user_id: number;
+    workflow_state: string;
+    moderator?: boolean;
+}
+
+export interface ConversationParticipant {
+    id: number;
+    name: string;
+    full_name?: string;
+    avatar_url?: string;
+}
+
+export interface ConversationMessage {
+    id: number;
+    created_at?: string;
+    body: string;
+    author_id: number;
+    generated: boolean;
+    participating_user_ids: number[];
+    attachments: Attachment[];
+}
+
+export interface Conversation {
+    id: number;
+    subject?: string;
+    workflow_state: string;
+    last_message?: string;
+    last_message_at?: string;
+    message_count: number;
+    subscribed: boolean;
+    private: boolean;
+    starred: boolean;
+    audience: number[];
+    participants: ConversationParticipant[];
+    context_name?: string;
+    context_code?: string;
+    messages: ConversationMessage[];
+}
+
+export interface NewConversation {
+    recipients: string[];
+    subject: string;
+    body: string;
+    attachment_paths: string[];
+    context_code?: string;
+    bulk_message: boolean;
+}
+
+export interface Quiz {
+    id: number;
+    title: string;
+    html_url: string;
+    description?: string;
+    quiz_type: string;
+    assignment_id?: number;
+    time_limit?: number;
+    allowed_attempts: number;
+    question_count: number;
+    points_possible?: number;
+    due_at?: string;
+    lock_at?: string;
+    unlock_at?: string;
+    published: boolean;
+    locked_for_user: boolean;
+    hide_results?: string;
+    all_dates: AssignmentDate[];
+}
+
+export interface QuizSubmission {
+    id: number;
+    quiz_id: number;
+    user_id: number;
+    submission_id?: number;
+    attempt?: number;
+    score?: number;
+    kept_score?: number;
+    quiz_points_possible?: number;
+    started_at?: string;
+    finished_at?: string;
+    end_at?: string;
+    time_spent?: number;
+    attempts_left?: number;
+    workflow_state: string;
+}
+
+export interface QuizAttempt {
+    attempt?: number;
+    score?: number;
+    submitted_at?: string;
+}
+
+export interface QuizSubmissionHistory {
+    quiz_submissions: QuizSubmission[];
+    attempts: QuizAttempt[];
+}
+
+export interface QuizAnswer {
+    id: number;
+    text: string;
+    html?: string;
+    weight?: number;
+    comments?: string;
+}
+
+export interface QuizQuestion {
+    id: number;
+    quiz_id: number;
+    position?: number;
+    question_name: string;
+    question_type: string;
+    question_text: string;
+    points_possible?: number;
+    correct_comments?: string;
+    incorrect_comments?: string;
+    answers: QuizAnswer[];
+}
+
+export interface Page {
+    page_id: number;
+    url: string;
+    title: string;
+    created_at?: string;
+    updated_at?: string;
+    published: boolean;
+    front_page: boolean;
+    locked_for_user: boolean;
+    body?: string;
+    html_url: string;
+}
+
+export interface Announcement {
+    id: number;
+    title: string;
+    message: string;
+    posted_at?: string;
+    user_name?: string;
+    context_code: string;
+    html_url: string;
+    attachments: Attachment[];
+    read: boolean;
+}
+
+export interface AnnouncementFeed {
+    announcements: Announcement[];
+    unread_count: number;
+    unread_counts: Record<string, number>;
+}
+
 export interface FullDiscussion {
     unread_entries: number[];
     new_entries: number[];
     participants: Participant[];
     view: DiscussionView[];
+    entry_ratings: Record<number, number>;
 }
 
 export interface Participant {
@@ -601,6 +1038,23 @@ export interface Reply {
     message: Option<string>;
 }
 
+export interface DiscussionEntry {
+    id: number;
+    user_id: number;
+    parent_id: Option<number>;
+    user_name: Option<string>;
+    message: Option<string>;
+    created_at: Option<string>;
+    updated_at: Option<string>;
+}
+
+export interface DiscussionTopicInput {
+    title: string;
+    message: string;
+    threaded: boolean;
+    published: boolean;
+}
+
 export interface DraggableItem {
     id: string;
     content: string;
@@ -628,6 +1082,10 @@ export interface AnnualCourseStatistic {
     totalPointsPossible: number;
     totalScore: number;
     submitTimeList: string[];
+    quizCount: number;
+    quizSubmittedCount: number;
+    quizPointsPossible: number;
+    quizTotalScore: number;
 }
 
 export interface AnnualReport {
@@ -678,3 +1136,11 @@ export interface ModuleItem {
     content_details: Option<ContentDetails>;
     published: Option<boolean>;
 }
+
+export interface SearchResult {
+    path: string;
+    file_name: string;
+    course: string;
+    snippet: string;
+    score: number;
+}
@@ -281,6 +281,15 @@ export default function AnnualPage() {
         0
       )
     : 0;
+  const quizCount = report.data
+    ? Object.values(report.data.courseToStatistic).reduce((sum, course) => sum + course.quizCount, 0)
+    : 0;
+  const quizSubmittedCount = report.data
+    ? Object.values(report.data.courseToStatistic).reduce(
+        (sum, course) => sum + course.quizSubmittedCount,
+        0
+      )
+    : 0;
   const completionRate =
     totalAssignments > 0 ? Math.round((totalSubmits / totalAssignments) * 100) : 0;
   const scoreRate =
@@ -952,6 +961,7 @@ export default function AnnualPage() {
                       </Typography>
                       <Typography variant="body1" color="text.secondary" sx={{ mb: 2 }}>
                         你一共面对了 {totalAssignments} 个作业节点，完成了 {totalSubmits} 次提交，整体完成率约为 {completionRate}%。其中有 {lateCount} 次晚交，{gradedCount} 次拿到了成绩反馈。
+                        {quizCount > 0 && ` 另外还有 ${quizCount} 场测验，你参加了其中的 ${quizSubmittedCount} 场。`}
                       </Typography>
                       <Stack direction={{ xs: "column", sm: "row" }} spacing={1.25} useFlexGap flexWrap="wrap">
                         <Chip label={`累计得分 ${totalScore.toFixed(1)}`} color="primary" variant="outlined" />
@@ -1,5 +1,10 @@
 import { invoke } from "@tauri-apps/api/core";
+import AddRoundedIcon from "@mui/icons-material/AddRounded";
 import CalendarMonthRoundedIcon from "@mui/icons-material/CalendarMonthRounded";
+import ContentCopyRoundedIcon from "@mui/icons-material/ContentCopyRounded";
+import DateRangeRoundedIcon from "@mui/icons-material/DateRangeRounded";
+import DeleteOutlineRoundedIcon from "@mui/icons-material/DeleteOutlineRounded";
+import EditRoundedIcon from "@mui/icons-material/EditRounded";
 import EditCalendarRoundedIcon from "@mui/icons-material/EditCalendarRounded";
 import ExpandLessRoundedIcon from "@mui/icons-material/ExpandLessRounded";
 import ExpandMoreRoundedIcon from "@mui/icons-material/ExpandMoreRounded";
@@ -16,6 +21,10 @@ import {
   Checkbox,
   Chip,
   Collapse,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
   Divider,
   FormControlLabel,
   Link as MuiLink,
@@ -41,7 +50,11 @@ import { GradeOverviewChart } from "../components/grade_overview";
 import BasicLayout from "../components/layout";
 import { WorkspaceHero } from "../components/workspace_hero";
 import ModifyDDLModal from "../components/modify_ddl_modal";
+import AssignmentEditorModal from "../components/assignment_editor_modal";
+import BatchDeadlineModal from "../components/batch_deadline_modal";
+import CloneAssignmentModal from "../components/clone_assignment_modal";
 import { SubmitModal } from "../components/submit_modal";
+import SubmissionCommentInput from "../components/submission_comment_input";
 import { useBaseURL, useCourses, useMe, usePreview } from "../lib/hooks";
 import { useAppMessage } from "../lib/message";
 import {
@@ -83,6 +96,17 @@ export default function AssignmentsPage() {
     []
   );
   const [showModifyDDLModal, setShowModifyDDLModal] = useState(false);
+  const [showBatchDeadlineModal, setShowBatchDeadlineModal] = useState(false);
+  const [showEditorModal, setShowEditorModal] = useState(false);
+  const [assignmentToEdit, setAssignmentToEdit] = useState<
+    Assignment | undefined
+  >();
+  const [assignmentToClone, setAssignmentToClone] = useState<
+    Assignment | undefined
+  >();
+  const [assignmentToDelete, setAssignmentToDelete] = useState<
+    Assignment | undefined
+  >();
   const [assignmentToModify, setAssignmentToModify] = useState<
     Assignment | undefined
   >();
@@ -439,6 +463,36 @@ export default function AssignmentsPage() {
     );
   };
 
+  const handleSetPublished = async (assignment: Assignment) => {
+    try {
+      await invoke("set_assignment_published", {
+        courseId: selectedCourseId,
+        assignmentId: assignment.id,
+        published: !assignment.published,
+      });
+      messageApi.success(assignment.published ? "已取消发布" : "已发布", 0.5);
+      void handleGetAssignments(selectedCourseId, onlyShowUnfinished);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`操作失败：${error}`);
+    }
+  };
+
+  const handleDeleteAssignment = async (assignment: Assignment) => {
+    try {
+      await invoke("delete_assignment", {
+        courseId: selectedCourseId,
+        assignmentId: assignment.id,
+      });
+      messageApi.success("删除成功", 0.5);
+      setAssignmentToDelete(undefined);
+      void handleGetAssignments(selectedCourseId, onlyShowUnfinished);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`删除失败：${error}`);
+    }
+  };
+
   const selectedCourse = courses.data.find(
     (course) => course.id === selectedCourseId
   );
@@ -462,10 +516,70 @@ export default function AssignmentsPage() {
           courseId={selectedCourseId}
         />
       ) : null}
+      {selectedCourseId > 0 && isTAOrTeacher(selectedCourseId) ? (
+        <BatchDeadlineModal
+          open={showBatchDeadlineModal}
+          courseId={selectedCourseId}
+          assignments={assignments}
+          handleCancel={() => setShowBatchDeadlineModal(false)}
+          onSuccess={() =>
+            void handleGetAssignments(selectedCourseId, onlyShowUnfinished)
+          }
+        />
+      ) : null}
+      {selectedCourseId > 0 && isTAOrTeacher(selectedCourseId) ? (
+        <AssignmentEditorModal
+          open={showEditorModal}
+          courseId={selectedCourseId}
+          assignment={assignmentToEdit}
+          handleCancel={() => setShowEditorModal(false)}
+          onSuccess={() => {
+            setShowEditorModal(false);
+            void handleGetAssignments(selectedCourseId, onlyShowUnfinished);
+          }}
+        />
+      ) : null}
+      {assignmentToClone ? (
+        <CloneAssignmentModal
+          open
+          courseId={selectedCourseId}
+          assignment={assignmentToClone}
+          handleCancel={() => setAssignmentToClone(undefined)}
+          onSuccess={(cloned) => {
+            setAssignmentToClone(undefined);
+            if (cloned.course_id === selectedCourseId) {
+              void handleGetAssignments(selectedCourseId, onlyShowUnfinished);
+            }
+          }}
+        />
+      ) : null}
+      <Dialog
+        open={assignmentToDelete !== undefined}
+        onClose={() => setAssignmentToDelete(undefined)}
+      >
+        <DialogTitle>删除作业</DialogTitle>
+        <DialogContent>
+          确定删除「{assignmentToDelete?.name}」吗？学生的提交和成绩也会一并删除。
+        </DialogContent>
+        <DialogActions>
+          <Button onClick={() => setAssignmentToDelete(undefined)}>取消</Button>
+          <Button
+            color="error"
+            onClick={() =>
+              assignmentToDelete
+                ? void handleDeleteAssignment(assignmentToDelete)
+                : undefined
+            }
+          >
+            删除
+          </Button>
+        </DialogActions>
+      </Dialog>
       {selectedAssignment ? (
         <SubmitModal
           open={showModal}
           allowed_extensions={selectedAssignment.allowed_extensions}
+          submission_types={selectedAssignment.submission_types}
           courseId={selectedCourseId}
           assignmentId={selectedAssignment.id}
           onCancel={() => setShowModal(false)}
@@ -529,7 +643,28 @@ export default function AssignmentsPage() {
                   sx={{ m: 0 }}
                 />
               ) : (
-                <Chip label="教师 / 助教模式" color="primary" variant="outlined" />
+                <Stack direction="row" spacing={1.5} alignItems="center">
+                  <Chip label="教师 / 助教模式" color="primary" variant="outlined" />
+                  <Button
+                    variant="outlined"
+                    startIcon={<DateRangeRoundedIcon />}
+                    disabled={operating}
+                    onClick={() => setShowBatchDeadlineModal(true)}
+                  >
+                    批量修改日期
+                  </Button>
+                  <Button
+                    variant="contained"
+                    startIcon={<AddRoundedIcon />}
+                    disabled={operating || selectedCourseId === -1}
+                    onClick={() => {
+                      setAssignmentToEdit(undefined);
+                      setShowEditorModal(true);
+                    }}
+                  >
+                    新建作业
+                  </Button>
+                </Stack>
               )}
               {selectedCourse ? (
                 <Chip
@@ -680,6 +815,41 @@ export default function AssignmentsPage() {
                         >
                           修改日期
                         </Button>
+                      ) : null}
+                      {isTAOrTeacher(selectedCourseId) ? (
+                        <>
+                          <Button
+                            variant="text"
+                            startIcon={<EditRoundedIcon />}
+                            onClick={() => {
+                              setAssignmentToEdit(assignment);
+                              setShowEditorModal(true);
+                            }}
+                          >
+                            编辑
+                          </Button>
+                          <Button
+                            variant="text"
+                            onClick={() => void handleSetPublished(assignment)}
+                          >
+                            {assignment.published ? "取消发布" : "发布"}
+                          </Button>
+                          <Button
+                            variant="text"
+                            startIcon={<ContentCopyRoundedIcon />}
+                            onClick={() => setAssignmentToClone(assignment)}
+                          >
+                            复制
+                          </Button>
+                          <Button
+                            variant="text"
+                            color="error"
+                            startIcon={<DeleteOutlineRoundedIcon />}
+                            onClick={() => setAssignmentToDelete(assignment)}
+                          >
+                            删除
+                          </Button>
+                        </>
                       ) : allowSubmit ? (
                         <Button
                           variant="contained"
@@ -792,6 +962,18 @@ export default function AssignmentsPage() {
                               暂无评论记录。
                             </Typography>
                           )}
+                          {!isTAOrTeacher(selectedCourseId) && submission ? (
+                            <Box sx={{ mt: 1.5 }}>
+                              <SubmissionCommentInput
+                                courseId={selectedCourseId}
+                                assignmentId={assignment.id}
+                                messageApi={messageApi}
+                                onSuccess={() =>
+                                  handleGetMySingleSubmission(selectedCourseId, assignment.id)
+                                }
+                              />
+                            </Box>
+                          ) : null}
                         </Box>
 
                         <Box>
@@ -132,12 +132,14 @@ export default function CalendarPage() {
   };
 
   const dedupeEvents = (rawEvents: CalendarEvent[]) => {
-    const seenAssignments = new Set<number>();
+    const seenAssignments = new Set<string>();
     return rawEvents.filter((event) => {
-      if (seenAssignments.has(event.assignment.id)) {
+      // quiz events carry quiz ids, which may collide with assignment ids
+      const key = `${event.type_field}_${event.assignment.id}`;
+      if (seenAssignments.has(key)) {
         return false;
       }
-      seenAssignments.add(event.assignment.id);
+      seenAssignments.add(key);
       return true;
     });
   };
@@ -631,6 +633,9 @@ export default function CalendarPage() {
                               <Typography variant="body2" color="text.secondary">
                                 {event.context_name}
                               </Typography>
+                              {event.assignment.quiz_id != null && (
+                                <Chip size="small" label="测验" sx={{ width: "fit-content" }} />
+                              )}
                               <Typography variant="caption" color="text.secondary">
                                 截止时间：{getEventMoment(event).format("YYYY/MM/DD HH:mm")}
                               </Typography>
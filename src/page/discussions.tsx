@@ -1,9 +1,17 @@
 import { invoke } from "@tauri-apps/api/core";
+import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import AddCommentRoundedIcon from "@mui/icons-material/AddCommentRounded";
+import AttachFileRoundedIcon from "@mui/icons-material/AttachFileRounded";
 import ChatBubbleOutlineRoundedIcon from "@mui/icons-material/ChatBubbleOutlineRounded";
 import LaunchRoundedIcon from "@mui/icons-material/LaunchRounded";
 import MarkEmailUnreadRoundedIcon from "@mui/icons-material/MarkEmailUnreadRounded";
+import DoneAllRoundedIcon from "@mui/icons-material/DoneAllRounded";
 import PushPinRoundedIcon from "@mui/icons-material/PushPinRounded";
 import RefreshRoundedIcon from "@mui/icons-material/RefreshRounded";
+import ReplyRoundedIcon from "@mui/icons-material/ReplyRounded";
+import SendRoundedIcon from "@mui/icons-material/SendRounded";
+import ThumbUpAltOutlinedIcon from "@mui/icons-material/ThumbUpAltOutlined";
+import ThumbUpAltRoundedIcon from "@mui/icons-material/ThumbUpAltRounded";
 import {
   Avatar,
   Box,
@@ -21,6 +29,7 @@ import { alpha, useTheme } from "@mui/material/styles";
 import { useMemo, useState } from "react";
 
 import CourseSelect from "../components/course_select";
+import DiscussionTopicModal from "../components/discussion_topic_modal";
 import BasicLayout from "../components/layout";
 import { WorkspaceHero } from "../components/workspace_hero";
 import { useCourses, useMe } from "../lib/hooks";
@@ -48,12 +57,22 @@ function DiscussionBubble({
   createdAt,
   mine,
   unread,
+  liked,
+  ratingCount,
+  onReply,
+  onLike,
+  onMarkRead,
 }: {
   author: string;
   message: string;
   createdAt?: string | null;
   mine: boolean;
   unread?: boolean;
+  liked?: boolean;
+  ratingCount?: number | null;
+  onReply?: () => void;
+  onLike?: () => void;
+  onMarkRead?: () => void;
 }) {
   return (
     <Stack
@@ -123,6 +142,32 @@ function DiscussionBubble({
           }}
           dangerouslySetInnerHTML={{ __html: message || "<p>无内容</p>" }}
         />
+        <Stack
+          direction="row"
+          spacing={0.5}
+          justifyContent={mine ? "flex-start" : "flex-end"}
+          sx={{ mt: 1, "& .MuiButton-root": { color: "inherit", minWidth: 0 } }}
+        >
+          {onReply ? (
+            <Button size="small" startIcon={<ReplyRoundedIcon />} onClick={onReply}>
+              回复
+            </Button>
+          ) : null}
+          {onLike ? (
+            <Button
+              size="small"
+              startIcon={liked ? <ThumbUpAltRoundedIcon /> : <ThumbUpAltOutlinedIcon />}
+              onClick={onLike}
+            >
+              {ratingCount || 0}
+            </Button>
+          ) : null}
+          {unread && onMarkRead ? (
+            <Button size="small" startIcon={<DoneAllRoundedIcon />} onClick={onMarkRead}>
+              已读
+            </Button>
+          ) : null}
+        </Stack>
       </Box>
     </Stack>
   );
@@ -136,6 +181,13 @@ export default function DiscussionsPage() {
   const [selectedTopicId, setSelectedTopicId] = useState<number | "">("");
   const [fullDiscussion, setFullDiscussion] = useState<FullDiscussion>();
   const [selectedCourseId, setSelectedCourseId] = useState<number>(-1);
+  const [openTopicModal, setOpenTopicModal] = useState(false);
+  const [replyTarget, setReplyTarget] = useState<
+    { entryId: number; author: string } | undefined
+  >();
+  const [replyMessage, setReplyMessage] = useState("");
+  const [replyAttachment, setReplyAttachment] = useState<string | undefined>();
+  const [sending, setSending] = useState(false);
   const courses = useCourses();
   const me = useMe();
 
@@ -212,9 +264,91 @@ export default function DiscussionsPage() {
 
   const handleTopicSelect = async (topicId: number) => {
     setSelectedTopicId(topicId);
+    setReplyTarget(undefined);
+    setReplyMessage("");
+    setReplyAttachment(undefined);
     await handleGetFullDiscussion(topicId);
   };
 
+  const handleSelectReplyAttachment = async () => {
+    const selected = await openDialog({ multiple: false, directory: false });
+    if (typeof selected === "string") {
+      setReplyAttachment(selected);
+    }
+  };
+
+  const handleSendReply = async () => {
+    if (selectedTopicId === "" || !replyMessage.trim()) {
+      return;
+    }
+    try {
+      setSending(true);
+      await invoke("post_discussion_reply", {
+        courseId: selectedCourseId,
+        topicId: selectedTopicId,
+        entryId: replyTarget?.entryId,
+        message: replyMessage,
+        attachment: replyAttachment,
+      });
+      messageApi.success("发送成功", 0.5);
+      setReplyTarget(undefined);
+      setReplyMessage("");
+      setReplyAttachment(undefined);
+      await handleGetFullDiscussion(selectedTopicId);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`发送失败：${error}`);
+    } finally {
+      setSending(false);
+    }
+  };
+
+  const handleLike = async (entryId: number, liked: boolean) => {
+    if (selectedTopicId === "") {
+      return;
+    }
+    try {
+      await invoke("rate_discussion_entry", {
+        courseId: selectedCourseId,
+        topicId: selectedTopicId,
+        entryId,
+        liked,
+      });
+      await handleGetFullDiscussion(selectedTopicId);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`操作失败：${error}`);
+    }
+  };
+
+  const handleMarkRead = async (entryId?: number) => {
+    if (selectedTopicId === "") {
+      return;
+    }
+    try {
+      if (entryId === undefined) {
+        await invoke("mark_discussion_topic_read", {
+          courseId: selectedCourseId,
+          topicId: selectedTopicId,
+        });
+      } else {
+        await invoke("mark_discussion_entry_read", {
+          courseId: selectedCourseId,
+          topicId: selectedTopicId,
+          entryId,
+          read: true,
+        });
+      }
+      await Promise.all([
+        handleGetFullDiscussion(selectedTopicId),
+        handleGetDiscussionTopics(selectedCourseId),
+      ]);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`标记已读失败：${error}`);
+    }
+  };
+
   const selectedCourse = courses.data.find(
     (course) => course.id === selectedCourseId
   );
@@ -222,6 +356,17 @@ export default function DiscussionsPage() {
   return (
     <BasicLayout>
       {contextHolder}
+      <DiscussionTopicModal
+        open={openTopicModal}
+        courseId={selectedCourseId}
+        handleCancel={() => setOpenTopicModal(false)}
+        onSuccess={(topic) => {
+          setOpenTopicModal(false);
+          void handleGetDiscussionTopics(selectedCourseId).then(() =>
+            handleTopicSelect(topic.id)
+          );
+        }}
+      />
       <Stack spacing={3}>
         <WorkspaceHero
           chipLabel="Discussion Workspace"
@@ -289,6 +434,15 @@ export default function DiscussionsPage() {
               >
                 刷新主题
               </Button>
+              <Button
+                variant="contained"
+                startIcon={<AddCommentRoundedIcon />}
+                onClick={() => setOpenTopicModal(true)}
+                disabled={selectedCourseId <= 0 || operating}
+                sx={{ minWidth: { md: 132 } }}
+              >
+                新建话题
+              </Button>
             </Stack>
           }
         />
@@ -423,16 +577,26 @@ export default function DiscussionsPage() {
                           {formatDate(selectedTopic.last_reply_at || selectedTopic.created_at)}
                         </Typography>
                       </Box>
-                      <Button
-                        component="a"
-                        href={selectedTopic.html_url}
-                        target="_blank"
-                        rel="noreferrer"
-                        variant="outlined"
-                        startIcon={<LaunchRoundedIcon />}
-                      >
-                        在 Canvas 打开
-                      </Button>
+                      <Stack direction="row" spacing={1} alignItems="flex-start">
+                        <Button
+                          variant="outlined"
+                          startIcon={<DoneAllRoundedIcon />}
+                          disabled={fullDiscussion.unread_entries.length === 0}
+                          onClick={() => void handleMarkRead()}
+                        >
+                          全部已读
+                        </Button>
+                        <Button
+                          component="a"
+                          href={selectedTopic.html_url}
+                          target="_blank"
+                          rel="noreferrer"
+                          variant="outlined"
+                          startIcon={<LaunchRoundedIcon />}
+                        >
+                          在 Canvas 打开
+                        </Button>
+                      </Stack>
                     </Stack>
                     <Stack direction="row" spacing={1} flexWrap="wrap" useFlexGap>
                       <Chip
@@ -489,19 +653,83 @@ export default function DiscussionsPage() {
                       const unread =
                         fullDiscussion.unread_entries.includes(view.id) ||
                         fullDiscussion.new_entries.includes(view.id);
+                      const author = user?.display_name ?? "未知用户";
+                      const liked = fullDiscussion.entry_ratings?.[view.id] === 1;
 
                       return (
                         <DiscussionBubble
                           key={view.id}
-                          author={user?.display_name ?? "未知用户"}
+                          author={author}
                           message={view.message ?? ""}
                           createdAt={view.created_at}
                           mine={Boolean(isMine)}
                           unread={unread}
+                          liked={liked}
+                          ratingCount={view.rating_sum}
+                          onReply={() =>
+                            setReplyTarget({
+                              // side comment topics only allow one level of replies
+                              entryId:
+                                selectedTopic.discussion_type === "threaded"
+                                  ? view.id
+                                  : view.parent_id || view.id,
+                              author,
+                            })
+                          }
+                          onLike={
+                            selectedTopic.allow_rating
+                              ? () => void handleLike(view.id, !liked)
+                              : undefined
+                          }
+                          onMarkRead={() => void handleMarkRead(view.id)}
                         />
                       );
                     })}
                   </Stack>
+
+                  <Stack spacing={1.25}>
+                    {replyTarget ? (
+                      <Chip
+                        sx={{ alignSelf: "flex-start" }}
+                        icon={<ReplyRoundedIcon />}
+                        label={`回复 ${replyTarget.author}`}
+                        onDelete={() => setReplyTarget(undefined)}
+                      />
+                    ) : null}
+                    <TextField
+                      fullWidth
+                      multiline
+                      minRows={3}
+                      disabled={!!selectedTopic.locked}
+                      label={selectedTopic.locked ? "话题已锁定" : "发表回复"}
+                      value={replyMessage}
+                      onChange={(event) => setReplyMessage(event.target.value)}
+                    />
+                    <Stack direction="row" spacing={1} alignItems="center">
+                      <Button
+                        startIcon={<AttachFileRoundedIcon />}
+                        disabled={!!selectedTopic.locked}
+                        onClick={() => void handleSelectReplyAttachment()}
+                      >
+                        附件
+                      </Button>
+                      {replyAttachment ? (
+                        <Chip
+                          label={replyAttachment.split(/[\\/]/).pop()}
+                          onDelete={() => setReplyAttachment(undefined)}
+                        />
+                      ) : null}
+                      <Box sx={{ flex: 1 }} />
+                      <Button
+                        variant="contained"
+                        startIcon={<SendRoundedIcon />}
+                        disabled={sending || !!selectedTopic.locked || !replyMessage.trim()}
+                        onClick={() => void handleSendReply()}
+                      >
+                        发送
+                      </Button>
+                    </Stack>
+                  </Stack>
                 </Stack>
               ) : (
                 <Box
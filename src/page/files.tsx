@@ -43,6 +43,7 @@ import { WorkspaceHero } from "../components/workspace_hero";
 import CourseSelect from "../components/course_select";
 import FileDownloadTable from "../components/file_download_table";
 import FileOrderSelectModal from "../components/file_order_select_modal";
+import FolderUploadModal from "../components/folder_upload_modal";
 import {
   useBaseURL,
   useCourses,
@@ -53,6 +54,8 @@ import {
 import { useAppMessage } from "../lib/message";
 import {
   Course,
+  CourseSyncDiff,
+  DownloadJobState,
   Entry,
   File,
   FileChatStreamChunkPayload,
@@ -60,6 +63,7 @@ import {
   FileChatStreamErrorPayload,
   FileDownloadTask,
   Folder,
+  FolderFilesPagePayload,
   LLMChatMessage,
   LOG_LEVEL_ERROR,
   Option,
@@ -137,6 +141,7 @@ export default function FilesPage() {
   const [keyword, setKeyword] = useState<string>("");
   const [openFileOrderSelectModal, setOpenFileOrderSelectModal] =
     useState<boolean>(false);
+  const [openFolderUploadModal, setOpenFolderUploadModal] = useState(false);
   const [chatOpen, setChatOpen] = useState(false);
   const [chatFile, setChatFile] = useState<File | null>(null);
   const [chatMessages, setChatMessages] = useState<FileAIChatMessage[]>([]);
@@ -420,7 +425,21 @@ export default function FilesPage() {
   };
 
   const handleGetFolderFiles = async (folderId: number) => {
+    // large folders are shown page by page while the rest is still loading
+    const unlistenPage = await listen<FolderFilesPagePayload>(
+      "folder_files://page",
+      ({ payload }) => {
+        if (payload.folder_id !== folderId) {
+          return;
+        }
+        payload.files.forEach((file) => {
+          file.key = file.uuid;
+        });
+        setFiles((prev) => [...prev, ...payload.files]);
+      }
+    );
     try {
+      setFiles([]);
       const nextFiles = (await invoke("list_folder_files", { folderId })) as File[];
       if (folderId !== currentFolderId) {
         return;
@@ -431,6 +450,8 @@ export default function FilesPage() {
       setFiles(nextFiles);
     } catch {
       setFiles([]);
+    } finally {
+      unlistenPage();
     }
   };
 
@@ -510,7 +531,7 @@ export default function FilesPage() {
     }
   };
 
-  const handleDownloadFile = async (file: File) => {
+  const handleDownloadFile = async (file: File): Promise<DownloadJobState> => {
     const folderPath = getFolderPath(file);
     const course = getSelectedCourse();
     if (!downloadInfoMap.get(file.folder_id) && folderPath !== undefined) {
@@ -520,24 +541,31 @@ export default function FilesPage() {
       });
     }
     if (section === COURSE_FILES) {
-      await invoke("download_course_file", { file, course, folderPath });
-    } else {
-      await invoke("download_my_file", { file, folderPath });
+      return invoke("download_course_file", { file, course, folderPath });
     }
+    return invoke("download_my_file", { file, folderPath });
   };
 
   const handleSyncFiles = async () => {
     try {
       const course = getSelectedCourse()!;
       notify("正在计算同步任务…", "info");
-      const filesToSync = (await invoke("sync_course_files", {
+      const diff = (await invoke("sync_course_files", {
         course,
-      })) as File[];
+      })) as CourseSyncDiff;
+      const filesToSync = [
+        ...diff.new_files,
+        ...diff.modified_files,
+        ...diff.renamed_files,
+      ].map((change) => change.file);
       if (filesToSync.length > 0) {
         notify(`共 ${filesToSync.length} 个文件需要下载，任务已开始。`, "success");
       } else {
         notify("已同步，无需下载。", "success");
       }
+      if (diff.removed_files.length > 0) {
+        notify(`有 ${diff.removed_files.length} 个文件已在 Canvas 上删除。`, "info");
+      }
       filesToSync.forEach((file) => void handleAddDownloadFileTask(file));
     } catch (error) {
       consoleLog(LOG_LEVEL_ERROR, error);
@@ -728,6 +756,14 @@ export default function FilesPage() {
         files={getSupportedMergeFiles()}
       />
 
+      <FolderUploadModal
+        open={openFolderUploadModal}
+        folderId={currentFolderId}
+        folderName={currentFolderName}
+        handleCancel={() => setOpenFolderUploadModal(false)}
+        onSuccess={() => void handleGetFoldersAndFiles(currentFolderId)}
+      />
+
       <FileAIChatModal
         open={chatOpen}
         title={chatFile?.display_name ?? ""}
@@ -1085,6 +1121,14 @@ export default function FilesPage() {
                   >
                     合并 Word/PDF/PPTX
                   </Button>
+                  <Button
+                    variant="outlined"
+                    startIcon={<UploadFileRoundedIcon />}
+                    disabled={operating || currentFolderId <= 0}
+                    onClick={() => setOpenFolderUploadModal(true)}
+                  >
+                    上传到当前目录
+                  </Button>
                 </Stack>
                 <Chip
                   label={selectedFileCount > 0 ? `已选 ${selectedFileCount} 个文件` : "尚未选择文件"}
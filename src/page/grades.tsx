@@ -306,13 +306,25 @@ export default function GradePage() {
     setExportInfo((prev) => ({ ...prev, folderPath: nextPath }));
   };
 
-  const handleExport = async () => {
+  const getExportFileName = () => {
     if (!exportInfo.fileName.trim()) {
       messageApi.error("请输入导出文件名");
-      return;
+      return undefined;
     }
     if (!exportInfo.folderPath.trim()) {
       messageApi.error("请选择导出目录");
+      return undefined;
+    }
+    let fileName = exportInfo.fileName;
+    if (!fileName.endsWith(".xlsx")) {
+      fileName += ".xlsx";
+    }
+    return fileName;
+  };
+
+  const handleExport = async () => {
+    const fileName = getExportFileName();
+    if (!fileName) {
       return;
     }
 
@@ -332,11 +344,6 @@ export default function GradePage() {
       exportData.push(row);
     });
 
-    let fileName = exportInfo.fileName;
-    if (!fileName.endsWith(".xlsx")) {
-      fileName += ".xlsx";
-    }
-
     try {
       await invoke("export_excel", {
         data: exportData,
@@ -350,6 +357,25 @@ export default function GradePage() {
     }
   };
 
+  // includes late flags, submission times, totals and statistics with numeric cells
+  const handleExportGradebook = async () => {
+    const fileName = getExportFileName();
+    if (!fileName) {
+      return;
+    }
+    try {
+      await invoke("export_gradebook", {
+        courseId: selectedCourseId,
+        fileName,
+        folderPath: exportInfo.folderPath,
+      });
+      messageApi.success("导出成功", 0.5);
+    } catch (error) {
+      consoleLog(LOG_LEVEL_ERROR, error);
+      messageApi.error(`导出失败：${error}`);
+    }
+  };
+
   return (
     <BasicLayout>
       {contextHolder}
@@ -658,7 +684,7 @@ export default function GradePage() {
                           选择目录
                         </Button>
                       </Box>
-                      <Box>
+                      <Stack direction="row" spacing={1.5}>
                         <Button
                           variant="contained"
                           startIcon={<DownloadRoundedIcon />}
@@ -666,7 +692,14 @@ export default function GradePage() {
                         >
                           导出 Excel
                         </Button>
-                      </Box>
+                        <Button
+                          variant="outlined"
+                          startIcon={<DownloadRoundedIcon />}
+                          onClick={() => void handleExportGradebook()}
+                        >
+                          导出完整成绩册
+                        </Button>
+                      </Stack>
                     </Stack>
                   </>
                 ) : (
@@ -418,6 +418,7 @@ export default function SettingsPage() {
         compact_mode: config.compact_mode ?? false,
         mcp_enabled: config.mcp_enabled ?? false,
         mcp_port: config.mcp_port || 3100,
+        mcp_allow_write: config.mcp_allow_write ?? false,
         proxy_port: config.proxy_port === 0 ? DEFAULT_PROXY_PORT : config.proxy_port,
         llm_api_keys: config.llm_api_keys ?? [],
         llm_active_api_key: config.llm_active_api_key ?? "",
@@ -1670,6 +1671,18 @@ export default function SettingsPage() {
                           helperText="默认 3100"
                           inputProps={{ min: 1024, max: 65535 }}
                         />
+                        <FormControlLabel
+                          control={
+                            <Switch
+                              checked={formData?.mcp_allow_write ?? false}
+                              onChange={(event) =>
+                                updateField("mcp_allow_write", event.target.checked)
+                              }
+                              disabled={!formData?.mcp_enabled}
+                            />
+                          }
+                          label="允许写入操作（发帖、回复、点赞等）"
+                        />
                       </Box>
                     </Box>
 
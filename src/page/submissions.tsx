@@ -1,10 +1,13 @@
 import { invoke } from "@tauri-apps/api/core";
 import ArticleRoundedIcon from "@mui/icons-material/ArticleRounded";
+import ChecklistRoundedIcon from "@mui/icons-material/ChecklistRounded";
+import CompareArrowsRoundedIcon from "@mui/icons-material/CompareArrowsRounded";
 import DownloadRoundedIcon from "@mui/icons-material/DownloadRounded";
 import OpenInNewRoundedIcon from "@mui/icons-material/OpenInNewRounded";
 import PreviewRoundedIcon from "@mui/icons-material/PreviewRounded";
 import RateReviewRoundedIcon from "@mui/icons-material/RateReviewRounded";
 import RefreshRoundedIcon from "@mui/icons-material/RefreshRounded";
+import UploadFileRoundedIcon from "@mui/icons-material/UploadFileRounded";
 import WarningAmberRoundedIcon from "@mui/icons-material/WarningAmberRounded";
 import {
   Alert,
@@ -42,8 +45,13 @@ import CommentPanel from "../components/comment_panel";
 import CourseFileSelector from "../components/course_file_selector";
 import CourseSelect from "../components/course_select";
 import FileDownloadTable from "../components/file_download_table";
+import GradeImportModal from "../components/grade_import_modal";
 import GradeStatisticChart from "../components/grade_statistic";
 import BasicLayout from "../components/layout";
+import RubricGradeModal from "../components/rubric_grade_modal";
+import SimilarityReportModal from "../components/similarity_report_modal";
+import FeedbackUploadModal from "../components/feedback_upload_modal";
+import SubmissionDownloadModal from "../components/submission_download_modal";
 import { getConfig, saveConfig } from "../lib/config";
 import { SUBMISSION_PAGE_HINT_ALERT_KEY } from "../lib/constants";
 import { useAppMessage } from "../lib/message";
@@ -56,6 +64,7 @@ import {
 import {
   Assignment,
   Attachment,
+  DownloadJobState,
   File,
   FileDownloadTask,
   GradeStatistic,
@@ -155,6 +164,13 @@ export default function SubmissionsPage() {
   const [statistic, setStatistic] = useState<GradeStatistic | undefined>();
   const [keywords, setKeywords] = useState<string[]>([]);
   const [attachmentToComment, setAttachmentToComment] = useState(-1);
+  const [showGradeImport, setShowGradeImport] = useState(false);
+  const [showSubmissionDownload, setShowSubmissionDownload] = useState(false);
+  const [showSimilarityReport, setShowSimilarityReport] = useState(false);
+  const [showFeedbackUpload, setShowFeedbackUpload] = useState(false);
+  const [attachmentToRubricGrade, setAttachmentToRubricGrade] = useState<
+    Attachment | undefined
+  >(undefined);
   const [expandedRowKeys, setExpandedRowKeys] = useState<number[]>([]);
   const [previewFooter, setPreviewFooter] = useState<ReactNode>(undefined);
   const [commentingWhilePreviewing, setCommentingWhilePreviewing] =
@@ -297,9 +313,14 @@ export default function SubmissionsPage() {
         assignmentId: selectedAssignment?.id,
         studentId: attachment.user_id,
         grade,
+        groupId: attachment.group_id,
       });
       attachments
-        .filter((item) => item.user_id === attachment.user_id)
+        .filter((item) =>
+          attachment.group_id != null
+            ? item.group_id === attachment.group_id
+            : item.user_id === attachment.user_id
+        )
         .forEach((item) => (item.grade = grade));
       setAttachments([...attachments]);
       updateGradeStatistic(attachments);
@@ -345,16 +366,28 @@ export default function SubmissionsPage() {
       return;
     }
     try {
-      const submissions = (await invoke("list_course_assignment_submissions", {
-        courseId,
-        assignmentId,
-      })) as Submission[];
+      // group submissions are graded as one unit unless students are graded individually
+      const assignment = assignments.find((item) => item.id === assignmentId);
+      const grouped =
+        assignment?.group_category_id != null && !assignment.grade_group_students_individually;
+      const submissions = (await invoke(
+        grouped ? "list_group_assignment_submissions" : "list_course_assignment_submissions",
+        {
+          courseId,
+          assignmentId,
+        }
+      )) as Submission[];
       const nextAttachments: Attachment[] = [];
       submissions.forEach((submission) => {
         const thisAttachments = submission.attachments;
+        const groupId = submission.group?.id;
         thisAttachments.forEach((attachment) => {
-          attachment.user = usersMap.get(submission.user_id)?.name;
+          attachment.user =
+            groupId != null
+              ? submission.group?.name ?? undefined
+              : usersMap.get(submission.user_id)?.name;
           attachment.user_id = submission.user_id;
+          attachment.group_id = groupId;
           attachment.submitted_at = submission.submitted_at;
           attachment.grade = submission.grade;
           attachment.key = attachment.id;
@@ -471,8 +504,8 @@ export default function SubmissionsPage() {
     return attachment.user && (showAll || keywords.includes(attachment.user));
   };
 
-  const handleDownloadFile = async (file: File) => {
-    await invoke("download_file", { file });
+  const handleDownloadFile = async (file: File): Promise<DownloadJobState> => {
+    return invoke("download_file", { file });
   };
 
   const handleOpenTaskFile = async (task: FileDownloadTask) => {
@@ -526,6 +559,58 @@ export default function SubmissionsPage() {
   return (
     <BasicLayout>
       {contextHolder}
+      {selectedAssignment && (
+        <SubmissionDownloadModal
+          open={showSubmissionDownload}
+          courseId={selectedCourseId}
+          assignment={selectedAssignment}
+          handleCancel={() => setShowSubmissionDownload(false)}
+        />
+      )}
+      {selectedAssignment && (
+        <SimilarityReportModal
+          open={showSimilarityReport}
+          courseId={selectedCourseId}
+          assignment={selectedAssignment}
+          handleCancel={() => setShowSimilarityReport(false)}
+        />
+      )}
+      {selectedAssignment && (
+        <FeedbackUploadModal
+          open={showFeedbackUpload}
+          courseId={selectedCourseId}
+          assignment={selectedAssignment}
+          handleCancel={() => setShowFeedbackUpload(false)}
+          onSuccess={() =>
+            void handleGetSubmissions(selectedCourseId, selectedAssignment.id)
+          }
+        />
+      )}
+      {selectedAssignment && (
+        <GradeImportModal
+          open={showGradeImport}
+          courseId={selectedCourseId}
+          assignment={selectedAssignment}
+          handleCancel={() => setShowGradeImport(false)}
+          onSuccess={() => {
+            setShowGradeImport(false);
+            void handleGetSubmissions(selectedCourseId, selectedAssignment.id);
+          }}
+        />
+      )}
+      {selectedAssignment && attachmentToRubricGrade && (
+        <RubricGradeModal
+          open
+          courseId={selectedCourseId}
+          assignment={selectedAssignment}
+          attachment={attachmentToRubricGrade}
+          handleCancel={() => setAttachmentToRubricGrade(undefined)}
+          onSuccess={(userId) => {
+            void refreshSubmission(userId);
+            setAttachmentToRubricGrade(undefined);
+          }}
+        />
+      )}
       {previewer}
       <Stack spacing={3}>
         <ClosableAlert
@@ -716,6 +801,38 @@ export default function SubmissionsPage() {
                 >
                   刷新提交
                 </Button>
+                <Button
+                  variant="outlined"
+                  startIcon={<UploadFileRoundedIcon />}
+                  disabled={!selectedAssignment || operating || readonlyGrade}
+                  onClick={() => setShowGradeImport(true)}
+                >
+                  导入成绩
+                </Button>
+                <Button
+                  variant="outlined"
+                  startIcon={<DownloadRoundedIcon />}
+                  disabled={!selectedAssignment || operating}
+                  onClick={() => setShowSubmissionDownload(true)}
+                >
+                  批量下载
+                </Button>
+                <Button
+                  variant="outlined"
+                  startIcon={<CompareArrowsRoundedIcon />}
+                  disabled={!selectedAssignment || operating}
+                  onClick={() => setShowSimilarityReport(true)}
+                >
+                  相似度检测
+                </Button>
+                <Button
+                  variant="outlined"
+                  startIcon={<UploadFileRoundedIcon />}
+                  disabled={!selectedAssignment || operating}
+                  onClick={() => setShowFeedbackUpload(true)}
+                >
+                  批量上传反馈
+                </Button>
               </Stack>
             </Stack>
           </CardContent>
@@ -941,6 +1058,19 @@ export default function SubmissionsPage() {
                                         <RateReviewRoundedIcon fontSize="small" />
                                       </IconButton>
                                     </Tooltip>
+                                    {!readonlyGrade &&
+                                      (selectedAssignment?.rubric.length ?? 0) > 0 && (
+                                        <Tooltip title="量规评分">
+                                          <IconButton
+                                            size="small"
+                                            onClick={() =>
+                                              setAttachmentToRubricGrade(attachment)
+                                            }
+                                          >
+                                            <ChecklistRoundedIcon fontSize="small" />
+                                          </IconButton>
+                                        </Tooltip>
+                                      )}
                                     <Tooltip title="SpeedGrader">
                                       <IconButton
                                         size="small"